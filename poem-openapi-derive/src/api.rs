@@ -8,7 +8,8 @@ use syn::{
 
 use crate::{
     common_args::{
-        APIMethod, CodeSample, DefaultValue, ExampleValue, ExternalDocument, ExtraHeader,
+        APIMethod, CodeSample, DefaultValue, Deprecated, ExampleValue, ExternalDocument,
+        ExtraHeader,
     },
     error::GeneratorResult,
     parameter_style::ParameterStyle,
@@ -42,7 +43,7 @@ struct APIOperation {
     #[darling(default, multiple, rename = "method")]
     methods: Vec<SpannedValue<APIMethod>>,
     #[darling(default)]
-    deprecated: bool,
+    deprecated: Deprecated,
     #[darling(default, multiple, rename = "tag")]
     tags: Vec<Path>,
     #[darling(default)]
@@ -59,6 +60,8 @@ struct APIOperation {
     actual_type: Option<Type>,
     #[darling(default, multiple, rename = "code_sample")]
     code_samples: Vec<CodeSample>,
+    #[darling(default, multiple, rename = "server")]
+    servers: Vec<String>,
     #[darling(default)]
     hidden: bool,
     #[darling(default)]
@@ -84,6 +87,19 @@ struct APIOperationParam {
     explode: Option<bool>,
     #[darling(default)]
     style: Option<ParameterStyle>,
+    #[darling(default)]
+    max_items: Option<usize>,
+    #[darling(default)]
+    max_raw_length: Option<usize>,
+    #[darling(default)]
+    trim_values: Option<bool>,
+    #[darling(default)]
+    flatten_commas: Option<bool>,
+    #[darling(default)]
+    component: Option<String>,
+    // for request objects
+    #[darling(default)]
+    content_type: Option<String>,
     // for oauth
     #[darling(multiple, default, rename = "scope")]
     scopes: Vec<Path>,
@@ -188,9 +204,23 @@ fn generate_operation(
         request_headers,
         actual_type,
         code_samples,
+        servers,
         hidden,
         ignore_case,
     } = args;
+    let is_deprecated = deprecated.is_deprecated;
+    let replaced_by = match &deprecated.replaced_by {
+        Some(replaced_by) => quote!(::std::option::Option::Some(#replaced_by)),
+        None => quote!(::std::option::Option::None),
+    };
+    let deprecated_since = match &deprecated.since {
+        Some(since) => quote!(::std::option::Option::Some(#since)),
+        None => quote!(::std::option::Option::None),
+    };
+    let deprecated_remove_in = match &deprecated.remove_in {
+        Some(remove_in) => quote!(::std::option::Option::Some(#remove_in)),
+        None => quote!(::std::option::Option::None),
+    };
     if methods.is_empty() {
         return Err(Error::new_spanned(
             &item_method.sig.ident,
@@ -199,6 +229,18 @@ fn generate_operation(
         .into());
     }
     let fn_ident = &item_method.sig.ident;
+    // every operation gets a stable id derived from the method name unless
+    // one is set explicitly; a function routed to several HTTP methods gets
+    // the method name appended to keep the ids unique
+    let derive_operation_id = |method: APIMethod| match &operation_id {
+        Some(operation_id) => operation_id.clone(),
+        None if methods.len() == 1 => fn_ident.unraw().to_string(),
+        None => format!(
+            "{}_{}",
+            fn_ident.unraw(),
+            format!("{method:?}").to_lowercase()
+        ),
+    };
     let (summary, description) = get_summary_and_description(&item_method.attrs)?;
     let summary = optional_literal(&summary);
     let description = optional_literal(&description);
@@ -383,17 +425,66 @@ fn generate_operation(
             None => quote!(::std::option::Option::None),
         };
 
-        parse_args.push(quote! {
-            let mut param_opts = #crate_name::ExtractParamOptions {
-                name: #extract_param_name,
-                ignore_case: #ignore_case,
-                default_value: #default_value,
-                example_value: #example_value,
-                explode: #explode,
-                style: #style,
-            };
+        let max_items = match &operation_param.max_items {
+            Some(max_items) => quote!(::std::option::Option::Some(#max_items)),
+            None => quote!(::std::option::Option::None),
+        };
+        let max_raw_length = match &operation_param.max_raw_length {
+            Some(max_raw_length) => quote!(::std::option::Option::Some(#max_raw_length)),
+            None => quote!(::std::option::Option::None),
+        };
+        let trim_values = operation_param.trim_values.unwrap_or(true);
+        let flatten_commas = operation_param.flatten_commas.unwrap_or(false);
+
+        let max_items_update_meta = operation_param
+            .max_items
+            .map(|max_items| quote!(schema.max_items = ::std::option::Option::Some(#max_items);))
+            .unwrap_or_default();
+
+        // overriding the content type only makes sense for payloads, so the
+        // extraction goes directly through `ParsePayload` instead of
+        // `ApiExtractor`
+        let do_extract = match &operation_param.content_type {
+            Some(content_type) => quote! {
+                match request.content_type() {
+                    ::std::option::Option::Some(content_type) => {
+                        if content_type
+                            .split(';')
+                            .next()
+                            .map(str::trim)
+                            .is_some_and(|mime| mime.eq_ignore_ascii_case(#content_type))
+                        {
+                            <#arg_ty as #crate_name::payload::ParsePayload>::from_request(&request, &mut body).await
+                        } else {
+                            ::std::result::Result::Err(::std::convert::Into::into(#crate_name::error::ContentTypeError::NotSupported {
+                                content_type: ::std::string::ToString::to_string(content_type),
+                            }))
+                        }
+                    }
+                    ::std::option::Option::None => {
+                        ::std::result::Result::Err(::std::convert::Into::into(#crate_name::error::ContentTypeError::ExpectContentType))
+                    }
+                }
+            },
+            None => quote! {
+                {
+                    let param_opts = #crate_name::ExtractParamOptions::new(#extract_param_name)
+                        .with_ignore_case(#ignore_case)
+                        .with_default_value(#default_value)
+                        .with_example_value(#example_value)
+                        .with_explode(#explode)
+                        .with_style(#style)
+                        .with_max_items(#max_items)
+                        .with_max_raw_length(#max_raw_length)
+                        .with_trim_values(#trim_values)
+                        .with_flatten_commas(#flatten_commas);
+                    <#arg_ty as #crate_name::ApiExtractor>::from_request(&request, &mut body, param_opts).await
+                }
+            },
+        };
 
-            let #pname = match <#arg_ty as #crate_name::ApiExtractor>::from_request(&request, &mut body, param_opts).await {
+        parse_args.push(quote! {
+            let #pname = match #do_extract {
                 ::std::result::Result::Ok(value) => value,
                 ::std::result::Result::Err(err) if <#res_ty as #crate_name::ApiResponse>::BAD_REQUEST_HANDLER => {
                     let res = <#res_ty as #crate_name::ApiResponse>::from_parse_request_error(err);
@@ -408,34 +499,68 @@ fn generate_operation(
         // param meta
         let param_desc = optional_literal_string(&param_description);
         let deprecated = operation_param.deprecated;
+        let param_component = match &operation_param.component {
+            Some(component) => quote!(::std::option::Option::Some(
+                ::std::string::ToString::to_string(#component)
+            )),
+            None => quote!(::std::option::Option::None),
+        };
+        let make_meta_param = quote! {{
+            let mut original_schema = <#arg_ty as #crate_name::ApiExtractor>::param_schema_ref().unwrap();
+
+            let mut patch_schema = {
+                let mut schema = #crate_name::registry::MetaSchema::ANY;
+                schema.default = #param_meta_default;
+                schema.example = #param_meta_example;
+                #validators_update_meta
+                #max_items_update_meta
+                schema
+            };
+
+            #crate_name::registry::MetaOperationParam {
+                name: ::std::string::ToString::to_string(#param_name),
+                schema: original_schema.merge(patch_schema),
+                in_type: <#arg_ty as #crate_name::ApiExtractor>::param_in().unwrap(),
+                description: #param_desc,
+                required: <#arg_ty as #crate_name::ApiExtractor>::PARAM_IS_REQUIRED && !#has_default,
+                deprecated: #deprecated,
+                explode: #explode,
+                allow_empty_value: <#arg_ty as #crate_name::ApiExtractor>::PARAM_ALLOW_EMPTY_VALUE,
+                style: #style,
+                component: #param_component,
+            }
+        }};
         params_meta.push(quote! {
             if <#arg_ty as #crate_name::ApiExtractor>::TYPES.contains(&#crate_name::ApiExtractorType::Parameter) {
-                let mut original_schema = <#arg_ty as #crate_name::ApiExtractor>::param_schema_ref().unwrap();
-
-                let mut patch_schema = {
-                    let mut schema = #crate_name::registry::MetaSchema::ANY;
-                    schema.default = #param_meta_default;
-                    schema.example = #param_meta_example;
-                    #validators_update_meta
-                    schema
-                };
-
-                let meta_param = #crate_name::registry::MetaOperationParam {
-                    name: ::std::string::ToString::to_string(#param_name),
-                    schema: original_schema.merge(patch_schema),
-                    in_type: <#arg_ty as #crate_name::ApiExtractor>::param_in().unwrap(),
-                    description: #param_desc,
-                    required: <#arg_ty as #crate_name::ApiExtractor>::PARAM_IS_REQUIRED && !#has_default,
-                    deprecated: #deprecated,
-                    explode: #explode,
-                    style: #style,
-                };
-                params.push(meta_param);
+                match <#arg_ty as #crate_name::ApiExtractor>::expanded_params() {
+                    ::std::option::Option::Some(expanded) => {
+                        ::std::iter::Extend::extend(&mut params, expanded)
+                    }
+                    ::std::option::Option::None => params.push(#make_meta_param),
+                }
             }
         });
+        if let Some(component) = &operation_param.component {
+            ctx.register_items.push(quote! {
+                if <#arg_ty as #crate_name::ApiExtractor>::TYPES.contains(&#crate_name::ApiExtractorType::Parameter) {
+                    registry.create_parameter(::std::string::ToString::to_string(#component), #make_meta_param);
+                }
+            });
+        }
 
         // request object meta
         let param_desc = optional_literal(&param_description);
+        let update_content_type = operation_param
+            .content_type
+            .as_ref()
+            .map(|content_type| {
+                quote! {
+                    for media_type in &mut request.content {
+                        media_type.content_type = #content_type;
+                    }
+                }
+            })
+            .unwrap_or_default();
         request_meta.push(quote! {
             if <#arg_ty as #crate_name::ApiExtractor>::TYPES.contains(&#crate_name::ApiExtractorType::RequestObject) {
                 request = <#arg_ty as #crate_name::ApiExtractor>::request_meta();
@@ -443,6 +568,7 @@ fn generate_operation(
                     if request.description.is_none() {
                         request.description = #param_desc;
                     }
+                    #update_content_type
                 }
             }
         });
@@ -489,27 +615,30 @@ fn generate_operation(
 
     for method in &methods {
         let http_method = method.to_http_method();
-        let set_operation_id = operation_id.as_ref().map(|operation_id| {
-            quote! {
-                let ep = #crate_name::__private::poem::EndpointExt::after(ep, |mut res| async move {
-                    let operator_id = #crate_name::OperationId(#operation_id);
-                    match &mut res {
-                        ::std::result::Result::Ok(resp) => resp.set_data(operator_id),
-                        ::std::result::Result::Err(err) => err.set_data(operator_id),
-                    }
-                    res
-                });
-            }
-        });
+        let effective_operation_id = derive_operation_id(**method);
+        let set_operation_id = quote! {
+            let ep = #crate_name::__private::poem::EndpointExt::after(ep, |mut res| async move {
+                let operator_id = #crate_name::OperationId(#effective_operation_id);
+                match &mut res {
+                    ::std::result::Result::Ok(resp) => resp.set_data(operator_id),
+                    ::std::result::Result::Err(err) => err.set_data(operator_id),
+                }
+                res
+            });
+        };
 
         ctx.add_routes.push(quote! {
             route_table.entry(#new_path)
                 .or_default()
                 .insert(#crate_name::__private::poem::http::Method::#http_method, {
                     let api_obj = ::std::clone::Clone::clone(&api_obj);
+                    let route_template = #crate_name::RouteTemplate(#oai_path);
                     let ep = #crate_name::__private::poem::endpoint::make(move |request| {
                         let api_obj = ::std::clone::Clone::clone(&api_obj);
+                        let route_template = ::std::clone::Clone::clone(&route_template);
                         async move {
+                            let mut request = request;
+                            request.extensions_mut().insert(route_template);
                             let (request, mut body) = request.split();
                             #(#parse_args)*
                             let res = api_obj.#fn_ident(#(#use_args),*).await;
@@ -536,7 +665,6 @@ fn generate_operation(
             .push(quote!(#crate_name::Tags::register(&#tag, registry);));
         tag_names.push(quote!(#crate_name::Tags::name(&#tag)));
     }
-    let operation_id = optional_literal(&operation_id);
     let external_docs = match external_docs {
         Some(external_docs) => {
             let s = external_docs.to_token_stream(crate_name);
@@ -545,6 +673,20 @@ fn generate_operation(
         None => quote!(::std::option::Option::None),
     };
 
+    // operation-specific servers
+    let servers = servers
+        .iter()
+        .map(|url| {
+            quote! {
+                #crate_name::registry::MetaServer {
+                    url: ::std::string::ToString::to_string(#url),
+                    description: ::std::option::Option::None,
+                    variables: ::std::default::Default::default(),
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
     // extra request headers
     let mut update_extra_request_headers = Vec::new();
     for header in api_args.request_headers.iter().chain(&request_headers) {
@@ -565,7 +707,9 @@ fn generate_operation(
                 required: <#ty as #crate_name::types::Type>::IS_REQUIRED,
                 deprecated: #deprecated,
                 explode: true,
+                allow_empty_value: false,
                 style: None,
+                component: None,
             });
         });
     }
@@ -626,6 +770,7 @@ fn generate_operation(
     if !hidden {
         for method in &methods {
             let http_method = method.to_http_method();
+            let effective_operation_id = derive_operation_id(**method);
             let meta_operation = quote! {
                 #crate_name::registry::MetaOperation {
                     tags: ::std::vec![#(#tag_names),*],
@@ -649,14 +794,18 @@ fn generate_operation(
                         #(#update_extra_response_headers)*
                         meta
                     },
-                    deprecated: #deprecated,
+                    deprecated: #is_deprecated,
+                    replaced_by: #replaced_by,
+                    deprecated_since: #deprecated_since,
+                    deprecated_remove_in: #deprecated_remove_in,
                     security: {
                         let mut security = ::std::vec![];
                         #(#security)*
                         security
                     },
-                    operation_id: #operation_id,
+                    operation_id: ::std::option::Option::Some(#effective_operation_id),
                     code_samples: ::std::vec![#(#code_samples),*],
+                    servers: ::std::vec![#(#servers),*],
                 }
             };
             ctx.operations.push((oai_path.clone(), meta_operation));
@@ -298,3 +298,44 @@ pub(crate) struct CodeSample {
     pub(crate) label: Option<String>,
     pub(crate) source: syn::Expr,
 }
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Deprecated {
+    pub(crate) is_deprecated: bool,
+    pub(crate) replaced_by: Option<String>,
+    pub(crate) since: Option<String>,
+    pub(crate) remove_in: Option<String>,
+}
+
+impl FromMeta for Deprecated {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Deprecated {
+            is_deprecated: true,
+            ..Deprecated::default()
+        })
+    }
+
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Ok(Deprecated {
+            is_deprecated: value,
+            ..Deprecated::default()
+        })
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct DeprecatedArgs {
+            replaced_by: Option<String>,
+            since: Option<String>,
+            remove_in: Option<String>,
+        }
+
+        let args = DeprecatedArgs::from_list(items)?;
+        Ok(Deprecated {
+            is_deprecated: true,
+            replaced_by: args.replaced_by,
+            since: args.since,
+            remove_in: args.remove_in,
+        })
+    }
+}
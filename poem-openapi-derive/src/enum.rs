@@ -18,9 +18,12 @@ use crate::{
 struct EnumItem {
     ident: Ident,
     fields: Fields<Ignored>,
+    discriminant: Option<syn::Expr>,
 
     #[darling(default)]
     rename: Option<String>,
+    #[darling(default)]
+    other: bool,
 }
 
 #[derive(FromDeriveInput)]
@@ -42,6 +45,10 @@ struct EnumArgs {
     deprecated: bool,
     #[darling(default)]
     external_docs: Option<ExternalDocument>,
+    #[darling(default)]
+    bitmask: bool,
+    #[darling(default)]
+    derive_max_length: bool,
 }
 
 pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
@@ -58,8 +65,30 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let mut enum_items = Vec::new();
     let mut ident_to_item = Vec::new();
     let mut item_to_ident = Vec::new();
+    let mut item_names = Vec::new();
+    let mut max_item_name_len = 0;
+    let mut other_item: Option<&Ident> = None;
 
     for variant in e {
+        if variant.other {
+            if other_item.is_some() {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    "Only one variant can be marked with `other`.",
+                )
+                .into());
+            }
+            if variant.fields.len() != 1 {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    "The `other` variant must have a single `String` field.",
+                )
+                .into());
+            }
+            other_item = Some(&variant.ident);
+            continue;
+        }
+
         if !variant.fields.is_empty() {
             return Err(Error::new_spanned(
                 &variant.ident,
@@ -76,6 +105,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             apply_rename_rule_variant(args.rename_all, variant.ident.unraw().to_string())
         });
 
+        max_item_name_len = max_item_name_len.max(oai_item_name.len());
+        item_names.push(quote!(#oai_item_name));
         enum_items.push(quote!(#crate_name::types::ToJSON::to_json(&#ident::#item_ident).unwrap()));
         ident_to_item.push(quote!(#ident::#item_ident => #oai_item_name));
         item_to_ident
@@ -116,6 +147,73 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     } else {
         None
     };
+
+    let bitmask_impl = if args.bitmask {
+        let mut bits = Vec::new();
+        let mut bit_arms = Vec::new();
+        let mut from_bit_items = Vec::new();
+
+        for variant in e {
+            let item_ident = &variant.ident;
+            let disc = variant.discriminant.as_ref().ok_or_else(|| {
+                Error::new_spanned(
+                    item_ident,
+                    "Bitmask enums require an explicit discriminant for each variant.",
+                )
+            })?;
+            bits.push(quote!((#disc) as u64));
+            bit_arms.push(quote!(#ident::#item_ident => (#disc) as u64));
+            from_bit_items.push(quote! {
+                if bit == (#disc) as u64 {
+                    return ::std::option::Option::Some(#ident::#item_ident);
+                }
+            });
+        }
+
+        Some(quote! {
+            impl #crate_name::types::EnumBitmask for #ident {
+                const BITS: &'static [u64] = &[#(#bits),*];
+
+                fn bit(&self) -> u64 {
+                    match self {
+                        #(#bit_arms),*
+                    }
+                }
+
+                fn from_bit(bit: u64) -> ::std::option::Option<Self> {
+                    #(#from_bit_items)*
+                    ::std::option::Option::None
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let (other_to_json, other_from_json, other_from_parameter) = match other_item {
+        Some(other_ident) => (
+            Some(quote! {
+                #ident::#other_ident(value) => return ::std::option::Option::Some(#crate_name::__private::serde_json::Value::String(::std::clone::Clone::clone(value))),
+            }),
+            quote!(_ => ::std::result::Result::Ok(#ident::#other_ident(::std::clone::Clone::clone(item)))),
+            quote!(_ => ::std::result::Result::Ok(#ident::#other_ident(::std::string::ToString::to_string(value)))),
+        ),
+        None => (
+            None,
+            quote!(_ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value))),
+            quote!(_ => ::std::result::Result::Err(#crate_name::types::ParseError::custom(::std::format!(
+                "unknown value \"{}\", expected one of: {}",
+                value,
+                <Self as #crate_name::types::EnumItems>::ITEMS.join(", ")
+            )))),
+        ),
+    };
+
+    let max_length = if args.derive_max_length {
+        quote!(::std::option::Option::Some(#max_item_name_len))
+    } else {
+        quote!(::std::option::Option::None)
+    };
     let description = optional_literal(&description);
     let deprecated = args.deprecated;
     let external_docs = match &args.external_docs {
@@ -152,6 +250,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     external_docs: #external_docs,
                     deprecated: #deprecated,
                     enum_items: ::std::vec![#(#enum_items),*],
+                    max_length: #max_length,
                     ..#crate_name::registry::MetaSchema::new("string")
                 });
             }
@@ -167,7 +266,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 match &value {
                     #crate_name::__private::serde_json::Value::String(item) => match item.as_str() {
                         #(#item_to_ident,)*
-                        _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+                        #other_from_json,
                     }
                     _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
                 }
@@ -178,7 +277,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             fn parse_from_parameter(value: &str) -> #crate_name::types::ParseResult<Self> {
                 match value {
                     #(#item_to_ident,)*
-                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::custom("Expect a valid enumeration value.")),
+                    #other_from_parameter,
                 }
             }
         }
@@ -186,7 +285,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         impl #crate_name::types::ToJSON for #ident {
             fn to_json(&self) -> ::std::option::Option<#crate_name::__private::serde_json::Value> {
                 let name = match self {
-                    #(#ident_to_item),*
+                    #(#ident_to_item,)*
+                    #other_to_json
                 };
                 ::std::option::Option::Some(#crate_name::__private::serde_json::Value::String(::std::string::ToString::to_string(name)))
             }
@@ -205,7 +305,13 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             }
         }
 
+        impl #crate_name::types::EnumItems for #ident {
+            const ITEMS: &'static [&'static str] = &[#(#item_names),*];
+        }
+
         #remote_conversion
+
+        #bitmask_impl
     };
 
     Ok(expanded)
@@ -1,5 +1,5 @@
 use darling::{
-    FromDeriveInput, FromField,
+    FromDeriveInput, FromField, FromMeta,
     ast::Data,
     util::{Ignored, SpannedValue},
 };
@@ -8,12 +8,19 @@ use quote::quote;
 use syn::{Attribute, DeriveInput, Error, Generics, Path, Type, ext::IdentExt};
 
 use crate::{
-    common_args::{DefaultValue, ExternalDocument, RenameRule, apply_rename_rule_field},
+    common_args::{
+        DefaultValue, Deprecated, ExternalDocument, RenameRule, apply_rename_rule_field,
+    },
     error::GeneratorResult,
     utils::{create_object_name, get_crate_name, get_description, optional_literal},
     validators::Validators,
 };
 
+#[derive(FromMeta)]
+struct RequiredIf {
+    feature: String,
+}
+
 #[derive(FromField)]
 #[darling(attributes(oai), forward_attrs(doc))]
 struct ObjectField {
@@ -31,16 +38,20 @@ struct ObjectField {
     #[darling(default)]
     write_only: bool,
     #[darling(default)]
-    deprecated: bool,
+    deprecated: Deprecated,
     #[darling(default)]
     nullable: bool,
     #[darling(default)]
     read_only: bool,
     #[darling(default)]
+    comment: Option<String>,
+    #[darling(default)]
     validator: Option<Validators>,
     #[darling(default)]
     flatten: SpannedValue<bool>,
     #[darling(default)]
+    inline: SpannedValue<bool>,
+    #[darling(default)]
     skip_serializing_if_is_none: bool,
     #[darling(default)]
     skip_serializing_if_is_empty: bool,
@@ -50,6 +61,14 @@ struct ObjectField {
     serialize_with: Option<Path>,
     #[darling(default)]
     deserialize_with: Option<Path>,
+    #[darling(default)]
+    to_uppercase: SpannedValue<bool>,
+    #[darling(default)]
+    to_lowercase: SpannedValue<bool>,
+    #[darling(default)]
+    trim: bool,
+    #[darling(default)]
+    required_if: Option<SpannedValue<RequiredIf>>,
 }
 
 #[derive(FromDeriveInput)]
@@ -65,6 +84,8 @@ struct ObjectArgs {
     #[darling(default)]
     rename: Option<String>,
     #[darling(default)]
+    title: Option<String>,
+    #[darling(default)]
     rename_all: Option<RenameRule>,
     #[darling(default)]
     deprecated: bool,
@@ -79,10 +100,16 @@ struct ObjectArgs {
     #[darling(default)]
     example: bool,
     #[darling(default)]
+    example_from_default: bool,
+    #[darling(default)]
+    comment: Option<String>,
+    #[darling(default)]
     external_docs: Option<ExternalDocument>,
     #[darling(default)]
     remote: Option<Path>,
     #[darling(default)]
+    numbers_as_strings: bool,
+    #[darling(default)]
     skip_serializing_if_is_none: bool,
     #[darling(default)]
     skip_serializing_if_is_empty: bool,
@@ -112,6 +139,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let mut meta_fields = Vec::new();
     let mut additional_properties = quote! { ::std::option::Option::None };
     let mut required_fields = Vec::new();
+    let mut flattened_unions = Vec::new();
     let object_name = create_object_name(&crate_name, &oai_typename, &args.generics);
 
     for field in &s.fields {
@@ -128,7 +156,15 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         let skip_serializing_if_is_empty =
             field.skip_serializing_if_is_empty || args.skip_serializing_if_is_empty;
         let skip_serializing_if = &field.skip_serializing_if;
-        let deprecated = field.deprecated;
+        let deprecated = field.deprecated.is_deprecated;
+        let deprecated_since = match &field.deprecated.since {
+            Some(since) => quote!(::std::option::Option::Some(#since)),
+            None => quote!(::std::option::Option::None),
+        };
+        let deprecated_remove_in = match &field.deprecated.remove_in {
+            Some(remove_in) => quote!(::std::option::Option::Some(#remove_in)),
+            None => quote!(::std::option::Option::None),
+        };
 
         if field.skip {
             deserialize_fields.push(quote! {
@@ -151,10 +187,53 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         });
         let field_description = get_description(&field.attrs)?;
         let field_description = optional_literal(&field_description);
+        let field_comment = optional_literal(&field.comment);
         let validators = field.validator.clone().unwrap_or_default();
         let validators_checker = validators.create_obj_field_checker(&crate_name, &field_name)?;
         let validators_update_meta = validators.create_update_meta(&crate_name)?;
 
+        if let Some(required_if) = &field.required_if {
+            if *field.flatten {
+                return Err(Error::new(
+                    required_if.span(),
+                    "required_if cannot be used with flatten.",
+                )
+                .into());
+            }
+            if field.default.is_some() {
+                return Err(Error::new(
+                    required_if.span(),
+                    "required_if cannot be used with default.",
+                )
+                .into());
+            }
+            if read_only {
+                return Err(Error::new(
+                    required_if.span(),
+                    "required_if cannot be used with read_only.",
+                )
+                .into());
+            }
+        }
+        // evaluated with `cfg!` in the generated code, so the feature is
+        // resolved against the crate deriving `Object`
+        let required_if_check = field.required_if.as_ref().map(|required_if| {
+            let feature = &required_if.feature;
+            quote! {
+                if ::std::cfg!(feature = #feature)
+                    && ::std::matches!(
+                        obj.get(#field_name),
+                        ::std::option::Option::None
+                            | ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Null)
+                    )
+                {
+                    return ::std::result::Result::Err(#crate_name::types::ParseError::custom(
+                        ::std::format!("field `{}` is required", #field_name),
+                    ));
+                }
+            }
+        });
+
         fields.push(field_ident);
 
         let create_default_value = match (&field.default, &args.default) {
@@ -216,6 +295,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     deserialize_fields.push(quote! {
                         #[allow(non_snake_case)]
                         let #field_ident: #field_ty = {
+                            #required_if_check
                             let value = #deserialize_function(obj.remove(#field_name))
                                 .map_err(#crate_name::types::ParseError::propagate)?;
                             #validators_checker
@@ -264,9 +344,48 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     None => quote! { #crate_name::types::ToJSON::to_json },
                 };
 
+                if *field.to_uppercase && *field.to_lowercase {
+                    return Err(Error::new(
+                        field.to_uppercase.span(),
+                        "to_uppercase and to_lowercase cannot be used together.",
+                    )
+                    .into());
+                }
+                let mut output_transforms = Vec::new();
+                if field.trim {
+                    output_transforms.push(quote! {
+                        *value = ::std::string::ToString::to_string(value.trim());
+                    });
+                }
+                if *field.to_uppercase {
+                    output_transforms.push(quote! {
+                        *value = value.to_uppercase();
+                    });
+                }
+                if *field.to_lowercase {
+                    output_transforms.push(quote! {
+                        *value = value.to_lowercase();
+                    });
+                }
+                let apply_output_transforms = if !output_transforms.is_empty() {
+                    Some(quote! {
+                        if let #crate_name::__private::serde_json::Value::String(value) = &mut value {
+                            #(#output_transforms)*
+                        }
+                    })
+                } else {
+                    None
+                };
+                let value_pattern = if apply_output_transforms.is_some() {
+                    quote!(mut value)
+                } else {
+                    quote!(value)
+                };
+
                 serialize_fields.push(quote! {
                     if #check_is_none && #check_is_empty && #check_if {
-                        if let ::std::option::Option::Some(value) = #serialize_function(&self.#field_ident) {
+                        if let ::std::option::Option::Some(#value_pattern) = #serialize_function(&self.#field_ident) {
+                            #apply_output_transforms
                             object.insert(::std::string::ToString::to_string(#field_name), value);
                         }
                     }
@@ -287,10 +406,39 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             _ => quote!(::std::option::Option::None),
         };
 
-        register_types.push(quote!(<#field_ty as #crate_name::types::Type>::register(registry);));
+        if *field.inline && *field.flatten {
+            return Err(Error::new(
+                field.inline.span(),
+                "inline and flatten cannot be used together.",
+            )
+            .into());
+        }
+
+        if !*field.inline {
+            register_types
+                .push(quote!(<#field_ty as #crate_name::types::Type>::register(registry);));
+        }
+        let original_schema = if *field.inline {
+            // resolve the schema in a private registry so that the named
+            // component is emitted inline instead of as a `$ref`, while any
+            // nested schemas it depends on are still registered
+            quote! {{
+                let mut fake_registry = #crate_name::registry::Registry::new();
+                let schema = fake_registry.create_fake_schema::<#field_ty>();
+                let name = <#field_ty as #crate_name::types::Type>::name();
+                for (schema_name, schema) in fake_registry.schemas {
+                    if schema_name != ::std::convert::AsRef::<str>::as_ref(&name) {
+                        registry.schemas.entry(schema_name).or_insert(schema);
+                    }
+                }
+                #crate_name::registry::MetaSchemaRef::Inline(::std::boxed::Box::new(schema))
+            }}
+        } else {
+            quote!(<#field_ty as #crate_name::types::Type>::schema_ref())
+        };
         if !*field.flatten {
             meta_fields.push(quote! {{
-                let original_schema = <#field_ty as #crate_name::types::Type>::schema_ref();
+                let original_schema = #original_schema;
                 let patch_schema = {
                     let mut schema = #crate_name::registry::MetaSchema::ANY;
                     schema.default = #field_meta_default;
@@ -298,10 +446,13 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     schema.read_only = #read_only;
                     schema.write_only = #write_only;
                     schema.deprecated = #deprecated;
+                    schema.deprecated_since = #deprecated_since;
+                    schema.deprecated_remove_in = #deprecated_remove_in;
 
                     if let ::std::option::Option::Some(field_description) = #field_description {
                         schema.description = ::std::option::Option::Some(field_description);
                     }
+                    schema.comment = #field_comment;
                     #validators_update_meta
                     schema
                 };
@@ -310,8 +461,15 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             }});
 
             let has_default = field.default.is_some();
+            let required_by_feature = match &field.required_if {
+                Some(required_if) => {
+                    let feature = &required_if.feature;
+                    quote!(::std::cfg!(feature = #feature))
+                }
+                None => quote!(false),
+            };
             required_fields.push(quote! {
-                if <#field_ty>::IS_REQUIRED && !#has_default {
+                if (<#field_ty>::IS_REQUIRED || #required_by_feature) && !#has_default {
                     fields.push(#field_name);
                 }
             });
@@ -319,6 +477,22 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             meta_fields.push(quote! {
                 fields.extend(registry.create_fake_schema::<#field_ty>().properties);
             });
+            // a flattened union has no properties of its own; carry its
+            // `oneOf` into the parent through `allOf` so the variants are not
+            // lost from the schema
+            flattened_unions.push(quote! {
+                let flattened = registry.create_fake_schema::<#field_ty>();
+                if !flattened.one_of.is_empty() || !flattened.any_of.is_empty() {
+                    schemas.push(#crate_name::registry::MetaSchemaRef::Inline(
+                        ::std::boxed::Box::new(#crate_name::registry::MetaSchema {
+                            one_of: flattened.one_of,
+                            any_of: flattened.any_of,
+                            discriminator: flattened.discriminator,
+                            ..#crate_name::registry::MetaSchema::ANY
+                        }),
+                    ));
+                }
+            });
             additional_properties = quote! {
                 registry.create_fake_schema::<#field_ty>().additional_properties
             };
@@ -329,6 +503,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     }
 
     let description = optional_literal(&description);
+    let comment = optional_literal(&args.comment);
     let deprecated = args.deprecated;
     let external_docs = match &args.external_docs {
         Some(external_docs) => {
@@ -337,11 +512,36 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         }
         None => quote!(::std::option::Option::None),
     };
+    let (parse_null_arm, meta_default) = match &args.default {
+        Some(DefaultValue::Default) => (
+            quote! {
+                #crate_name::__private::serde_json::Value::Null => ::std::result::Result::Ok(<Self as ::std::default::Default>::default()),
+            },
+            quote!(#crate_name::types::ToJSON::to_json(
+                &<Self as ::std::default::Default>::default()
+            )),
+        ),
+        Some(DefaultValue::Function(func_name)) => (
+            quote! {
+                #crate_name::__private::serde_json::Value::Null => ::std::result::Result::Ok(#func_name()),
+            },
+            quote!(#crate_name::types::ToJSON::to_json(&#func_name())),
+        ),
+        None => (quote!(), quote!(::std::option::Option::None)),
+    };
+    // the title defaults to the type name so that generated clients get a
+    // readable name even for inline schemas
+    let meta_title = match &args.title {
+        Some(title) => quote!(::std::string::ToString::to_string(#title)),
+        None => object_name.clone(),
+    };
     let meta = quote! {
         #crate_name::registry::MetaSchema {
-            title: ::std::option::Option::Some(#object_name),
+            title: ::std::option::Option::Some(#meta_title),
             description: #description,
+            comment: #comment,
             external_docs: #external_docs,
+            default: #meta_default,
             required: {
                 #[allow(unused_mut)]
                 let mut fields = ::std::vec::Vec::new();
@@ -354,6 +554,12 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 fields
             },
             additional_properties: #additional_properties,
+            all_of: {
+                #[allow(unused_mut)]
+                let mut schemas = ::std::vec::Vec::new();
+                #(#flattened_unions)*
+                schemas
+            },
             deprecated: #deprecated,
             ..#crate_name::registry::MetaSchema::new("object")
         }
@@ -367,6 +573,25 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     } else {
         None
     };
+    let to_json_tail = if args.numbers_as_strings {
+        quote! {
+            let mut value = #crate_name::__private::serde_json::Value::Object(object);
+            #crate_name::__private::numbers_to_strings(&mut value);
+            ::std::option::Option::Some(value)
+        }
+    } else {
+        quote! {
+            ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(object))
+        }
+    };
+
+    if args.example && args.example_from_default {
+        return Err(Error::new_spanned(
+            ident,
+            "The `example` and `example_from_default` attributes cannot be enabled both.",
+        )
+        .into());
+    }
 
     let (example, where_clause) = if args.example {
         let new_where_clause = match where_clause {
@@ -385,6 +610,23 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             },
             new_where_clause,
         )
+    } else if args.example_from_default {
+        let new_where_clause = match where_clause {
+            Some(where_clause) => {
+                if where_clause.predicates.trailing_punct() {
+                    quote! { #where_clause Self: ::std::default::Default }
+                } else {
+                    quote! { #where_clause, Self: ::std::default::Default }
+                }
+            }
+            None => quote! { where Self: ::std::default::Default },
+        };
+        (
+            quote! {
+                <Self as #crate_name::types::ToJSON>::to_json(&<Self as ::std::default::Default>::default())
+            },
+            new_where_clause,
+        )
     } else {
         (
             quote! { ::std::option::Option::None },
@@ -437,6 +679,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                         #deny_unknown_fields
                         ::std::result::Result::Ok(Self { #(#fields),* })
                     }
+                    #parse_null_arm
                     _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
                 }
             }
@@ -446,7 +689,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             fn to_json(&self) -> ::std::option::Option<#crate_name::__private::serde_json::Value> {
                 let mut object = #crate_name::__private::serde_json::Map::new();
                 #(#serialize_fields)*
-                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(object))
+                #to_json_tail
             }
         }
 
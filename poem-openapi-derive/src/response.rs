@@ -37,6 +37,8 @@ struct ResponseItem {
     #[darling(default)]
     status_range: Option<String>,
     #[darling(default)]
+    default: bool,
+    #[darling(default)]
     content_type: Option<String>,
     #[darling(default, multiple, rename = "header")]
     headers: Vec<ExtraHeader>,
@@ -89,6 +91,14 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             .into());
         }
 
+        if variant.default && (variant.status.is_some() || variant.status_range.is_some()) {
+            return Err(Error::new(
+                variant.ident.span(),
+                "default cannot be used with status or status_range.",
+            )
+            .into());
+        }
+
         let item_ident = &variant.ident;
         let item_description = get_description(&variant.attrs)?;
         let item_description = optional_literal(&item_description);
@@ -290,6 +300,46 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     schemas.push(media_ty);
                 }
             }
+            1 if variant.default => {
+                // #[oai(default)]
+                // Item(media)
+                let media_ty = &values[0].ty;
+                let (update_response_content_type, update_meta_content_type) = update_content_type(
+                    &crate_name,
+                    variant.content_type.as_deref(),
+                    variant.actual_type.as_ref(),
+                );
+                into_responses.push(quote! {
+                    #ident::#item_ident(media, #(#match_headers),*) => {
+                        #[allow(unused_mut)]
+                        let mut resp = #crate_name::__private::poem::IntoResponse::into_response(media);
+                        #(#with_headers)*
+                        #update_response_content_type
+                        resp
+                    }
+                });
+                error_messages.push(quote! {
+                    #ident::#item_ident(media, #(#match_headers),*) => #item_description,
+                });
+                responses_meta.push(quote! {
+                    #crate_name::registry::MetaResponse {
+                        description: #item_description.unwrap_or_default(),
+                        status: ::std::option::Option::None,
+                        status_range: ::std::option::Option::None,
+                        content: {
+                            let mut content = <#media_ty as #crate_name::ResponseContent>::media_types();
+                            #update_meta_content_type
+                            content
+                        },
+                        headers: ::std::vec![#(#meta_headers),*],
+                    }
+                });
+                if let Some(actual_type) = variant.actual_type.as_ref() {
+                    schemas.push(actual_type);
+                } else {
+                    schemas.push(media_ty);
+                }
+            }
             1 => {
                 // #[oai(status = 200)]
                 // Item(media)
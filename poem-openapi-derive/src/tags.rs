@@ -10,7 +10,7 @@ use syn::{Attribute, DeriveInput, Error, ext::IdentExt};
 use crate::{
     common_args::{ExternalDocument, RenameRule, apply_rename_rule_variant},
     error::GeneratorResult,
-    utils::{get_crate_name, get_description, optional_literal},
+    utils::{get_crate_name, get_description},
 };
 
 #[derive(FromVariant)]
@@ -67,8 +67,12 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         let oai_item_name = variant.rename.clone().unwrap_or_else(|| {
             apply_rename_rule_variant(args.rename_all, item_ident.unraw().to_string())
         });
-        let description = get_description(&variant.attrs)?;
-        let description = optional_literal(&description);
+        let description = match get_description(&variant.attrs)? {
+            Some(description) => {
+                quote!(::std::option::Option::Some(::std::string::ToString::to_string(#description)))
+            }
+            None => quote!(::std::option::Option::None),
+        };
         let external_docs = match &variant.external_docs {
             Some(external_docs) => {
                 let s = external_docs.to_token_stream(&crate_name);
@@ -78,7 +82,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         };
 
         meta_items.push(quote!(#crate_name::registry::MetaTag {
-            name: #oai_item_name,
+            name: ::std::string::ToString::to_string(#oai_item_name),
             description: #description,
             external_docs: #external_docs,
         }));
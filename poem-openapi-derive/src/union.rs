@@ -63,6 +63,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let mut types = Vec::new();
     let mut from_json = Vec::new();
     let mut to_json = Vec::new();
+    let mut discriminator_arms = Vec::new();
     let mut mapping = Vec::new();
     let mut create_schemas = Vec::new();
     let mut schemas = Vec::new();
@@ -139,6 +140,10 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     });
                 }
 
+                discriminator_arms.push(quote! {
+                    Self::#item_ident(_) => #mapping_name
+                });
+
                 mapping.push(quote! {
                     (#mapping_name, ::std::format!("#/components/schemas/{}", #schema_name))
                 });
@@ -259,6 +264,20 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         }
     };
 
+    let tagged_union_impl = args.discriminator_name.as_ref().map(|discriminator_name| {
+        quote! {
+            impl #impl_generics #crate_name::types::TaggedUnion for #ident #ty_generics #where_clause {
+                const DISCRIMINATOR_NAME: &'static str = #discriminator_name;
+
+                fn discriminator(&self) -> ::std::string::String {
+                    match self {
+                        #(#discriminator_arms),*
+                    }
+                }
+            }
+        }
+    });
+
     let expanded = quote! {
         impl #impl_generics #crate_name::types::Type for #ident #ty_generics #where_clause {
             const IS_REQUIRED: bool = true;
@@ -309,6 +328,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 }
             }
         }
+
+        #tagged_union_impl
     };
 
     Ok(expanded)
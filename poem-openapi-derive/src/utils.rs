@@ -133,6 +133,21 @@ pub(crate) fn convert_oai_path(path: &SpannedValue<String>) -> Result<(String, S
             new_path.push_str(&format!("param{param_count}"));
             param_count += 1;
 
+            if !vars.insert(var) {
+                return Err(Error::new(
+                    path.span(),
+                    format!("Repeated path variable `{}`.", &s[1..]),
+                ));
+            }
+        } else if let Some(var) = s.strip_prefix('*') {
+            oai_path.push_str("/{");
+            oai_path.push_str(var);
+            oai_path.push('}');
+
+            new_path.push_str("/*");
+            new_path.push_str(&format!("param{param_count}"));
+            param_count += 1;
+
             if !vars.insert(var) {
                 return Err(Error::new(
                     path.span(),
@@ -42,6 +42,14 @@ pub(crate) struct Validators {
     max_properties: Option<SpannedValue<usize>>,
     #[darling(default)]
     min_properties: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    max_cols: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    min_cols: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    max_rows: Option<SpannedValue<usize>>,
+    #[darling(default)]
+    min_rows: Option<SpannedValue<usize>>,
 
     // custom validators for elements
     #[darling(default, multiple)]
@@ -150,6 +158,30 @@ impl Validators {
             container_validators.push(quote!(#crate_name::validation::MinProperties::new(#value)));
         }
 
+        if let Some(value) = self.max_rows {
+            // row counterpart of `maxItems`; applies to matrix types
+            let value = &*value;
+            container_validators.push(quote!(#crate_name::validation::MaxRows::new(#value)));
+        }
+
+        if let Some(value) = self.min_rows {
+            // row counterpart of `minItems`; applies to matrix types
+            let value = &*value;
+            container_validators.push(quote!(#crate_name::validation::MinRows::new(#value)));
+        }
+
+        if let Some(value) = self.max_cols {
+            // column counterpart of `maxItems`; applies to matrix types
+            let value = &*value;
+            container_validators.push(quote!(#crate_name::validation::MaxCols::new(#value)));
+        }
+
+        if let Some(value) = self.min_cols {
+            // column counterpart of `minItems`; applies to matrix types
+            let value = &*value;
+            container_validators.push(quote!(#crate_name::validation::MinCols::new(#value)));
+        }
+
         Ok(ValidatorsTokenStream {
             container_validators,
             elem_validators,
@@ -234,7 +234,9 @@ fn generate_operation(
                     required: <#arg_ty as #crate_name::ApiExtractor>::PARAM_IS_REQUIRED,
                     deprecated: #deprecated,
                     explode: #explode,
-                    style: ::std::option::Option::None
+                    allow_empty_value: <#arg_ty as #crate_name::ApiExtractor>::PARAM_ALLOW_EMPTY_VALUE,
+                    style: ::std::option::Option::None,
+                    component: ::std::option::Option::None
                 };
                 params.push(meta_param);
             }
@@ -288,9 +290,13 @@ fn generate_operation(
                         },
                         responses: <#res_ty as #crate_name::ApiResponse>::meta(),
                         deprecated: #deprecated,
+                        replaced_by: ::std::option::Option::None,
+                        deprecated_since: ::std::option::Option::None,
+                        deprecated_remove_in: ::std::option::Option::None,
                         security: ::std::vec![],
                         operation_id: #operation_id,
                         code_samples: ::std::vec![],
+                        servers: ::std::vec![],
                     }
                 }
             },
@@ -12,8 +12,8 @@ use serde::Serialize;
 use crate::{
     payload::Payload,
     registry::{
-        MetaApi, MetaMediaType, MetaOAuthScope, MetaParamIn, MetaRequest, MetaResponse,
-        MetaResponses, MetaSchemaRef, MetaWebhook, Registry,
+        MetaApi, MetaMediaType, MetaOAuthScope, MetaOperationParam, MetaParamIn, MetaRequest,
+        MetaResponse, MetaResponses, MetaSchemaRef, MetaWebhook, Registry,
     },
 };
 
@@ -55,33 +55,80 @@ pub enum ApiExtractorType {
     PoemExtractor,
 }
 
+/// Recursively replaces every JSON number with its string representation.
+///
+/// Used by the `numbers_as_strings` object attribute.
+#[doc(hidden)]
+pub fn numbers_to_strings(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Number(number) => *value = serde_json::Value::String(number.to_string()),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(numbers_to_strings),
+        serde_json::Value::Object(fields) => fields.values_mut().for_each(numbers_to_strings),
+        _ => {}
+    }
+}
+
 #[doc(hidden)]
 #[derive(Clone)]
-pub struct UrlQuery(pub Vec<(String, String)>);
+pub struct UrlQuery {
+    pairs: Vec<(String, String)>,
+    /// Indices into `pairs` keyed by the lowercased parameter name, built
+    /// once so case-insensitive lookups do not rescan the query string.
+    lowercase_index: HashMap<String, Vec<usize>>,
+}
 
 impl Deref for UrlQuery {
     type Target = Vec<(String, String)>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.pairs
     }
 }
 
 impl UrlQuery {
+    /// Create a `UrlQuery` from the parsed query pairs.
+    pub fn new(pairs: Vec<(String, String)>) -> Self {
+        let mut lowercase_index = HashMap::<String, Vec<usize>>::new();
+        for (idx, (name, _)) in pairs.iter().enumerate() {
+            lowercase_index
+                .entry(name.to_ascii_lowercase())
+                .or_default()
+                .push(idx);
+        }
+        Self {
+            pairs,
+            lowercase_index,
+        }
+    }
+
     /// Returns all values with the specified name.
     pub fn get_all<'a, 'b: 'a>(&'b self, name: &'a str) -> impl Iterator<Item = &'b String> + 'a {
-        self.0
+        self.pairs
             .iter()
             .filter(move |(n, _)| n == name)
             .map(|(_, value)| value)
     }
 
+    /// Returns all values whose name matches case-insensitively, in the
+    /// order they appear in the query string.
+    pub fn get_all_ignore_case<'a, 'b: 'a>(
+        &'b self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'b String> + 'a {
+        self.lowercase_index
+            .get(&name.to_ascii_lowercase())
+            .map(|indices| indices.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|idx| &self.pairs[*idx].1)
+    }
+
     /// Returns all values uses a closure to filter the name.
     pub fn get_all_by<'a, 'b: 'a>(
         &'b self,
         mut f: impl FnMut(&str) -> bool + 'a,
     ) -> impl Iterator<Item = &'b String> + 'a {
-        self.0
+        self.pairs
             .iter()
             .filter(move |(n, _)| f(n))
             .map(|(_, value)| value)
@@ -94,7 +141,12 @@ impl UrlQuery {
 }
 
 /// Options for the parameter extractor.
+///
+/// Construct it with [`ExtractParamOptions::new`] and the `with_*` methods;
+/// the struct is `#[non_exhaustive]` so new options can be added without
+/// breaking downstream code.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ExtractParamOptions<T> {
     /// The name of this parameter.
     pub name: &'static str,
@@ -115,6 +167,93 @@ pub struct ExtractParamOptions<T> {
 
     /// The style of the parameter.
     pub style: Option<ParameterStyle>,
+
+    /// The maximum number of values accepted for this parameter.
+    ///
+    /// The limit is checked before the values are parsed.
+    pub max_items: Option<usize>,
+
+    /// The maximum length in bytes of a raw parameter value.
+    ///
+    /// The limit is checked before the value is split into elements, so an
+    /// over-length comma list is rejected without touching its contents.
+    pub max_raw_length: Option<usize>,
+
+    /// Whether to trim whitespace around delimiter-separated values.
+    pub trim_values: bool,
+
+    /// When `explode` is `true`, additionally comma-split each repeated value
+    /// and flatten the result, so `?id=1&id=2,3` yields `[1, 2, 3]`.
+    ///
+    /// This tolerates clients that mix the repeated-key and comma-separated
+    /// conventions; the strict behavior stays the default.
+    pub flatten_commas: bool,
+}
+
+impl<T> ExtractParamOptions<T> {
+    /// Creates options for a parameter with the given name; everything else
+    /// starts from its default.
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            ..Self::default()
+        }
+    }
+
+    /// Sets whether the case of the parameter name is ignored when matching.
+    pub fn with_ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Sets the default value of this parameter.
+    pub fn with_default_value(mut self, default_value: Option<fn() -> T>) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    /// Sets the example value of this parameter.
+    pub fn with_example_value(mut self, example_value: Option<fn() -> T>) -> Self {
+        self.example_value = example_value;
+        self
+    }
+
+    /// Sets whether array values generate separate parameters.
+    pub fn with_explode(mut self, explode: bool) -> Self {
+        self.explode = explode;
+        self
+    }
+
+    /// Sets the style of the parameter.
+    pub fn with_style(mut self, style: Option<ParameterStyle>) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the maximum number of values accepted for this parameter.
+    pub fn with_max_items(mut self, max_items: Option<usize>) -> Self {
+        self.max_items = max_items;
+        self
+    }
+
+    /// Sets the maximum length in bytes of a raw parameter value.
+    pub fn with_max_raw_length(mut self, max_raw_length: Option<usize>) -> Self {
+        self.max_raw_length = max_raw_length;
+        self
+    }
+
+    /// Sets whether whitespace around delimiter-separated values is trimmed.
+    pub fn with_trim_values(mut self, trim_values: bool) -> Self {
+        self.trim_values = trim_values;
+        self
+    }
+
+    /// Sets whether repeated values are additionally comma-split and
+    /// flattened.
+    pub fn with_flatten_commas(mut self, flatten_commas: bool) -> Self {
+        self.flatten_commas = flatten_commas;
+        self
+    }
 }
 
 impl<T> Default for ExtractParamOptions<T> {
@@ -126,6 +265,10 @@ impl<T> Default for ExtractParamOptions<T> {
             example_value: None,
             explode: true,
             style: None,
+            max_items: None,
+            max_raw_length: None,
+            trim_values: true,
+            flatten_commas: false,
         }
     }
 }
@@ -204,6 +347,9 @@ pub trait ApiExtractor<'a>: Sized {
     /// If it is `true`, it means that this parameter is required.
     const PARAM_IS_REQUIRED: bool = false;
 
+    /// If it is `true`, it means that this parameter allows an empty value.
+    const PARAM_ALLOW_EMPTY_VALUE: bool = false;
+
     /// The parameter type.
     type ParamType;
 
@@ -233,6 +379,13 @@ pub trait ApiExtractor<'a>: Sized {
         None
     }
 
+    /// Returns the full list of parameters if this extractor expands to more
+    /// than one, overriding the single parameter built from
+    /// [`param_schema_ref`](Self::param_schema_ref).
+    fn expanded_params() -> Option<Vec<MetaOperationParam>> {
+        None
+    }
+
     /// Returns `MetaRequest` if this extractor is request object.
     fn request_meta() -> Option<MetaRequest> {
         None
@@ -444,6 +597,44 @@ impl Display for OperationId {
     }
 }
 
+/// The OpenAPI path template matched by the current operation, e.g.
+/// `/users/{id}`.
+///
+/// Unlike the concrete request path, the template has a bounded set of values,
+/// which makes it suitable as a metrics label. It is inserted into the request
+/// extensions by the generated operation handler, so it can only be extracted
+/// inside an OpenAPI operation.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RouteTemplate(pub String);
+
+impl RouteTemplate {
+    /// The path template.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RouteTemplate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'a> FromRequest<'a> for RouteTemplate {
+    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+        req.extensions()
+            .get::<RouteTemplate>()
+            .cloned()
+            .ok_or_else(|| {
+                Error::from_string(
+                    "route template is only available inside an OpenAPI operation",
+                    poem::http::StatusCode::INTERNAL_SERVER_ERROR,
+                )
+            })
+    }
+}
+
 /// Represents an OpenAPI object.
 pub trait OpenApi: Sized {
     /// Gets metadata of this API object.
@@ -112,6 +112,7 @@
 //! | bson               | Integrate with the [`bson` crate](https://crates.io/crates/bson)                       |
 //! | rust_decimal       | Integrate with the [`rust_decimal` crate](https://crates.io/crates/rust_decimal)       |
 //! | prost-wkt-types    | Integrate with the [`prost-wkt-types` crate](https://crates.io/crates/prost-wkt-types) |
+//! | msgpack            | Support for MessagePack payloads via [`rmp-serde`](https://crates.io/crates/rmp-serde) |
 //! | static-files       | Support for static file response                                                       |
 //! | websocket          | Support for websocket                                                                  |
 //! | sonic-rs           | Uses [`sonic-rs`](https://github.com/cloudwego/sonic-rs) instead of `serde_json`. Pls, checkout `sonic-rs` requirements to properly enable `sonic-rs` capabilities |
@@ -134,7 +135,7 @@ pub mod param;
 pub mod payload;
 #[doc(hidden)]
 pub mod registry;
-mod response;
+pub mod response;
 pub mod types;
 #[doc(hidden)]
 pub mod validation;
@@ -154,10 +155,11 @@ mod ui;
 
 pub use base::{
     ApiExtractor, ApiExtractorType, ApiResponse, ExtractParamOptions, OAuthScopes, OpenApi,
-    OperationId, ParameterStyle, ResponseContent, Tags, Webhook,
+    OperationId, ParameterStyle, ResponseContent, RouteTemplate, Tags, Webhook,
 };
 pub use openapi::{
-    ContactObject, ExternalDocumentObject, ExtraHeader, LicenseObject, OpenApiService, ServerObject,
+    ContactObject, ExternalDocumentObject, ExtraHeader, LicenseObject, OpenApiService,
+    ServerObject, TagObject,
 };
 #[doc = include_str!("docs/request.md")]
 pub use poem_openapi_derive::ApiRequest;
@@ -195,5 +197,9 @@ pub mod __private {
     pub use serde;
     pub use serde_json;
 
-    pub use crate::{auth::CheckerReturn, base::UrlQuery, path_util::join_path};
+    pub use crate::{
+        auth::CheckerReturn,
+        base::{UrlQuery, numbers_to_strings},
+        path_util::join_path,
+    };
 }
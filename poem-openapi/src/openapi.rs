@@ -15,7 +15,8 @@ use crate::{
     base::UrlQuery,
     registry::{
         Document, MetaContact, MetaExternalDocument, MetaHeader, MetaInfo, MetaLicense,
-        MetaOperationParam, MetaParamIn, MetaSchemaRef, MetaServer, MetaServerVariable, Registry,
+        MetaOperationParam, MetaParamIn, MetaSchemaRef, MetaServer, MetaServerVariable, MetaTag,
+        Registry,
     },
     types::Type,
 };
@@ -138,6 +139,41 @@ impl ContactObject {
             ..self
         }
     }
+
+    /// Sets the email address of the contact person/organization, returning
+    /// an error if the value is not a plausible email address.
+    pub fn try_email(self, email: impl Into<String>) -> Result<Self, String> {
+        let email = email.into();
+        if !is_valid_email(&email) {
+            return Err(format!("invalid contact email: {email}"));
+        }
+        Ok(Self {
+            email: Some(email),
+            ..self
+        })
+    }
+}
+
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && !domain.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+                && !email.chars().any(|ch| ch.is_whitespace())
+                && !domain.contains('@')
+        }
+        None => false,
+    }
+}
+
+fn is_valid_url(url: &str) -> bool {
+    let rest = url
+        .strip_prefix("http://")
+        .or_else(|| url.strip_prefix("https://"));
+    matches!(rest, Some(rest) if !rest.is_empty() && !rest.starts_with('/'))
 }
 
 /// A license information for the exposed API.
@@ -257,6 +293,49 @@ impl ExtraHeader {
     }
 }
 
+/// An object representing a tag definition.
+#[derive(Debug, Clone)]
+pub struct TagObject {
+    name: String,
+    description: Option<String>,
+    external_docs: Option<ExternalDocumentObject>,
+}
+
+impl<T: Into<String>> From<T> for TagObject {
+    fn from(name: T) -> Self {
+        Self::new(name)
+    }
+}
+
+impl TagObject {
+    /// Create a tag object by name.
+    pub fn new(name: impl Into<String>) -> TagObject {
+        Self {
+            name: name.into(),
+            description: None,
+            external_docs: None,
+        }
+    }
+
+    /// Sets a description of the tag.
+    #[must_use]
+    pub fn description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    /// Sets additional external documentation for the tag.
+    #[must_use]
+    pub fn external_document(self, external_document: impl Into<ExternalDocumentObject>) -> Self {
+        Self {
+            external_docs: Some(external_document.into()),
+            ..self
+        }
+    }
+}
+
 /// An OpenAPI service for Poem.
 #[derive(Clone)]
 pub struct OpenApiService<T, W> {
@@ -269,7 +348,10 @@ pub struct OpenApiService<T, W> {
     cookie_key: Option<CookieKey>,
     extra_response_headers: Vec<(ExtraHeader, MetaSchemaRef, bool)>,
     extra_request_headers: Vec<(ExtraHeader, MetaSchemaRef, bool)>,
+    tags: Vec<TagObject>,
     url_prefix: Option<String>,
+    json_schema_dialect: Option<String>,
+    dedup_inline_schemas: bool,
 }
 
 impl<T> OpenApiService<T, ()> {
@@ -294,7 +376,10 @@ impl<T> OpenApiService<T, ()> {
             cookie_key: None,
             extra_response_headers: vec![],
             extra_request_headers: vec![],
+            tags: vec![],
             url_prefix: None,
+            json_schema_dialect: None,
+            dedup_inline_schemas: false,
         }
     }
 }
@@ -312,7 +397,10 @@ impl<T, W> OpenApiService<T, W> {
             cookie_key: self.cookie_key,
             extra_response_headers: self.extra_response_headers,
             extra_request_headers: self.extra_request_headers,
+            tags: self.tags,
             url_prefix: None,
+            json_schema_dialect: self.json_schema_dialect,
+            dedup_inline_schemas: self.dedup_inline_schemas,
         }
     }
 
@@ -337,6 +425,17 @@ impl<T, W> OpenApiService<T, W> {
         self
     }
 
+    /// Sets a URL to the Terms of Service for the API, returning an error if
+    /// the value is not an absolute `http`/`https` URL.
+    pub fn try_terms_of_service(mut self, url: impl Into<String>) -> Result<Self, String> {
+        let url = url.into();
+        if !is_valid_url(&url) {
+            return Err(format!("invalid terms of service URL: {url}"));
+        }
+        self.info.terms_of_service = Some(url);
+        Ok(self)
+    }
+
     /// Appends a server to the API container.
     ///
     /// Reference: <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#server-object>
@@ -392,6 +491,15 @@ impl<T, W> OpenApiService<T, W> {
         self
     }
 
+    /// Appends a tag definition to the API container.
+    ///
+    /// Reference: <https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md#tag-object>
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<TagObject>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
     /// Add extra response header
     #[must_use]
     pub fn extra_response_header<HT, H>(mut self, header: H) -> Self
@@ -436,6 +544,22 @@ impl<T, W> OpenApiService<T, W> {
         }
     }
 
+    /// Sets the top-level `jsonSchemaDialect`, declaring the default JSON
+    /// Schema dialect of the schemas in this document.
+    #[must_use]
+    pub fn json_schema_dialect(mut self, json_schema_dialect: impl Into<String>) -> Self {
+        self.json_schema_dialect = Some(json_schema_dialect.into());
+        self
+    }
+
+    /// Promotes structurally identical inline schemas to shared named
+    /// components referenced by `$ref` when generating the specification.
+    #[must_use]
+    pub fn dedup_inline_schemas(mut self) -> Self {
+        self.dedup_inline_schemas = true;
+        self
+    }
+
     /// Create the OpenAPI Explorer endpoint.
     #[must_use]
     #[cfg(feature = "openapi-explorer")]
@@ -621,7 +745,9 @@ impl<T, W> OpenApiService<T, W> {
                         required: *is_required,
                         deprecated: header.deprecated,
                         explode: true,
+                        allow_empty_value: false,
                         style: None,
+                        component: None,
                     },
                 );
             }
@@ -653,6 +779,17 @@ impl<T, W> OpenApiService<T, W> {
         T::register(&mut registry);
         W::register(&mut registry);
 
+        for tag in &self.tags {
+            registry.create_tag(MetaTag {
+                name: tag.name.clone(),
+                description: tag.description.clone(),
+                external_docs: tag.external_docs.as_ref().map(|doc| MetaExternalDocument {
+                    url: doc.url.clone(),
+                    description: doc.description.clone(),
+                }),
+            });
+        }
+
         let webhooks = W::meta();
 
         let mut doc = Document {
@@ -663,7 +800,11 @@ impl<T, W> OpenApiService<T, W> {
             registry,
             external_document: self.external_document.as_ref(),
             url_prefix: self.url_prefix.as_deref(),
+            json_schema_dialect: self.json_schema_dialect.as_deref(),
         };
+        if self.dedup_inline_schemas {
+            doc.dedup_inline_schemas();
+        }
         doc.remove_unused_schemas();
 
         doc
@@ -696,7 +837,7 @@ impl<T: OpenApi, W: Webhook> IntoEndpoint for OpenApiService<T, W> {
     fn into_endpoint(self) -> Self::Endpoint {
         async fn extract_query(mut req: Request) -> Result<Request> {
             let url_query: Vec<(String, String)> = req.params().unwrap_or_default();
-            req.extensions_mut().insert(UrlQuery(url_query));
+            req.extensions_mut().insert(UrlQuery::new(url_query));
             Ok(req)
         }
 
@@ -8,10 +8,49 @@ use poem::{
 use crate::{
     ApiExtractor, ApiExtractorType, ExtractParamOptions,
     error::ParseParamError,
+    param::check_max_items,
     registry::{MetaParamIn, MetaSchemaRef, Registry},
     types::ParseFromParameter,
 };
 
+/// Parses a cookie value with the same comma-split handling as
+/// `Query::from_request`: non-exploded values are split on `,`, exploded
+/// values are passed through as a single parameter.
+fn parse_value<T: ParseFromParameter>(
+    value: Option<String>,
+    param_opts: &ExtractParamOptions<T>,
+) -> poem::Result<T> {
+    let result = match &value {
+        Some(value) if !param_opts.explode => {
+            // a present but empty cookie parses as an empty parameter list
+            if value.is_empty() {
+                ParseFromParameter::parse_from_parameters(std::iter::empty::<&str>())
+            } else {
+                check_max_items(
+                    param_opts.name,
+                    param_opts.max_items,
+                    value.split(',').count(),
+                )?;
+                ParseFromParameter::parse_from_parameters(value.split(',').map(|item| {
+                    if param_opts.trim_values {
+                        item.trim()
+                    } else {
+                        item
+                    }
+                }))
+            }
+        }
+        _ => ParseFromParameter::parse_from_parameters(value.as_deref()),
+    };
+    result.map_err(|err| {
+        ParseParamError {
+            name: param_opts.name,
+            reason: err.into_message(),
+        }
+        .into()
+    })
+}
+
 /// Represents the parameters passed by the cookie.
 pub struct Cookie<T>(pub T);
 
@@ -66,15 +105,7 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Cookie<T> {
             (None, _) => None,
         };
 
-        ParseFromParameter::parse_from_parameters(value.as_deref())
-            .map(Self)
-            .map_err(|err| {
-                ParseParamError {
-                    name: param_opts.name,
-                    reason: err.into_message(),
-                }
-                .into()
-            })
+        parse_value(value, &param_opts).map(Self)
     }
 }
 
@@ -133,15 +164,7 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for CookiePrivate<T> {
             (None, _) => None,
         };
 
-        ParseFromParameter::parse_from_parameters(value.as_deref())
-            .map(Self)
-            .map_err(|err| {
-                dbg!(ParseParamError {
-                    name: param_opts.name,
-                    reason: err.into_message(),
-                })
-                .into()
-            })
+        parse_value(value, &param_opts).map(Self)
     }
 }
 
@@ -200,15 +223,7 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for CookieSigned<T> {
             (None, _) => None,
         };
 
-        ParseFromParameter::parse_from_parameters(value.as_deref())
-            .map(Self)
-            .map_err(|err| {
-                ParseParamError {
-                    name: param_opts.name,
-                    reason: err.into_message(),
-                }
-                .into()
-            })
+        parse_value(value, &param_opts).map(Self)
     }
 }
 
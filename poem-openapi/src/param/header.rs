@@ -6,6 +6,7 @@ use poem::{Request, RequestBody, Result};
 use crate::{
     ApiExtractor, ApiExtractorType, ExtractParamOptions,
     error::ParseParamError,
+    param::check_max_items,
     registry::{MetaParamIn, MetaSchemaRef, Registry},
     types::ParseFromParameter,
 };
@@ -81,14 +82,49 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Header<T> {
             _ => {}
         }
 
-        ParseFromParameter::parse_from_parameters(values)
-            .map(Self)
-            .map_err(|err| {
-                ParseParamError {
-                    name: param_opts.name,
-                    reason: err.into_message(),
-                }
-                .into()
-            })
+        if param_opts.explode {
+            let values = values.collect::<Vec<_>>();
+            check_max_items(param_opts.name, param_opts.max_items, values.len())?;
+            ParseFromParameter::parse_from_parameters(values)
+                .map(Self)
+                .map_err(|err| {
+                    ParseParamError {
+                        name: param_opts.name,
+                        reason: err.into_message(),
+                    }
+                    .into()
+                })
+        } else {
+            // headers use the `simple` style: a single comma-separated value;
+            // an absent header parses as an empty parameter list
+            let Some(value) = values.next() else {
+                return ParseFromParameter::parse_from_parameters(std::iter::empty::<&str>())
+                    .map(Self)
+                    .map_err(|err| {
+                        ParseParamError {
+                            name: param_opts.name,
+                            reason: err.into_message(),
+                        }
+                        .into()
+                    });
+            };
+            check_max_items(
+                param_opts.name,
+                param_opts.max_items,
+                value.split(',').count(),
+            )?;
+            let values = value
+                .split(',')
+                .map(|v| if param_opts.trim_values { v.trim() } else { v });
+            ParseFromParameter::parse_from_parameters(values)
+                .map(Self)
+                .map_err(|err| {
+                    ParseParamError {
+                        name: param_opts.name,
+                        reason: err.into_message(),
+                    }
+                    .into()
+                })
+        }
     }
 }
@@ -2,11 +2,45 @@
 #[cfg(feature = "cookie")]
 mod cookie;
 mod header;
+mod page;
 mod path;
 mod query;
+mod query_struct;
 
 #[cfg(feature = "cookie")]
 pub use cookie::{Cookie, CookiePrivate, CookieSigned};
 pub use header::Header;
+pub use page::Page;
 pub use path::Path;
 pub use query::Query;
+pub use query_struct::QueryStruct;
+
+pub(crate) fn check_max_raw_length(
+    name: &'static str,
+    max_raw_length: Option<usize>,
+    len: usize,
+) -> poem::Result<()> {
+    match max_raw_length {
+        Some(max_raw_length) if len > max_raw_length => Err(crate::error::ParseParamError {
+            name,
+            reason: format!("the raw parameter value is longer than {max_raw_length} bytes"),
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn check_max_items(
+    name: &'static str,
+    max_items: Option<usize>,
+    len: usize,
+) -> poem::Result<()> {
+    match max_items {
+        Some(max_items) if len > max_items => Err(crate::error::ParseParamError {
+            name,
+            reason: format!("the length of the parameter list is greater than {max_items}"),
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,123 @@
+use poem::{Request, RequestBody, Result};
+use serde_json::Value;
+
+use crate::{
+    ApiExtractor, ApiExtractorType, ExtractParamOptions,
+    base::UrlQuery,
+    error::ParseParamError,
+    registry::{MetaOperationParam, MetaParamIn, MetaSchema, Registry},
+    types::{Cursor, ParseFromParameter, Type},
+};
+
+/// Represents `?cursor=...&limit=...` pagination parameters.
+///
+/// The cursor is an optional [`Cursor`] token; the limit falls back to
+/// `DEFAULT_LIMIT` when absent and is clamped to `MAX_LIMIT`. Both appear as
+/// individual query parameters in the specification.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::{OpenApi, param::Page, payload::PlainText};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/items", method = "get")]
+///     async fn items(&self, page: Page<20, 100>) -> PlainText<String> {
+///         PlainText(format!("limit {}", page.limit))
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Page<const DEFAULT_LIMIT: u32 = 20, const MAX_LIMIT: u32 = 100> {
+    /// The position to resume from, if any.
+    pub cursor: Option<Cursor>,
+    /// The page size, between `1` and `MAX_LIMIT`.
+    pub limit: u32,
+}
+
+impl<'a, const DEFAULT_LIMIT: u32, const MAX_LIMIT: u32> ApiExtractor<'a>
+    for Page<DEFAULT_LIMIT, MAX_LIMIT>
+{
+    const TYPES: &'static [ApiExtractorType] = &[ApiExtractorType::Parameter];
+
+    type ParamType = ();
+    type ParamRawType = ();
+
+    fn register(registry: &mut Registry) {
+        Cursor::register(registry);
+        u32::register(registry);
+    }
+
+    fn param_in() -> Option<MetaParamIn> {
+        Some(MetaParamIn::Query)
+    }
+
+    fn expanded_params() -> Option<Vec<MetaOperationParam>> {
+        Some(vec![
+            MetaOperationParam {
+                name: "cursor".to_string(),
+                schema: Cursor::schema_ref(),
+                in_type: MetaParamIn::Query,
+                description: None,
+                required: false,
+                deprecated: false,
+                explode: true,
+                allow_empty_value: false,
+                style: None,
+                component: None,
+            },
+            MetaOperationParam {
+                name: "limit".to_string(),
+                schema: u32::schema_ref().merge(MetaSchema {
+                    default: Some(Value::from(DEFAULT_LIMIT)),
+                    minimum: Some(1.0),
+                    maximum: Some(MAX_LIMIT as f64),
+                    ..MetaSchema::ANY
+                }),
+                in_type: MetaParamIn::Query,
+                description: None,
+                required: false,
+                deprecated: false,
+                explode: true,
+                allow_empty_value: false,
+                style: None,
+                component: None,
+            },
+        ])
+    }
+
+    async fn from_request(
+        request: &'a Request,
+        _body: &mut RequestBody,
+        _param_opts: ExtractParamOptions<Self::ParamType>,
+    ) -> Result<Self> {
+        let url_query = request.extensions().get::<UrlQuery>().unwrap();
+
+        let cursor = match url_query.get("cursor") {
+            Some(value) => {
+                Some(
+                    Cursor::parse_from_parameter(value).map_err(|err| ParseParamError {
+                        name: "cursor",
+                        reason: err.into_message(),
+                    })?,
+                )
+            }
+            None => None,
+        };
+
+        let limit = match url_query.get("limit") {
+            Some(value) => u32::parse_from_parameter(value)
+                .map_err(|err| ParseParamError {
+                    name: "limit",
+                    reason: err.into_message(),
+                })?
+                .clamp(1, MAX_LIMIT),
+            None => DEFAULT_LIMIT,
+        };
+
+        Ok(Self { cursor, limit })
+    }
+}
@@ -10,6 +10,10 @@ use crate::{
 };
 
 /// Represents the parameters passed by the URI path.
+///
+/// If a default value is provided with `#[oai(default)]`, it is also applied
+/// when the matched segment is empty. This is only meaningful for catch-all
+/// parameters, since a regular path segment never matches an empty string.
 pub struct Path<T>(pub T);
 
 impl<T> Deref for Path<T> {
@@ -58,6 +62,9 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Path<T> {
             request.raw_path_param(param_opts.name),
             &param_opts.default_value,
         ) {
+            (Some(value), Some(default_value)) if value.is_empty() => {
+                return Ok(Self(default_value()));
+            }
             (Some(value), _) => Some(value),
             (None, Some(default_value)) => return Ok(Self(default_value())),
             (None, _) => None,
@@ -4,9 +4,10 @@ use itertools::Either;
 use poem::{Request, RequestBody, Result};
 
 use crate::{
-    ApiExtractor, ApiExtractorType, ExtractParamOptions,
+    ApiExtractor, ApiExtractorType, ExtractParamOptions, ParameterStyle,
     base::UrlQuery,
     error::ParseParamError,
+    param::{check_max_items, check_max_raw_length},
     registry::{MetaParamIn, MetaSchemaRef, Registry},
     types::ParseFromParameter,
 };
@@ -31,6 +32,7 @@ impl<T> DerefMut for Query<T> {
 impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Query<T> {
     const TYPES: &'static [ApiExtractorType] = &[ApiExtractorType::Parameter];
     const PARAM_IS_REQUIRED: bool = T::IS_REQUIRED;
+    const PARAM_ALLOW_EMPTY_VALUE: bool = T::ALLOW_EMPTY_VALUE;
 
     type ParamType = T;
     type ParamRawType = T::RawValueType;
@@ -57,10 +59,44 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Query<T> {
         param_opts: ExtractParamOptions<Self::ParamType>,
     ) -> Result<Self> {
         let url_query = request.extensions().get::<UrlQuery>().unwrap();
+
+        if param_opts.style == Some(ParameterStyle::DeepObject) {
+            let pairs = url_query
+                .iter()
+                .filter_map(|(n, value)| {
+                    let (name, key) = n.strip_suffix(']')?.split_once('[')?;
+                    let matched = if !param_opts.ignore_case {
+                        name == param_opts.name
+                    } else {
+                        name.eq_ignore_ascii_case(param_opts.name)
+                    };
+                    matched.then_some((key, value.as_str()))
+                })
+                .collect::<Vec<_>>();
+
+            match &param_opts.default_value {
+                Some(default_value) if pairs.is_empty() => {
+                    return Ok(Self(default_value()));
+                }
+                _ => {}
+            }
+
+            check_max_items(param_opts.name, param_opts.max_items, pairs.len())?;
+            return ParseFromParameter::parse_from_deep_object(pairs)
+                .map(Self)
+                .map_err(|err| {
+                    ParseParamError {
+                        name: param_opts.name,
+                        reason: err.into_message(),
+                    }
+                    .into()
+                });
+        }
+
         let mut values = if !param_opts.ignore_case {
             Either::Left(url_query.get_all(param_opts.name))
         } else {
-            Either::Right(url_query.get_all_by(|n| param_opts.name.eq_ignore_ascii_case(n)))
+            Either::Right(url_query.get_all_ignore_case(param_opts.name))
         }
         .peekable();
 
@@ -72,23 +108,81 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Query<T> {
         }
 
         if param_opts.explode {
-            ParseFromParameter::parse_from_parameters(values)
+            let raw_values = values.map(|value| value.as_str()).collect::<Vec<_>>();
+            for value in &raw_values {
+                check_max_raw_length(param_opts.name, param_opts.max_raw_length, value.len())?;
+            }
+            let values = if param_opts.flatten_commas {
+                // tolerate clients that mix `?id=1&id=2` and `?id=2,3`
+                raw_values
+                    .into_iter()
+                    .flat_map(|value| value.split(','))
+                    .map(|value| {
+                        if param_opts.trim_values {
+                            value.trim()
+                        } else {
+                            value
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            } else {
+                raw_values
+            };
+            check_max_items(param_opts.name, param_opts.max_items, values.len())?;
+            ParseFromParameter::parse_from_parameters(values.iter().copied())
                 .map(Self)
                 .map_err(|err| {
                     ParseParamError {
                         name: param_opts.name,
-                        reason: err.into_message(),
+                        // keep the offending input in the message so handler
+                        // logs show what was actually sent
+                        reason: if values.is_empty() {
+                            err.into_message()
+                        } else {
+                            format!("{} (value: `{}`)", err.into_message(), values.join(","))
+                        },
                     }
                     .into()
                 })
         } else {
-            let values = values.next().unwrap().split(',').map(|v| v.trim());
+            let delimiter = match param_opts.style {
+                Some(ParameterStyle::SpaceDelimited) => ' ',
+                Some(ParameterStyle::PipeDelimited) => '|',
+                _ => ',',
+            };
+            let value = values.next().unwrap();
+            // reject over-length input before it is split into elements
+            check_max_raw_length(param_opts.name, param_opts.max_raw_length, value.len())?;
+
+            // `?tags=` means the parameter is present but empty: parse it as
+            // an empty list instead of as a single empty element. A truly
+            // absent key is handled by `default_value` above.
+            if value.is_empty() {
+                return ParseFromParameter::parse_from_parameters(std::iter::empty::<&str>())
+                    .map(Self)
+                    .map_err(|err| {
+                        ParseParamError {
+                            name: param_opts.name,
+                            reason: err.into_message(),
+                        }
+                        .into()
+                    });
+            }
+
+            check_max_items(
+                param_opts.name,
+                param_opts.max_items,
+                value.split(delimiter).count(),
+            )?;
+            let values = value
+                .split(delimiter)
+                .map(|v| if param_opts.trim_values { v.trim() } else { v });
             ParseFromParameter::parse_from_parameters(values)
                 .map(Self)
                 .map_err(|err| {
                     ParseParamError {
                         name: param_opts.name,
-                        reason: err.into_message(),
+                        reason: format!("{} (value: `{value}`)", err.into_message()),
                     }
                     .into()
                 })
@@ -0,0 +1,308 @@
+use std::ops::{Deref, DerefMut};
+
+use poem::{Request, RequestBody, Result};
+use serde::de::{
+    self, DeserializeOwned, IntoDeserializer,
+    value::{BorrowedStrDeserializer, Error as DeError, MapDeserializer, SeqDeserializer},
+};
+
+use crate::{
+    ApiExtractor, ApiExtractorType, ExtractParamOptions,
+    base::UrlQuery,
+    registry::{MetaOperationParam, MetaParamIn, MetaSchemaRef, Registry},
+    types::Type,
+};
+
+/// Represents all query string parameters deserialized into a single struct.
+///
+/// Unlike [`Query`](crate::param::Query), which extracts one named parameter,
+/// this extractor feeds the whole query string to a serde deserializer. Each
+/// field of the struct is still documented as an individual query parameter in
+/// the specification. Repeated keys are collected into sequences, so `Vec`
+/// fields work with `?tag=a&tag=b`.
+pub struct QueryStruct<T>(pub T);
+
+impl<T> Deref for QueryStruct<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for QueryStruct<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a, T: DeserializeOwned + Type> ApiExtractor<'a> for QueryStruct<T> {
+    const TYPES: &'static [ApiExtractorType] = &[ApiExtractorType::Parameter];
+
+    type ParamType = T;
+    type ParamRawType = ();
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn param_in() -> Option<MetaParamIn> {
+        Some(MetaParamIn::Query)
+    }
+
+    fn param_schema_ref() -> Option<MetaSchemaRef> {
+        Some(T::schema_ref())
+    }
+
+    fn expanded_params() -> Option<Vec<MetaOperationParam>> {
+        let schema = match T::schema_ref() {
+            MetaSchemaRef::Inline(schema) => *schema,
+            MetaSchemaRef::Reference(name) => {
+                let mut registry = Registry::new();
+                T::register(&mut registry);
+                registry.schemas.remove(&name)?
+            }
+        };
+
+        let required = schema.required;
+        Some(
+            schema
+                .properties
+                .into_iter()
+                .map(|(name, schema)| MetaOperationParam {
+                    name: name.to_string(),
+                    schema,
+                    in_type: MetaParamIn::Query,
+                    description: None,
+                    required: required.contains(&name),
+                    deprecated: false,
+                    explode: true,
+                    allow_empty_value: false,
+                    style: None,
+                    component: None,
+                })
+                .collect(),
+        )
+    }
+
+    async fn from_request(
+        request: &'a Request,
+        _body: &mut RequestBody,
+        _param_opts: ExtractParamOptions<Self::ParamType>,
+    ) -> Result<Self> {
+        let url_query = request.extensions().get::<UrlQuery>().unwrap();
+        from_pairs(url_query)
+            .map(Self)
+            .map_err(|err| poem::error::ParseQueryError(err).into())
+    }
+}
+
+/// Deserializes `T` from decoded query pairs, grouping repeated keys so they
+/// can be visited as a sequence.
+fn from_pairs<T: DeserializeOwned>(pairs: &[(String, String)]) -> Result<T, DeError> {
+    let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+    for (name, value) in pairs {
+        match groups.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, values)) => values.push(value),
+            None => groups.push((name, vec![value])),
+        }
+    }
+
+    T::deserialize(MapDeserializer::new(
+        groups
+            .into_iter()
+            .map(|(name, values)| (name, Values(values))),
+    ))
+}
+
+/// All values collected for one query key.
+struct Values<'de>(Vec<&'de str>);
+
+impl<'de> IntoDeserializer<'de> for Values<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// A single query value: a string that can also be parsed into a scalar.
+struct Scalar<'de>(&'de str);
+
+impl<'de> IntoDeserializer<'de> for Scalar<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! parse_scalar {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                visitor.$visit(self.0.parse().map_err(de::Error::custom)?)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Scalar<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    parse_scalar! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_enum(BorrowedStrDeserializer::new(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit unit_struct seq tuple tuple_struct map
+        struct identifier ignored_any
+    }
+}
+
+macro_rules! delegate_to_first_value {
+    ($($method:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+                Scalar(self.0[0]).$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for Values<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        if self.0.len() > 1 {
+            self.deserialize_seq(visitor)
+        } else {
+            Scalar(self.0[0]).deserialize_any(visitor)
+        }
+    }
+
+    delegate_to_first_value! {
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_i128,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_u128,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_unit,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any,
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        de::Deserializer::deserialize_seq(
+            SeqDeserializer::new(self.0.into_iter().map(Scalar)),
+            visitor,
+        )
+    }
+
+    fn deserialize_tuple<V: de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_unit_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Scalar(self.0[0]).deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Scalar(self.0[0]).deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Scalar(self.0[0]).deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        Scalar(self.0[0]).deserialize_enum(name, variants, visitor)
+    }
+}
@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use poem::{Error, IntoResponse, http::HeaderValue};
+
+use crate::{
+    ApiResponse,
+    registry::{MetaResponses, Registry},
+};
+
+/// A response type wrapper that sets the `Cache-Control` header.
+///
+/// The inner response keeps its own schema and status; only the caching
+/// directives are added.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use poem::test::TestClient;
+/// use poem_openapi::{
+///     OpenApi, OpenApiService,
+///     payload::{Cached, Json},
+/// };
+///
+/// struct MyApi;
+///
+/// #[OpenApi]
+/// impl MyApi {
+///     #[oai(path = "/test", method = "get")]
+///     async fn test(&self) -> Cached<Json<i32>> {
+///         Cached::new(Json(100))
+///             .public()
+///             .max_age(Duration::from_secs(60))
+///     }
+/// }
+///
+/// let api = OpenApiService::new(MyApi, "Demo", "0.1.0");
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = TestClient::new(api).get("/test").send().await;
+/// resp.assert_status_is_ok();
+/// resp.assert_header("cache-control", "public, max-age=60");
+/// # });
+/// ```
+pub struct Cached<T> {
+    inner: T,
+    directives: Vec<String>,
+}
+
+impl<T> Cached<T> {
+    /// Create a cached response without any directives.
+    #[must_use]
+    pub fn new(resp: T) -> Self {
+        Self {
+            inner: resp,
+            directives: Vec::new(),
+        }
+    }
+
+    fn directive(mut self, directive: impl Into<String>) -> Self {
+        self.directives.push(directive.into());
+        self
+    }
+
+    /// Marks the response as cacheable by shared caches.
+    #[must_use]
+    pub fn public(self) -> Self {
+        self.directive("public")
+    }
+
+    /// Marks the response as cacheable only by private caches.
+    #[must_use]
+    pub fn private(self) -> Self {
+        self.directive("private")
+    }
+
+    /// Sets the `max-age` directive.
+    #[must_use]
+    pub fn max_age(self, max_age: Duration) -> Self {
+        let secs = max_age.as_secs();
+        self.directive(format!("max-age={secs}"))
+    }
+
+    /// Sets the `s-maxage` directive for shared caches.
+    #[must_use]
+    pub fn s_max_age(self, max_age: Duration) -> Self {
+        let secs = max_age.as_secs();
+        self.directive(format!("s-maxage={secs}"))
+    }
+
+    /// Sets the `stale-while-revalidate` directive.
+    #[must_use]
+    pub fn stale_while_revalidate(self, duration: Duration) -> Self {
+        let secs = duration.as_secs();
+        self.directive(format!("stale-while-revalidate={secs}"))
+    }
+
+    /// Sets the `immutable` directive.
+    #[must_use]
+    pub fn immutable(self) -> Self {
+        self.directive("immutable")
+    }
+
+    /// Sets the `no-store` directive.
+    #[must_use]
+    pub fn no_store(self) -> Self {
+        self.directive("no-store")
+    }
+}
+
+impl<T: IntoResponse> IntoResponse for Cached<T> {
+    fn into_response(self) -> poem::Response {
+        let mut resp = self.inner.into_response();
+        if !self.directives.is_empty() {
+            // the directive order follows the builder calls, so e.g.
+            // `public, max-age=60`
+            if let Ok(value) = HeaderValue::from_str(&self.directives.join(", ")) {
+                resp.headers_mut()
+                    .insert(poem::http::header::CACHE_CONTROL, value);
+            }
+        }
+        resp
+    }
+}
+
+impl<T: ApiResponse> ApiResponse for Cached<T> {
+    const BAD_REQUEST_HANDLER: bool = T::BAD_REQUEST_HANDLER;
+
+    fn meta() -> MetaResponses {
+        T::meta()
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn from_parse_request_error(err: Error) -> Self {
+        Self::new(T::from_parse_request_error(err))
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
     ApiResponse,
     payload::Payload,
     registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef, Registry},
-    types::{ToJSON, Type},
+    types::{TaggedUnion, ToJSON, Type},
 };
 
 type ToEventFn<T> = Box<dyn (FnMut(T) -> Event) + Send + 'static>;
@@ -43,6 +43,48 @@ impl<T: Stream + Send + 'static> EventStream<T> {
         }
     }
 
+    /// Emit each message as a typed SSE event, using the union discriminator
+    /// as the event type.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use poem_openapi::{Object, Union, payload::EventStream};
+    ///
+    /// #[derive(Debug, Object)]
+    /// struct Delta {
+    ///     content: String,
+    /// }
+    ///
+    /// #[derive(Debug, Object)]
+    /// struct Done {}
+    ///
+    /// #[derive(Debug, Union)]
+    /// #[oai(discriminator_name = "type", rename_all = "lowercase")]
+    /// enum Chunk {
+    ///     Delta(Delta),
+    ///     Done(Done),
+    /// }
+    ///
+    /// EventStream::new(futures_util::stream::iter(vec![
+    ///     Chunk::Delta(Delta {
+    ///         content: "hello".to_string(),
+    ///     }),
+    ///     Chunk::Done(Done {}),
+    /// ]))
+    /// .typed_events();
+    /// ```
+    #[must_use]
+    pub fn typed_events(self) -> Self
+    where
+        T::Item: TaggedUnion + ToJSON,
+    {
+        self.to_event(|message| {
+            let event_type = message.discriminator();
+            Event::message(message.to_json_string()).event_type(event_type)
+        })
+    }
+
     /// Set a function used to convert the message to SSE event.
     ///
     /// # Examples
@@ -55,10 +55,13 @@ impl<T: ParseFromJSON> ParsePayload for Json<T> {
 
     async fn from_request(request: &Request, body: &mut RequestBody) -> Result<Self> {
         let data = Vec::<u8>::from_request(request, body).await?;
+        // some Windows clients prefix JSON bodies with a UTF-8 BOM, which
+        // serde_json rejects
+        let data = data.strip_prefix("\u{feff}".as_bytes()).unwrap_or(&data);
         let value = if data.is_empty() {
             Value::Null
         } else {
-            serde_json::from_slice(&data).map_err(|err| ParseRequestPayloadError {
+            serde_json::from_slice(data).map_err(|err| ParseRequestPayloadError {
                 reason: err.to_string(),
             })?
         };
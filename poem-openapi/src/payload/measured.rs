@@ -0,0 +1,79 @@
+use poem::{Error, IntoResponse};
+
+use crate::{
+    ApiResponse,
+    registry::{MetaResponses, Registry},
+};
+
+/// The serialized body size in bytes recorded by [`Measured`].
+///
+/// Downstream middleware can read it from the response extensions.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct ResponseSize(pub u64);
+
+/// A response type wrapper that records the serialized body size.
+///
+/// The inner response keeps its own schema and status; the byte length of the
+/// body is stored as a [`ResponseSize`] extension so middleware can observe
+/// it. Bodies whose size is not known up front (e.g. streaming bodies) record
+/// nothing.
+///
+/// # Examples
+///
+/// ```
+/// use poem::{Endpoint, EndpointExt, test::TestClient};
+/// use poem_openapi::{
+///     OpenApi, OpenApiService,
+///     payload::{Json, Measured, ResponseSize},
+/// };
+///
+/// struct MyApi;
+///
+/// #[OpenApi]
+/// impl MyApi {
+///     #[oai(path = "/test", method = "get")]
+///     async fn test(&self) -> Measured<Json<i32>> {
+///         Measured(Json(100))
+///     }
+/// }
+///
+/// let api = OpenApiService::new(MyApi, "Demo", "0.1.0").around(|ep, req| async move {
+///     let resp = ep.call(req).await?;
+///     assert_eq!(resp.extensions().get::<ResponseSize>(), Some(&ResponseSize(3)));
+///     Ok(resp)
+/// });
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = TestClient::new(api).get("/test").send().await;
+/// resp.assert_status_is_ok();
+/// # });
+/// ```
+pub struct Measured<T>(pub T);
+
+impl<T: IntoResponse> IntoResponse for Measured<T> {
+    fn into_response(self) -> poem::Response {
+        let mut resp = self.0.into_response();
+        let body = resp.take_body();
+        if let Some(size) = body.exact_size() {
+            resp.extensions_mut().insert(ResponseSize(size));
+        }
+        resp.set_body(body);
+        resp
+    }
+}
+
+impl<T: ApiResponse> ApiResponse for Measured<T> {
+    const BAD_REQUEST_HANDLER: bool = T::BAD_REQUEST_HANDLER;
+
+    fn meta() -> MetaResponses {
+        T::meta()
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn from_parse_request_error(err: Error) -> Self {
+        Self(T::from_parse_request_error(err))
+    }
+}
@@ -3,10 +3,16 @@
 mod attachment;
 mod base64_payload;
 mod binary;
+mod cached;
 mod event_stream;
 mod form;
 mod html;
 mod json;
+mod measured;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+mod multipart;
+mod multipart_mixed;
 mod plain_text;
 mod response;
 mod xml;
@@ -16,14 +22,20 @@ use std::future::Future;
 
 use poem::{Request, RequestBody, Result};
 
+#[cfg(feature = "msgpack")]
+pub use self::msgpack::{JsonOrMsgPack, MsgPack};
 pub use self::{
     attachment::{Attachment, AttachmentType},
     base64_payload::Base64,
     binary::Binary,
+    cached::Cached,
     event_stream::EventStream,
     form::Form,
     html::Html,
     json::Json,
+    measured::{Measured, ResponseSize},
+    multipart::{Multipart, MultipartField},
+    multipart_mixed::{MultipartMixed, Part},
     plain_text::PlainText,
     response::Response,
     xml::Xml,
@@ -0,0 +1,201 @@
+use std::ops::{Deref, DerefMut};
+
+use poem::{FromRequest, IntoResponse, Request, RequestBody, Response, Result, web::Accept};
+use serde_json::Value;
+
+use crate::{
+    ApiResponse,
+    error::ParseRequestPayloadError,
+    payload::{Json, ParsePayload, Payload},
+    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchemaRef, Registry},
+    types::{ParseFromJSON, ToJSON, Type},
+};
+
+/// A MessagePack payload.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct MsgPack<T>(pub T);
+
+impl<T> Deref for MsgPack<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for MsgPack<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Type> Payload for MsgPack<T> {
+    const CONTENT_TYPE: &'static str = "application/msgpack";
+
+    fn check_content_type(content_type: &str) -> bool {
+        matches!(content_type.parse::<mime::Mime>(), Ok(content_type) if content_type.type_() == "application"
+                && (content_type.subtype() == "msgpack"
+                || content_type.subtype() == "x-msgpack"
+                || content_type
+                    .suffix()
+                    .is_some_and(|v| v == "msgpack")))
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        T::schema_ref()
+    }
+
+    #[allow(unused_variables)]
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+}
+
+impl<T: ParseFromJSON> ParsePayload for MsgPack<T> {
+    const IS_REQUIRED: bool = true;
+
+    async fn from_request(request: &Request, body: &mut RequestBody) -> Result<Self> {
+        let data = Vec::<u8>::from_request(request, body).await?;
+        let value = if data.is_empty() {
+            Value::Null
+        } else {
+            rmp_serde::from_slice(&data).map_err(|err| ParseRequestPayloadError {
+                reason: err.to_string(),
+            })?
+        };
+
+        let value = T::parse_from_json(Some(value)).map_err(|err| ParseRequestPayloadError {
+            reason: err.into_message(),
+        })?;
+        Ok(Self(value))
+    }
+}
+
+impl<T: ToJSON> IntoResponse for MsgPack<T> {
+    fn into_response(self) -> Response {
+        let value = self.0.to_json().unwrap_or(Value::Null);
+        match rmp_serde::to_vec_named(&value) {
+            Ok(data) => Response::builder()
+                .content_type(Self::CONTENT_TYPE)
+                .body(data),
+            Err(err) => Response::builder()
+                .status(poem::http::StatusCode::INTERNAL_SERVER_ERROR)
+                .body(err.to_string()),
+        }
+    }
+}
+
+impl<T: ToJSON> ApiResponse for MsgPack<T> {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "",
+                status: Some(200),
+                status_range: None,
+                content: vec![MetaMediaType {
+                    content_type: Self::CONTENT_TYPE,
+                    schema: Self::schema_ref(),
+                }],
+                headers: vec![],
+            }],
+        }
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+}
+
+impl_apirequest_for_payload!(MsgPack<T>, T: ParseFromJSON);
+
+/// A response that returns MessagePack when the client asks for it and JSON
+/// otherwise.
+///
+/// The negotiation is driven by the request's `Accept` header, which can be
+/// obtained in a handler with the [`Accept`] extractor. Both media types are
+/// registered in the document.
+///
+/// # Example
+///
+/// ```
+/// use poem::web::Accept;
+/// use poem_openapi::{OpenApi, payload::JsonOrMsgPack};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/stats", method = "get")]
+///     async fn stats(&self, accept: Accept) -> JsonOrMsgPack<Vec<i32>> {
+///         JsonOrMsgPack::new(&accept, vec![1, 2, 3])
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum JsonOrMsgPack<T> {
+    /// Respond with a JSON body.
+    Json(T),
+    /// Respond with a MessagePack body.
+    MsgPack(T),
+}
+
+impl<T> JsonOrMsgPack<T> {
+    /// Create a response, choosing MessagePack if it appears in the `Accept`
+    /// header before any JSON media type, and JSON otherwise.
+    pub fn new(accept: &Accept, value: T) -> Self {
+        for mime in &accept.0 {
+            if mime.type_() == "application" {
+                if mime.subtype() == "msgpack" || mime.subtype() == "x-msgpack" {
+                    return Self::MsgPack(value);
+                }
+                if mime.subtype() == "json" {
+                    return Self::Json(value);
+                }
+            }
+        }
+        Self::Json(value)
+    }
+
+    /// Returns the inner value.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Json(value) | Self::MsgPack(value) => value,
+        }
+    }
+}
+
+impl<T: ToJSON> IntoResponse for JsonOrMsgPack<T> {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Json(value) => Json(value).into_response(),
+            Self::MsgPack(value) => MsgPack(value).into_response(),
+        }
+    }
+}
+
+impl<T: ToJSON> ApiResponse for JsonOrMsgPack<T> {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "",
+                status: Some(200),
+                status_range: None,
+                content: vec![
+                    MetaMediaType {
+                        content_type: <Json<T> as Payload>::CONTENT_TYPE,
+                        schema: T::schema_ref(),
+                    },
+                    MetaMediaType {
+                        content_type: <MsgPack<T> as Payload>::CONTENT_TYPE,
+                        schema: T::schema_ref(),
+                    },
+                ],
+                headers: vec![],
+            }],
+        }
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+}
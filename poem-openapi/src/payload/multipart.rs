@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use poem::{FromRequest, Request, RequestBody, Result, error::SizedLimitError, web::Field};
+use tokio::io::AsyncReadExt;
+
+use crate::{
+    payload::{ParsePayload, Payload},
+    registry::{MetaSchema, MetaSchemaRef},
+};
+
+/// A streaming multipart payload.
+///
+/// Unlike the [`Multipart`](derive@crate::Multipart) derive macro, which
+/// buffers all fields into a struct, this payload yields the fields one by one
+/// in the order they arrive, so each field can be processed without holding the
+/// whole body in memory.
+///
+/// Per-field byte caps can be configured with
+/// [`field_size_limit`](Self::field_size_limit) and
+/// [`default_field_size_limit`](Self::default_field_size_limit); an oversized
+/// field is rejected with `413 Payload Too Large` while it is being read.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem::{error::BadRequest, test::TestClient, Result};
+/// use poem_openapi::{payload::{Multipart, PlainText}, OpenApi, OpenApiService};
+///
+/// struct MyApi;
+///
+/// #[OpenApi]
+/// impl MyApi {
+///     #[oai(path = "/upload", method = "post")]
+///     async fn upload(&self, multipart: Multipart) -> Result<PlainText<String>> {
+///         let mut multipart = multipart.field_size_limit("a", 16);
+///         let mut names = Vec::new();
+///         while let Some(field) = multipart.next_field().await? {
+///             names.push(field.name().unwrap_or_default().to_string());
+///             field.bytes().await?;
+///         }
+///         Ok(PlainText(names.join(",")))
+///     }
+/// }
+///
+/// let api = OpenApiService::new(MyApi, "Demo", "0.1.0");
+/// let cli = TestClient::new(api);
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = cli
+///     .post("/upload")
+///     .content_type("multipart/form-data; boundary=X-BOUNDARY")
+///     .body(
+///         "--X-BOUNDARY\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n1\r\n\
+///          --X-BOUNDARY\r\nContent-Disposition: form-data; name=\"b\"\r\n\r\n2\r\n\
+///          --X-BOUNDARY--\r\n",
+///     )
+///     .send()
+///     .await;
+/// resp.assert_status_is_ok();
+/// resp.assert_text("a,b").await;
+/// # });
+/// ```
+pub struct Multipart {
+    inner: poem::web::Multipart,
+    field_limits: HashMap<String, usize>,
+    default_field_limit: Option<usize>,
+}
+
+impl Multipart {
+    /// Sets the maximum number of bytes the field with the specified name may
+    /// contain.
+    #[must_use]
+    pub fn field_size_limit(mut self, name: impl Into<String>, limit: usize) -> Self {
+        self.field_limits.insert(name.into(), limit);
+        self
+    }
+
+    /// Sets the maximum number of bytes for fields without an explicit limit.
+    #[must_use]
+    pub fn default_field_size_limit(mut self, limit: usize) -> Self {
+        self.default_field_limit = Some(limit);
+        self
+    }
+
+    /// Yields the next [`MultipartField`] if available.
+    pub async fn next_field(&mut self) -> Result<Option<MultipartField>> {
+        match self.inner.next_field().await? {
+            Some(field) => {
+                let limit = field
+                    .name()
+                    .and_then(|name| self.field_limits.get(name).copied())
+                    .or(self.default_field_limit);
+                Ok(Some(MultipartField { field, limit }))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+/// A single field of a streaming [`Multipart`] payload.
+pub struct MultipartField {
+    field: Field,
+    limit: Option<usize>,
+}
+
+impl MultipartField {
+    /// Get the content type of the field.
+    #[inline]
+    pub fn content_type(&self) -> Option<&str> {
+        self.field.content_type()
+    }
+
+    /// The file name found in the `Content-Disposition` header.
+    #[inline]
+    pub fn file_name(&self) -> Option<&str> {
+        self.field.file_name()
+    }
+
+    /// The name found in the `Content-Disposition` header.
+    #[inline]
+    pub fn name(&self) -> Option<&str> {
+        self.field.name()
+    }
+
+    /// Get the full data of the field as bytes.
+    ///
+    /// Reading stops as soon as the configured size limit is exceeded and the
+    /// field is rejected with `413 Payload Too Large`.
+    pub async fn bytes(self) -> Result<Vec<u8>> {
+        let limit = self.limit;
+        let mut reader = self.field.into_async_read();
+        let mut data = Vec::new();
+        let mut buf = [0; 2048];
+        loop {
+            let sz = reader
+                .read(&mut buf[..])
+                .await
+                .map_err(poem::error::BadRequest)?;
+            if sz == 0 {
+                break;
+            }
+            data.extend_from_slice(&buf[..sz]);
+            if let Some(limit) = limit {
+                if data.len() > limit {
+                    return Err(SizedLimitError::PayloadTooLarge.into());
+                }
+            }
+        }
+        Ok(data)
+    }
+
+    /// Get the full field data as text.
+    pub async fn text(self) -> Result<String> {
+        String::from_utf8(self.bytes().await?).map_err(poem::error::BadRequest)
+    }
+
+    /// Consume this object and return the underlying [`Field`].
+    ///
+    /// NOTE: The size limit is not enforced when the field is read directly.
+    pub fn into_inner(self) -> Field {
+        self.field
+    }
+}
+
+impl Payload for Multipart {
+    const CONTENT_TYPE: &'static str = "multipart/form-data";
+
+    fn check_content_type(content_type: &str) -> bool {
+        matches!(content_type.parse::<mime::Mime>(), Ok(content_type) if content_type.type_() == "multipart"
+                && (content_type.subtype() == "form-data"
+                || content_type
+                    .suffix()
+                    .is_some_and(|v| v == "form-data")))
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new("object")))
+    }
+}
+
+impl ParsePayload for Multipart {
+    const IS_REQUIRED: bool = true;
+
+    async fn from_request(request: &Request, body: &mut RequestBody) -> Result<Self> {
+        Ok(Self {
+            inner: poem::web::Multipart::from_request(request, body).await?,
+            field_limits: HashMap::new(),
+            default_field_limit: None,
+        })
+    }
+}
+
+impl_apirequest_for_payload!(Multipart);
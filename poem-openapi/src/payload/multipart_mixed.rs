@@ -0,0 +1,160 @@
+use poem::{IntoResponse, Response};
+
+use crate::{
+    ApiResponse,
+    payload::Payload,
+    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef, Registry},
+    types::ToJSON,
+};
+
+const DEFAULT_BOUNDARY: &str = "poem-openapi-boundary";
+
+/// A single part of a [`MultipartMixed`] response.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Part {
+    content_type: String,
+    body: Vec<u8>,
+}
+
+impl Part {
+    /// Create a part with the specified content type and body.
+    pub fn new(content_type: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            content_type: content_type.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Create an `application/json` part from a value.
+    pub fn json<T: ToJSON>(value: &T) -> Self {
+        Self::new(
+            "application/json; charset=utf-8",
+            value.to_json().unwrap_or_default().to_string(),
+        )
+    }
+
+    /// Returns the content type of this part.
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// Returns the body of this part.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/// A `multipart/mixed` response containing several typed parts.
+///
+/// Each part carries its own content type and is separated by the boundary in
+/// the response body, which is useful for batch APIs that return multiple
+/// documents in a single response.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::payload::{MultipartMixed, Part};
+///
+/// let multipart = MultipartMixed::new()
+///     .part(Part::new("text/plain; charset=utf-8", "hello"))
+///     .part(Part::json(&100));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultipartMixed {
+    boundary: String,
+    parts: Vec<Part>,
+}
+
+impl Default for MultipartMixed {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultipartMixed {
+    /// Create an empty `multipart/mixed` response.
+    pub fn new() -> Self {
+        Self {
+            boundary: DEFAULT_BOUNDARY.to_string(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Sets the boundary used to separate the parts.
+    #[must_use]
+    pub fn boundary(self, boundary: impl Into<String>) -> Self {
+        Self {
+            boundary: boundary.into(),
+            ..self
+        }
+    }
+
+    /// Appends a part to the response.
+    #[must_use]
+    pub fn part(mut self, part: Part) -> Self {
+        self.parts.push(part);
+        self
+    }
+
+    /// Returns the parts of this response.
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    fn to_body(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for part in &self.parts {
+            body.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            body.extend_from_slice(
+                format!("Content-Type: {}\r\n\r\n", part.content_type).as_bytes(),
+            );
+            body.extend_from_slice(&part.body);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        body
+    }
+}
+
+impl Payload for MultipartMixed {
+    const CONTENT_TYPE: &'static str = "multipart/mixed";
+
+    fn check_content_type(content_type: &str) -> bool {
+        matches!(content_type.parse::<mime::Mime>(), Ok(content_type) if content_type.type_() == "multipart"
+                && content_type.subtype() == "mixed")
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            format: Some("binary"),
+            ..MetaSchema::new("string")
+        }))
+    }
+}
+
+impl IntoResponse for MultipartMixed {
+    fn into_response(self) -> Response {
+        let content_type = format!("{}; boundary={}", Self::CONTENT_TYPE, self.boundary);
+        let body = self.to_body();
+        Response::builder().content_type(content_type).body(body)
+    }
+}
+
+impl ApiResponse for MultipartMixed {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "",
+                status: Some(200),
+                status_range: None,
+                content: vec![MetaMediaType {
+                    content_type: Self::CONTENT_TYPE,
+                    schema: Self::schema_ref(),
+                }],
+                headers: vec![],
+            }],
+        }
+    }
+
+    fn register(_registry: &mut Registry) {}
+}
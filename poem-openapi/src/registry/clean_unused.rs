@@ -4,6 +4,31 @@ use crate::registry::{Document, MetaMediaType, MetaOperation, MetaSchemaRef};
 
 type UsedTypes = BTreeSet<String>;
 
+fn collect_references(schema: &crate::registry::MetaSchema, out: &mut Vec<String>) {
+    let collect = |schema_ref: &MetaSchemaRef, out: &mut Vec<String>| match schema_ref {
+        MetaSchemaRef::Reference(name) => out.push(name.clone()),
+        MetaSchemaRef::Inline(schema) => collect_references(schema, out),
+    };
+
+    for (_, schema_ref) in &schema.properties {
+        collect(schema_ref, out);
+    }
+    if let Some(schema_ref) = &schema.items {
+        collect(schema_ref, out);
+    }
+    if let Some(schema_ref) = &schema.additional_properties {
+        collect(schema_ref, out);
+    }
+    for schema_ref in schema
+        .any_of
+        .iter()
+        .chain(&schema.one_of)
+        .chain(&schema.all_of)
+    {
+        collect(schema_ref, out);
+    }
+}
+
 impl<'a> Document<'a> {
     fn traverse_schema(&self, used_types: &mut UsedTypes, schema_ref: &'a MetaSchemaRef) {
         let schema = match schema_ref {
@@ -80,6 +105,23 @@ impl<'a> Document<'a> {
             self.traverse_operation(&mut used_types, &api.operation);
         }
 
+        // dynamically registered schemas have no compile-time references;
+        // treat them as roots so they and everything they reference survive
+        let mut pending = self
+            .registry
+            .dyn_schemas
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        while let Some(name) = pending.pop() {
+            if !used_types.insert(name.clone()) {
+                continue;
+            }
+            if let Some(schema) = self.registry.schemas.get(&name) {
+                collect_references(schema, &mut pending);
+            }
+        }
+
         let all_schemas = self
             .registry
             .schemas
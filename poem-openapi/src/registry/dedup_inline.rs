@@ -0,0 +1,188 @@
+use std::collections::BTreeMap;
+
+use crate::registry::{Document, MetaSchema, MetaSchemaRef};
+
+/// Whether an inline schema is worth promoting to a shared component.
+///
+/// Only object schemas with properties are considered; collapsing every
+/// repeated `{"type": "string"}` would bloat the components section instead
+/// of shrinking the spec.
+fn is_candidate(schema: &MetaSchema) -> bool {
+    !schema.properties.is_empty()
+}
+
+/// A structural key for a schema, independent of where it appears.
+fn schema_key(schema: &MetaSchema) -> String {
+    serde_json::to_string(schema).expect("serialize schema")
+}
+
+fn count_schema(counts: &mut BTreeMap<String, (usize, MetaSchema)>, schema: &MetaSchema) {
+    if is_candidate(schema) {
+        counts
+            .entry(schema_key(schema))
+            .or_insert_with(|| (0, schema.clone()))
+            .0 += 1;
+    }
+    for (_, schema_ref) in &schema.properties {
+        count_schema_ref(counts, schema_ref);
+    }
+    if let Some(schema_ref) = &schema.items {
+        count_schema_ref(counts, schema_ref);
+    }
+    if let Some(schema_ref) = &schema.additional_properties {
+        count_schema_ref(counts, schema_ref);
+    }
+    for schema_ref in schema
+        .any_of
+        .iter()
+        .chain(&schema.one_of)
+        .chain(&schema.all_of)
+    {
+        count_schema_ref(counts, schema_ref);
+    }
+}
+
+fn count_schema_ref(
+    counts: &mut BTreeMap<String, (usize, MetaSchema)>,
+    schema_ref: &MetaSchemaRef,
+) {
+    if let MetaSchemaRef::Inline(schema) = schema_ref {
+        count_schema(counts, schema);
+    }
+}
+
+fn replace_in_schema(names: &BTreeMap<String, String>, schema: &mut MetaSchema) {
+    for (_, schema_ref) in &mut schema.properties {
+        replace_in_schema_ref(names, schema_ref);
+    }
+    if let Some(schema_ref) = &mut schema.items {
+        replace_in_schema_ref(names, schema_ref);
+    }
+    if let Some(schema_ref) = &mut schema.additional_properties {
+        replace_in_schema_ref(names, schema_ref);
+    }
+    for schema_ref in schema
+        .any_of
+        .iter_mut()
+        .chain(&mut schema.one_of)
+        .chain(&mut schema.all_of)
+    {
+        replace_in_schema_ref(names, schema_ref);
+    }
+}
+
+fn replace_in_schema_ref(names: &BTreeMap<String, String>, schema_ref: &mut MetaSchemaRef) {
+    if let MetaSchemaRef::Inline(schema) = schema_ref {
+        if is_candidate(schema) {
+            if let Some(name) = names.get(&schema_key(schema)) {
+                *schema_ref = MetaSchemaRef::Reference(name.clone());
+                return;
+            }
+        }
+        replace_in_schema(names, schema);
+    }
+}
+
+impl Document<'_> {
+    /// Promotes structurally identical inline schemas to shared named
+    /// components referenced by `$ref`.
+    ///
+    /// Must run before [`remove_unused_schemas`](Self::remove_unused_schemas)
+    /// so the promoted components count as used.
+    pub(crate) fn dedup_inline_schemas(&mut self) {
+        let mut counts = BTreeMap::new();
+        for operation in self
+            .apis
+            .iter()
+            .flat_map(|api| &api.paths)
+            .flat_map(|path| &path.operations)
+            .chain(self.webhooks.iter().map(|webhook| &webhook.operation))
+        {
+            for param in &operation.params {
+                count_schema_ref(&mut counts, &param.schema);
+            }
+            for media_type in operation
+                .request
+                .iter()
+                .flat_map(|request| &request.content)
+                .chain(
+                    operation
+                        .responses
+                        .responses
+                        .iter()
+                        .flat_map(|response| &response.content),
+                )
+            {
+                count_schema_ref(&mut counts, &media_type.schema);
+            }
+        }
+        for schema in self.registry.schemas.values() {
+            count_schema(&mut counts, schema);
+        }
+
+        // assign component names to repeated schemas, preferring the title
+        let mut names = BTreeMap::new();
+        let mut promoted = Vec::new();
+        for (key, (count, schema)) in counts {
+            if count < 2 {
+                continue;
+            }
+            let base = schema
+                .title
+                .clone()
+                .unwrap_or_else(|| "InlineSchema".to_string());
+            let mut name = base.clone();
+            let mut suffix = 1;
+            while self.registry.schemas.contains_key(&name)
+                || names.values().any(|used| *used == name)
+            {
+                suffix += 1;
+                name = format!("{base}{suffix}");
+            }
+            names.insert(key, name.clone());
+            promoted.push((name, schema));
+        }
+        if names.is_empty() {
+            return;
+        }
+
+        for operation in self
+            .apis
+            .iter_mut()
+            .flat_map(|api| &mut api.paths)
+            .flat_map(|path| &mut path.operations)
+            .chain(
+                self.webhooks
+                    .iter_mut()
+                    .map(|webhook| &mut webhook.operation),
+            )
+        {
+            for param in &mut operation.params {
+                replace_in_schema_ref(&names, &mut param.schema);
+            }
+            for media_type in operation
+                .request
+                .iter_mut()
+                .flat_map(|request| &mut request.content)
+                .chain(
+                    operation
+                        .responses
+                        .responses
+                        .iter_mut()
+                        .flat_map(|response| &mut response.content),
+                )
+            {
+                replace_in_schema_ref(&names, &mut media_type.schema);
+            }
+        }
+        for schema in self.registry.schemas.values_mut() {
+            replace_in_schema(&names, schema);
+        }
+
+        for (name, mut schema) in promoted {
+            // duplicates nested inside a promoted schema also collapse
+            replace_in_schema(&names, &mut schema);
+            self.registry.schemas.insert(name, schema);
+        }
+    }
+}
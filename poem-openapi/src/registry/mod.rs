@@ -1,4 +1,5 @@
 mod clean_unused;
+mod dedup_inline;
 mod ser;
 
 use std::{
@@ -9,7 +10,10 @@ use std::{
 
 use poem::http::Method;
 pub(crate) use ser::Document;
-use serde::{Serialize, Serializer, ser::SerializeMap};
+use serde::{
+    Serialize, Serializer,
+    ser::{SerializeMap, SerializeSeq},
+};
 use serde_json::Value;
 
 use crate::{ParameterStyle, types::Type};
@@ -58,6 +62,8 @@ pub struct MetaSchema {
     pub description: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_docs: Option<MetaExternalDocument>,
+    #[serde(rename = "$comment", skip_serializing_if = "Option::is_none")]
+    pub comment: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<Value>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -75,6 +81,13 @@ pub struct MetaSchema {
     pub enum_items: Vec<Value>,
     #[serde(skip_serializing_if = "is_false")]
     pub deprecated: bool,
+    #[serde(rename = "x-deprecated-since", skip_serializing_if = "Option::is_none")]
+    pub deprecated_since: Option<&'static str>,
+    #[serde(
+        rename = "x-deprecated-remove-in",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deprecated_remove_in: Option<&'static str>,
     #[serde(skip_serializing_if = "is_false")]
     pub nullable: bool,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -139,6 +152,7 @@ impl MetaSchema {
         title: None,
         description: None,
         external_docs: None,
+        comment: None,
         default: None,
         required: vec![],
         properties: vec![],
@@ -146,6 +160,8 @@ impl MetaSchema {
         additional_properties: None,
         enum_items: vec![],
         deprecated: false,
+        deprecated_since: None,
+        deprecated_remove_in: None,
         any_of: vec![],
         one_of: vec![],
         all_of: vec![],
@@ -193,10 +209,13 @@ impl MetaSchema {
             read_only,
             write_only,
             deprecated,
+            deprecated_since,
+            deprecated_remove_in,
             nullable,
             title,
             description,
             external_docs,
+            comment,
             items,
             additional_properties,
             example,
@@ -232,10 +251,13 @@ impl MetaSchema {
         }
 
         merge_optional!(
+            deprecated_since,
+            deprecated_remove_in,
             default,
             title,
             description,
             external_docs,
+            comment,
             example,
             multiple_of,
             maximum,
@@ -374,8 +396,15 @@ pub struct MetaOperationParam {
     pub required: bool,
     pub deprecated: bool,
     pub explode: bool,
+    #[serde(rename = "allowEmptyValue", skip_serializing_if = "is_false")]
+    pub allow_empty_value: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub style: Option<ParameterStyle>,
+    /// When set, the parameter definition is registered in
+    /// `components/parameters` under this name and operations reference it by
+    /// `$ref`.
+    #[serde(skip)]
+    pub component: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Serialize)]
@@ -397,6 +426,24 @@ pub struct MetaRequest {
     pub required: bool,
 }
 
+fn serialize_params<S: Serializer>(
+    params: &[MetaOperationParam],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut s = serializer.serialize_seq(Some(params.len()))?;
+    for param in params {
+        match &param.component {
+            Some(name) => {
+                let mut reference = BTreeMap::new();
+                reference.insert("$ref", format!("#/components/parameters/{name}"));
+                s.serialize_element(&reference)?;
+            }
+            None => s.serialize_element(param)?,
+        }
+    }
+    s.end()
+}
+
 fn serialize_content<S: Serializer>(
     content: &[MetaMediaType],
     serializer: S,
@@ -485,19 +532,35 @@ pub struct MetaOperation {
     pub description: Option<&'static str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_docs: Option<MetaExternalDocument>,
-    #[serde(rename = "parameters", skip_serializing_if = "Vec::is_empty")]
+    #[serde(
+        rename = "parameters",
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_params"
+    )]
     pub params: Vec<MetaOperationParam>,
     #[serde(rename = "requestBody", skip_serializing_if = "Option::is_none")]
     pub request: Option<MetaRequest>,
     pub responses: MetaResponses,
     #[serde(skip_serializing_if = "is_false")]
     pub deprecated: bool,
+    #[serde(rename = "x-replaced-by", skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<&'static str>,
+    #[serde(rename = "x-deprecated-since", skip_serializing_if = "Option::is_none")]
+    pub deprecated_since: Option<&'static str>,
+    #[serde(
+        rename = "x-deprecated-remove-in",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deprecated_remove_in: Option<&'static str>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub security: Vec<HashMap<&'static str, Vec<&'static str>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operation_id: Option<&'static str>,
     #[serde(rename = "x-code-samples", skip_serializing_if = "Vec::is_empty")]
     pub code_samples: Vec<MetaCodeSample>,
+    /// Operation-specific servers, overriding the global `servers` array.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<MetaServer>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -573,16 +636,16 @@ pub struct MetaExternalDocument {
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MetaTag {
-    pub name: &'static str,
+    pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'static str>,
+    pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_docs: Option<MetaExternalDocument>,
 }
 
 impl PartialEq for MetaTag {
     fn eq(&self, other: &Self) -> bool {
-        self.name.eq(other.name)
+        self.name.eq(&other.name)
     }
 }
 
@@ -590,13 +653,13 @@ impl Eq for MetaTag {}
 
 impl PartialOrd for MetaTag {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.name.cmp(other.name))
+        Some(self.name.cmp(&other.name))
     }
 }
 
 impl Ord for MetaTag {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.name.cmp(other.name)
+        self.name.cmp(&other.name)
     }
 }
 
@@ -683,6 +746,8 @@ pub struct Registry {
     pub schemas: BTreeMap<String, MetaSchema>,
     pub tags: BTreeSet<MetaTag>,
     pub security_schemes: BTreeMap<&'static str, MetaSecurityScheme>,
+    pub parameters: BTreeMap<String, MetaOperationParam>,
+    pub(crate) dyn_schemas: BTreeSet<String>,
 }
 
 impl Registry {
@@ -718,6 +783,75 @@ impl Registry {
         }
     }
 
+    /// Registers a schema that is not backed by a compile-time [`Type`].
+    ///
+    /// Plugin architectures that produce response types at runtime can
+    /// contribute their schemas through a manual [`OpenApi`](crate::OpenApi)
+    /// implementation whose `register` calls this method, combined with the
+    /// rest of the service as a tuple:
+    ///
+    /// ```rust
+    /// use poem_openapi::{
+    ///     OpenApi,
+    ///     registry::{MetaApi, MetaSchema, Registry},
+    /// };
+    ///
+    /// struct Plugin;
+    ///
+    /// impl OpenApi for Plugin {
+    ///     fn meta() -> Vec<MetaApi> {
+    ///         vec![]
+    ///     }
+    ///
+    ///     fn register(registry: &mut Registry) {
+    ///         registry.register_dyn("PluginEvent", MetaSchema::new("object"));
+    ///     }
+    ///
+    ///     fn add_routes(self, _route_table: &mut std::collections::HashMap<String, std::collections::HashMap<poem::http::Method, poem::endpoint::BoxEndpoint<'static>>>) {}
+    /// }
+    /// ```
+    ///
+    /// Unlike schemas registered through [`Type`], a dynamically registered
+    /// schema is kept in the emitted spec even when no operation references
+    /// it, since references from runtime-generated content cannot be seen at
+    /// build time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a schema with the same name is already registered with a
+    /// different definition.
+    pub fn register_dyn(&mut self, name: impl Into<String>, schema: MetaSchema) {
+        let name = name.into();
+        match self.schemas.get(&name) {
+            Some(prev) => {
+                if prev != &schema {
+                    panic!(
+                        "the `{name}` schema component is registered with different definitions"
+                    );
+                }
+            }
+            None => {
+                self.schemas.insert(name.clone(), schema);
+            }
+        }
+        self.dyn_schemas.insert(name);
+    }
+
+    pub fn create_parameter(&mut self, name: String, param: MetaOperationParam) {
+        match self.parameters.get(&name) {
+            Some(prev) => {
+                if prev != &param {
+                    panic!(
+                        "the `{name}` parameter component is registered with different definitions"
+                    );
+                }
+            }
+            None => {
+                self.parameters.insert(name, param);
+            }
+        }
+    }
+
     pub fn create_fake_schema<T: Type>(&mut self) -> MetaSchema {
         match T::schema_ref() {
             MetaSchemaRef::Inline(schema) => *schema,
@@ -3,8 +3,8 @@ use std::collections::BTreeMap;
 use serde::{Serialize, Serializer, ser::SerializeMap};
 
 use crate::registry::{
-    MetaApi, MetaExternalDocument, MetaInfo, MetaPath, MetaResponses, MetaSchema, MetaSchemaRef,
-    MetaSecurityScheme, MetaServer, MetaWebhook, Registry,
+    MetaApi, MetaExternalDocument, MetaInfo, MetaOperationParam, MetaPath, MetaResponses,
+    MetaSchema, MetaSchemaRef, MetaSecurityScheme, MetaServer, MetaWebhook, Registry,
 };
 
 const OPENAPI_VERSION: &str = "3.0.0";
@@ -92,6 +92,7 @@ pub(crate) struct Document<'a> {
     pub(crate) registry: Registry,
     pub(crate) external_document: Option<&'a MetaExternalDocument>,
     pub(crate) url_prefix: Option<&'a str>,
+    pub(crate) json_schema_dialect: Option<&'a str>,
 }
 
 impl Serialize for Document<'_> {
@@ -102,11 +103,16 @@ impl Serialize for Document<'_> {
             schemas: &'a BTreeMap<String, MetaSchema>,
             #[serde(skip_serializing_if = "BTreeMap::is_empty")]
             security_schemes: &'a BTreeMap<&'static str, MetaSecurityScheme>,
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            parameters: &'a BTreeMap<String, MetaOperationParam>,
         }
 
         let mut s = serializer.serialize_map(None)?;
 
         s.serialize_entry("openapi", OPENAPI_VERSION)?;
+        if let Some(json_schema_dialect) = self.json_schema_dialect {
+            s.serialize_entry("jsonSchemaDialect", json_schema_dialect)?;
+        }
         s.serialize_entry("info", &self.info)?;
         s.serialize_entry("servers", self.servers)?;
         s.serialize_entry("tags", &self.registry.tags)?;
@@ -119,6 +125,7 @@ impl Serialize for Document<'_> {
             &Components {
                 schemas: &self.registry.schemas,
                 security_schemes: &self.registry.security_schemes,
+                parameters: &self.registry.parameters,
             },
         )?;
 
@@ -0,0 +1,68 @@
+use poem::{IntoResponse, Response, http::Uri};
+
+use crate::{
+    ApiResponse,
+    registry::{MetaHeader, MetaResponse, MetaResponses, Registry},
+    types::Type,
+};
+
+const LOCATION_DESCRIPTION: &str =
+    "The URL where the status of the asynchronous job can be polled.";
+
+/// A `202 Accepted` response with a `Location` header pointing to the job
+/// status endpoint.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem::http::Uri;
+/// use poem_openapi::{OpenApi, response::Accepted};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/jobs", method = "post")]
+///     async fn create_job(&self) -> Accepted {
+///         Accepted {
+///             location: Uri::from_static("/jobs/123"),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Accepted {
+    /// The URL of the job status endpoint.
+    pub location: Uri,
+}
+
+impl IntoResponse for Accepted {
+    fn into_response(self) -> Response {
+        Response::builder()
+            .status(poem::http::StatusCode::ACCEPTED)
+            .header("location", self.location.to_string())
+            .finish()
+    }
+}
+
+impl ApiResponse for Accepted {
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "The request has been accepted for processing.",
+                status: Some(202),
+                status_range: None,
+                content: vec![],
+                headers: vec![MetaHeader {
+                    name: "location".to_string(),
+                    description: Some(LOCATION_DESCRIPTION.to_string()),
+                    required: true,
+                    deprecated: false,
+                    schema: String::schema_ref(),
+                }],
+            }],
+        }
+    }
+
+    fn register(_registry: &mut Registry) {}
+}
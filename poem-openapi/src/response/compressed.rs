@@ -0,0 +1,116 @@
+use poem::{
+    IntoResponse, Response,
+    web::{Compress, CompressionAlgo, CompressionLevel},
+};
+
+use crate::{
+    ApiResponse,
+    registry::{MetaHeader, MetaResponses, Registry},
+    types::Type,
+};
+
+const CONTENT_ENCODING_DESCRIPTION: &str =
+    "The compression algorithm applied to the body, omitted when the body is sent uncompressed.";
+
+/// A response wrapper that compresses the body.
+///
+/// Bodies smaller than the configured minimum size are sent uncompressed and
+/// the `Content-Encoding` header is omitted, so tiny payloads don't pay the
+/// compression overhead.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem::web::CompressionAlgo;
+/// use poem_openapi::{OpenApi, payload::PlainText, response::Compressed};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/report", method = "get")]
+///     async fn report(&self) -> Compressed<PlainText<String>> {
+///         Compressed::new(PlainText("a".repeat(4096)), CompressionAlgo::GZIP)
+///             .with_min_size(1024)
+///     }
+/// }
+/// ```
+pub struct Compressed<P> {
+    /// The wrapped response.
+    pub response: P,
+    algo: CompressionAlgo,
+    level: Option<CompressionLevel>,
+    min_size: u64,
+}
+
+impl<P> Compressed<P> {
+    /// Create a `Compressed` response with the specified algorithm.
+    pub fn new(response: P, algo: CompressionAlgo) -> Self {
+        Self {
+            response,
+            algo,
+            level: None,
+            min_size: 0,
+        }
+    }
+
+    /// Specify the compression level.
+    #[must_use]
+    pub fn with_quality(self, level: CompressionLevel) -> Self {
+        Self {
+            level: Some(level),
+            ..self
+        }
+    }
+
+    /// Set the minimum body size in bytes below which the body is sent
+    /// uncompressed.
+    ///
+    /// Bodies whose size is not known in advance (streaming bodies) are
+    /// always compressed.
+    #[must_use]
+    pub fn with_min_size(self, min_size: u64) -> Self {
+        Self { min_size, ..self }
+    }
+}
+
+impl<P: IntoResponse> IntoResponse for Compressed<P> {
+    fn into_response(self) -> Response {
+        let mut resp = self.response.into_response();
+        let body = resp.take_body();
+
+        // bodies known to be below the threshold are passed through untouched;
+        // streaming bodies have no known size and are always compressed
+        let below_threshold = body.exact_size().is_some_and(|size| size < self.min_size);
+        resp.set_body(body);
+        if below_threshold {
+            return resp;
+        }
+
+        let compress = Compress::new(resp, self.algo);
+        match self.level {
+            Some(level) => compress.with_quality(level).into_response(),
+            None => compress.into_response(),
+        }
+    }
+}
+
+impl<P: ApiResponse> ApiResponse for Compressed<P> {
+    fn meta() -> MetaResponses {
+        let mut meta = P::meta();
+        for response in &mut meta.responses {
+            response.headers.push(MetaHeader {
+                name: "Content-Encoding".to_string(),
+                description: Some(CONTENT_ENCODING_DESCRIPTION.to_string()),
+                required: false,
+                deprecated: false,
+                schema: String::schema_ref(),
+            });
+        }
+        meta
+    }
+
+    fn register(registry: &mut Registry) {
+        P::register(registry);
+    }
+}
@@ -1,4 +1,13 @@
 //! Commonly used response types.
 
+mod accepted;
+#[cfg(feature = "compression")]
+mod compressed;
+mod retry_after;
 #[cfg(feature = "static-files")]
 mod static_file;
+
+pub use accepted::Accepted;
+#[cfg(feature = "compression")]
+pub use compressed::Compressed;
+pub use retry_after::{RetryAfter, RetryAfterValue};
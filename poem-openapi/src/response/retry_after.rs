@@ -0,0 +1,162 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, SystemTime},
+};
+
+use poem::{IntoResponse, Response};
+
+use crate::{
+    ApiResponse,
+    registry::{MetaHeader, MetaResponses, Registry},
+    types::Type,
+};
+
+const RETRY_AFTER_DESCRIPTION: &str =
+    "How long to wait before retrying, either in seconds or as an HTTP date.";
+
+/// The value of a `Retry-After` header.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RetryAfterValue {
+    /// A delay in seconds, e.g. `Retry-After: 120`.
+    Seconds(u64),
+    /// An absolute time in the HTTP date form, e.g.
+    /// `Retry-After: Wed, 21 Oct 2015 07:28:00 GMT`.
+    Date(SystemTime),
+}
+
+impl Display for RetryAfterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RetryAfterValue::Seconds(seconds) => write!(f, "{seconds}"),
+            RetryAfterValue::Date(time) => f.write_str(&fmt_http_date(*time)),
+        }
+    }
+}
+
+/// Formats a time as an IMF-fixdate (RFC 9110), e.g.
+/// `Wed, 21 Oct 2015 07:28:00 GMT`. Times before the epoch format as the
+/// epoch.
+fn fmt_http_date(time: SystemTime) -> String {
+    const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let secs = time
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let days = secs / 86400;
+    let time_of_day = secs % 86400;
+    // 1970-01-01 was a Thursday
+    let weekday = DAYS[((days + 4) % 7) as usize];
+
+    // civil date from days since the epoch
+    // (https://howardhinnant.github.io/date_algorithms.html)
+    let z = days as i64 + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{weekday}, {day:02} {} {year} {:02}:{:02}:{:02} GMT",
+        MONTHS[(month - 1) as usize],
+        time_of_day / 3600,
+        time_of_day % 3600 / 60,
+        time_of_day % 60,
+    )
+}
+
+impl From<Duration> for RetryAfterValue {
+    fn from(duration: Duration) -> Self {
+        RetryAfterValue::Seconds(duration.as_secs())
+    }
+}
+
+impl From<SystemTime> for RetryAfterValue {
+    fn from(time: SystemTime) -> Self {
+        RetryAfterValue::Date(time)
+    }
+}
+
+/// A response wrapper that sets the `Retry-After` header.
+///
+/// Typically used with `429 Too Many Requests` or `503 Service Unavailable`
+/// responses. The header value is either a number of seconds or an HTTP date.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use poem_openapi::{OpenApi, payload::PlainText, response::RetryAfter};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/limited", method = "get")]
+///     async fn limited(&self) -> RetryAfter<PlainText<String>> {
+///         RetryAfter::new(
+///             PlainText("slow down".to_string()),
+///             Duration::from_secs(120),
+///         )
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RetryAfter<P> {
+    /// The wrapped response.
+    pub response: P,
+    /// The `Retry-After` value.
+    pub retry_after: RetryAfterValue,
+}
+
+impl<P> RetryAfter<P> {
+    /// Create a `RetryAfter` response from a duration or an absolute time.
+    pub fn new(response: P, retry_after: impl Into<RetryAfterValue>) -> Self {
+        Self {
+            response,
+            retry_after: retry_after.into(),
+        }
+    }
+}
+
+impl<P: IntoResponse> IntoResponse for RetryAfter<P> {
+    fn into_response(self) -> Response {
+        let mut resp = self.response.into_response();
+        resp.headers_mut().insert(
+            "retry-after",
+            self.retry_after
+                .to_string()
+                .parse()
+                .expect("valid header value"),
+        );
+        resp
+    }
+}
+
+impl<P: ApiResponse> ApiResponse for RetryAfter<P> {
+    fn meta() -> MetaResponses {
+        let mut meta = P::meta();
+        for response in &mut meta.responses {
+            response.headers.push(MetaHeader {
+                name: "Retry-After".to_string(),
+                description: Some(RETRY_AFTER_DESCRIPTION.to_string()),
+                required: true,
+                deprecated: false,
+                schema: String::schema_ref(),
+            });
+        }
+        meta
+    }
+
+    fn register(registry: &mut Registry) {
+        P::register(registry);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A geographic bounding box in `minLon,minLat,maxLon,maxLat` order.
+///
+/// Longitudes must be within `-180..=180`, latitudes within `-90..=90`, and
+/// the minimum of each axis must not exceed the maximum. The schema is a
+/// `string` with the comma-separated form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox {
+    /// The western longitude.
+    pub min_lon: f64,
+    /// The southern latitude.
+    pub min_lat: f64,
+    /// The eastern longitude.
+    pub max_lon: f64,
+    /// The northern latitude.
+    pub max_lat: f64,
+}
+
+impl BBox {
+    /// Create a new bounding box, validating the coordinate ranges and that
+    /// the minimums do not exceed the maximums.
+    pub fn new(min_lon: f64, min_lat: f64, max_lon: f64, max_lat: f64) -> Result<Self, String> {
+        for lon in [min_lon, max_lon] {
+            if !(-180.0..=180.0).contains(&lon) {
+                return Err(format!("longitude {lon} is out of range [-180, 180]"));
+            }
+        }
+        for lat in [min_lat, max_lat] {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err(format!("latitude {lat} is out of range [-90, 90]"));
+            }
+        }
+        if min_lon > max_lon {
+            return Err(format!(
+                "the minimum longitude {min_lon} is greater than the maximum longitude {max_lon}"
+            ));
+        }
+        if min_lat > max_lat {
+            return Err(format!(
+                "the minimum latitude {min_lat} is greater than the maximum latitude {max_lat}"
+            ));
+        }
+        Ok(Self {
+            min_lon,
+            min_lat,
+            max_lon,
+            max_lat,
+        })
+    }
+
+    /// Returns `true` if the point is inside this bounding box.
+    pub fn contains(&self, lon: f64, lat: f64) -> bool {
+        (self.min_lon..=self.max_lon).contains(&lon) && (self.min_lat..=self.max_lat).contains(&lat)
+    }
+}
+
+impl Display for BBox {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.min_lon, self.min_lat, self.max_lon, self.max_lat
+        )
+    }
+}
+
+fn parse_bbox<T: Type>(value: &str) -> Result<BBox, ParseError<T>> {
+    let mut numbers = [0.0; 4];
+    let mut parts = value.split(',');
+    for number in &mut numbers {
+        let part = parts
+            .next()
+            .ok_or_else(|| ParseError::custom("expected four comma-separated numbers"))?;
+        *number = part
+            .trim()
+            .parse()
+            .map_err(|_| ParseError::custom(format!("invalid number: {part:?}")))?;
+    }
+    if parts.next().is_some() {
+        return Err(ParseError::custom("expected four comma-separated numbers"));
+    }
+    BBox::new(numbers[0], numbers[1], numbers[2], numbers[3]).map_err(ParseError::custom)
+}
+
+impl Type for BBox {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_bbox".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some("A bounding box in `minLon,minLat,maxLon,maxLat` order."),
+            ..MetaSchema::new_with_format("string", "bbox")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for BBox {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_bbox(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for BBox {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_bbox(value)
+    }
+}
+
+impl ToJSON for BBox {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_bbox() {
+        let bbox = BBox::parse_from_parameter("-10.5,-20.25,30,40").unwrap();
+        assert_eq!(
+            bbox,
+            BBox {
+                min_lon: -10.5,
+                min_lat: -20.25,
+                max_lon: 30.0,
+                max_lat: 40.0
+            }
+        );
+        assert!(bbox.contains(0.0, 0.0));
+        assert!(!bbox.contains(31.0, 0.0));
+        assert_eq!(
+            bbox.to_json(),
+            Some(Value::String("-10.5,-20.25,30,40".to_string()))
+        );
+    }
+
+    #[test]
+    fn reject_invalid_bbox() {
+        // inverted axes
+        assert!(BBox::parse_from_parameter("30,0,-10,40").is_err());
+        assert!(BBox::parse_from_parameter("0,40,10,-40").is_err());
+        // out of range
+        assert!(BBox::parse_from_parameter("-181,0,0,0").is_err());
+        assert!(BBox::parse_from_parameter("0,0,0,91").is_err());
+        // wrong arity
+        assert!(BBox::parse_from_parameter("1,2,3").is_err());
+        assert!(BBox::parse_from_parameter("1,2,3,4,5").is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// An enum whose variants map to bits of an integer bitmask.
+///
+/// This trait is implemented by the [`Enum`](crate::Enum) derive macro when
+/// the `bitmask` attribute is present. Each variant must have an explicit
+/// discriminant that is used as its bit value.
+pub trait EnumBitmask: Type {
+    /// All bit values in declaration order.
+    const BITS: &'static [u64];
+
+    /// Returns the bit value of this flag.
+    fn bit(&self) -> u64;
+
+    /// Returns the flag whose bit value is `bit`.
+    fn from_bit(bit: u64) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+/// A set of enum flags represented as an integer bitmask on the wire.
+///
+/// The enum must derive [`Enum`](crate::Enum) with the `bitmask` attribute.
+/// Use `Vec<T>` instead if the flags should be represented as an array of
+/// names.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::{Enum, types::{Bitmask, ParseFromJSON, ToJSON}};
+/// use serde_json::json;
+///
+/// #[derive(Debug, Enum, Copy, Clone, Eq, PartialEq)]
+/// #[oai(bitmask)]
+/// enum Permission {
+///     Read = 1,
+///     Write = 2,
+///     Delete = 4,
+/// }
+///
+/// let value = Bitmask::<Permission>::parse_from_json(Some(json!(3))).unwrap();
+/// assert_eq!(value.0, vec![Permission::Read, Permission::Write]);
+/// assert_eq!(value.to_json(), Some(json!(3)));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Bitmask<T>(pub Vec<T>);
+
+impl<T> Deref for Bitmask<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Bitmask<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: EnumBitmask> Type for Bitmask<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        format!("bitmask_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("integer", "int64")))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: EnumBitmask> ParseFromJSON for Bitmask<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let mask = match value.as_u64() {
+            Some(mask) => mask,
+            None => return Err(ParseError::expected_type(value)),
+        };
+
+        let mut flags = Vec::new();
+        let mut remaining = mask;
+        for &bit in T::BITS {
+            if mask & bit == bit {
+                if let Some(flag) = T::from_bit(bit) {
+                    flags.push(flag);
+                    remaining &= !bit;
+                }
+            }
+        }
+
+        if remaining != 0 {
+            return Err(ParseError::custom(format!(
+                "unknown bits in bitmask: {remaining:#b}"
+            )));
+        }
+        Ok(Self(flags))
+    }
+}
+
+impl<T: EnumBitmask> ToJSON for Bitmask<T> {
+    fn to_json(&self) -> Option<Value> {
+        let mask = self.0.iter().fold(0u64, |mask, flag| mask | flag.bit());
+        Some(Value::Number(mask.into()))
+    }
+}
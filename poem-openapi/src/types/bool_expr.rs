@@ -0,0 +1,305 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A boolean filter expression such as `a AND (b OR NOT c)`.
+///
+/// The grammar consists of identifiers (`[a-zA-Z0-9_.]+`), the operators
+/// `AND`, `OR` and `NOT` (case-insensitive) and parentheses. `AND` binds
+/// tighter than `OR`. Malformed input, including unbalanced parentheses, is
+/// rejected with the offset of the offending token.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{BoolExpr, ParseFromParameter};
+///
+/// let expr = BoolExpr::parse_from_parameter("a AND (b OR c)").unwrap();
+/// assert_eq!(expr.to_string(), "a AND (b OR c)");
+///
+/// assert!(BoolExpr::parse_from_parameter("a AND (b OR c").is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BoolExpr {
+    /// A named variable.
+    Var(String),
+    /// Logical negation.
+    Not(Box<BoolExpr>),
+    /// Logical conjunction.
+    And(Box<BoolExpr>, Box<BoolExpr>),
+    /// Logical disjunction.
+    Or(Box<BoolExpr>, Box<BoolExpr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+fn tokenize(input: &str) -> Result<Vec<(usize, Token)>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        match ch {
+            ' ' => {}
+            '(' => tokens.push((offset, Token::Open)),
+            ')' => tokens.push((offset, Token::Close)),
+            ch if ch.is_ascii_alphanumeric() || ch == '_' || ch == '.' => {
+                let mut ident = ch.to_string();
+                while let Some((_, ch)) = chars.peek() {
+                    if ch.is_ascii_alphanumeric() || *ch == '_' || *ch == '.' {
+                        ident.push(*ch);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                let token = match ident.to_ascii_uppercase().as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => Token::Ident(ident),
+                };
+                tokens.push((offset, token));
+            }
+            ch => return Err(format!("unexpected character `{ch}` at offset {offset}")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [(usize, Token)],
+    pos: usize,
+    input_len: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, token)| token)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map(|(offset, _)| *offset)
+            .unwrap_or(self.input_len)
+    }
+
+    fn or_expr(&mut self) -> Result<BoolExpr, String> {
+        let mut expr = self.and_expr()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            expr = BoolExpr::Or(Box::new(expr), Box::new(self.and_expr()?));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<BoolExpr, String> {
+        let mut expr = self.factor()?;
+        while self.peek() == Some(&Token::And) {
+            self.pos += 1;
+            expr = BoolExpr::And(Box::new(expr), Box::new(self.factor()?));
+        }
+        Ok(expr)
+    }
+
+    fn factor(&mut self) -> Result<BoolExpr, String> {
+        let offset = self.offset();
+        match self.peek() {
+            Some(Token::Not) => {
+                self.pos += 1;
+                Ok(BoolExpr::Not(Box::new(self.factor()?)))
+            }
+            Some(Token::Ident(ident)) => {
+                let expr = BoolExpr::Var(ident.clone());
+                self.pos += 1;
+                Ok(expr)
+            }
+            Some(Token::Open) => {
+                self.pos += 1;
+                let expr = self.or_expr()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(format!("unbalanced parenthesis at offset {offset}"));
+                }
+                self.pos += 1;
+                Ok(expr)
+            }
+            Some(Token::Close) => Err(format!("unbalanced parenthesis at offset {offset}")),
+            _ => Err(format!("expected an expression at offset {offset}")),
+        }
+    }
+}
+
+fn parse_expr(input: &str) -> Result<BoolExpr, String> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser {
+        tokens: &tokens,
+        pos: 0,
+        input_len: input.len(),
+    };
+    let expr = parser.or_expr()?;
+    match parser.peek() {
+        Some(Token::Close) => Err(format!(
+            "unbalanced parenthesis at offset {}",
+            parser.offset()
+        )),
+        Some(_) => Err(format!("unexpected token at offset {}", parser.offset())),
+        None => Ok(expr),
+    }
+}
+
+impl Display for BoolExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn fmt_operand(
+            expr: &BoolExpr,
+            parent_is_and: bool,
+            f: &mut fmt::Formatter<'_>,
+        ) -> fmt::Result {
+            // `OR` inside an `AND`, and any binary operator under `NOT`,
+            // need explicit parentheses to keep the precedence
+            let needs_parens = match expr {
+                BoolExpr::Or(_, _) => parent_is_and,
+                _ => false,
+            };
+            if needs_parens {
+                write!(f, "({expr})")
+            } else {
+                write!(f, "{expr}")
+            }
+        }
+
+        match self {
+            BoolExpr::Var(name) => f.write_str(name),
+            BoolExpr::Not(expr) => {
+                if matches!(**expr, BoolExpr::And(_, _) | BoolExpr::Or(_, _)) {
+                    write!(f, "NOT ({expr})")
+                } else {
+                    write!(f, "NOT {expr}")
+                }
+            }
+            BoolExpr::And(lhs, rhs) => {
+                fmt_operand(lhs, true, f)?;
+                f.write_str(" AND ")?;
+                fmt_operand(rhs, true, f)
+            }
+            BoolExpr::Or(lhs, rhs) => {
+                fmt_operand(lhs, false, f)?;
+                f.write_str(" OR ")?;
+                fmt_operand(rhs, false, f)
+            }
+        }
+    }
+}
+
+impl Type for BoolExpr {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_bool-expr".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some(
+                "A boolean expression over identifiers using `AND`, `OR`, `NOT` and parentheses.",
+            ),
+            ..MetaSchema::new_with_format("string", "bool-expr")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for BoolExpr {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_expr(&value).map_err(ParseError::custom)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for BoolExpr {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_expr(value).map_err(ParseError::custom)
+    }
+}
+
+impl ToJSON for BoolExpr {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_nested_expression() {
+        let expr = BoolExpr::parse_from_parameter("a AND (b OR NOT c)").unwrap();
+        assert_eq!(
+            expr,
+            BoolExpr::And(
+                Box::new(BoolExpr::Var("a".to_string())),
+                Box::new(BoolExpr::Or(
+                    Box::new(BoolExpr::Var("b".to_string())),
+                    Box::new(BoolExpr::Not(Box::new(BoolExpr::Var("c".to_string())))),
+                )),
+            )
+        );
+        assert_eq!(expr.to_string(), "a AND (b OR NOT c)");
+    }
+
+    #[test]
+    fn reject_unbalanced_parens() {
+        let err = BoolExpr::parse_from_parameter("a AND (b OR c").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("unbalanced parenthesis at offset 6")
+        );
+
+        let err = BoolExpr::parse_from_parameter("a) AND b").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("unbalanced parenthesis at offset 1")
+        );
+    }
+
+    #[test]
+    fn reject_malformed_expressions() {
+        for value in ["", "AND", "a OR", "a b", "a &"] {
+            assert!(BoolExpr::parse_from_parameter(value).is_err(), "{value:?}");
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+    time::Duration,
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A duration constrained to the inclusive range `MIN_SECS..=MAX_SECS`.
+///
+/// Accepts either a number of seconds or a string with a unit suffix
+/// (`500ms`, `30s`, `5m`, `1h`); serializes as seconds. The schema carries
+/// the bounds as `minimum` and `maximum`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use poem_openapi::types::{BoundedDuration, ParseFromParameter};
+///
+/// type Timeout = BoundedDuration<1, 3600>;
+///
+/// assert_eq!(
+///     Timeout::parse_from_parameter("5m").unwrap().0,
+///     Duration::from_secs(300)
+/// );
+/// assert!(Timeout::parse_from_parameter("500ms").is_err());
+/// assert!(Timeout::parse_from_parameter("2h").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BoundedDuration<const MIN_SECS: u64, const MAX_SECS: u64>(pub Duration);
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> Deref for BoundedDuration<MIN_SECS, MAX_SECS> {
+    type Target = Duration;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> Display for BoundedDuration<MIN_SECS, MAX_SECS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}s", self.0.as_secs_f64())
+    }
+}
+
+fn parse_duration_str<T: Type>(value: &str) -> Result<Duration, ParseError<T>> {
+    let value = value.trim();
+    let split = value
+        .find(|ch: char| !ch.is_ascii_digit() && ch != '.')
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number = number
+        .parse::<f64>()
+        .map_err(|_| ParseError::custom(format!("invalid duration: {value}")))?;
+    let secs = match unit {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 3600.0,
+        _ => return Err(ParseError::custom(format!("invalid duration unit: {unit}"))),
+    };
+    if !secs.is_finite() || secs < 0.0 {
+        return Err(ParseError::custom(format!("invalid duration: {value}")));
+    }
+    Ok(Duration::from_secs_f64(secs))
+}
+
+fn check_bounds<T: Type>(duration: Duration, min: u64, max: u64) -> Result<(), ParseError<T>> {
+    if duration < Duration::from_secs(min) || duration > Duration::from_secs(max) {
+        return Err(ParseError::custom(format!(
+            "the duration must be between {min}s and {max}s, but got {}s",
+            duration.as_secs_f64()
+        )));
+    }
+    Ok(())
+}
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> Type for BoundedDuration<MIN_SECS, MAX_SECS> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Duration;
+
+    type RawElementValueType = Duration;
+
+    fn name() -> Cow<'static, str> {
+        format!("duration_{MIN_SECS}_to_{MAX_SECS}").into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            minimum: Some(MIN_SECS as f64),
+            maximum: Some(MAX_SECS as f64),
+            ..MetaSchema::new_with_format("number", "seconds")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> ParseFromJSON
+    for BoundedDuration<MIN_SECS, MAX_SECS>
+{
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let duration = match &value {
+            Value::Number(number) => {
+                let secs = number
+                    .as_f64()
+                    .filter(|secs| secs.is_finite() && *secs >= 0.0)
+                    .ok_or_else(|| ParseError::expected_type(value.clone()))?;
+                Duration::from_secs_f64(secs)
+            }
+            Value::String(value) => parse_duration_str(value)?,
+            _ => return Err(ParseError::expected_type(value)),
+        };
+        check_bounds(duration, MIN_SECS, MAX_SECS)?;
+        Ok(Self(duration))
+    }
+}
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> ParseFromParameter
+    for BoundedDuration<MIN_SECS, MAX_SECS>
+{
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        let duration = parse_duration_str(value)?;
+        check_bounds(duration, MIN_SECS, MAX_SECS)?;
+        Ok(Self(duration))
+    }
+}
+
+impl<const MIN_SECS: u64, const MAX_SECS: u64> ToJSON for BoundedDuration<MIN_SECS, MAX_SECS> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::from(self.0.as_secs_f64()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type Timeout = BoundedDuration<1, 3600>;
+
+    #[test]
+    fn parse_in_range() {
+        assert_eq!(
+            Timeout::parse_from_json(Some(json!(30))).unwrap().0,
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            Timeout::parse_from_json(Some(json!("5m"))).unwrap().0,
+            Duration::from_secs(300)
+        );
+        assert_eq!(
+            Timeout::parse_from_parameter("1h").unwrap().0,
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn reject_too_short() {
+        let err = Timeout::parse_from_parameter("500ms").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("the duration must be between 1s and 3600s")
+        );
+    }
+
+    #[test]
+    fn reject_too_long() {
+        assert!(Timeout::parse_from_json(Some(json!(3601))).is_err());
+        assert!(Timeout::parse_from_parameter("2h").is_err());
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!(Timeout::parse_from_parameter("abc").is_err());
+        assert!(Timeout::parse_from_parameter("5y").is_err());
+        assert!(Timeout::parse_from_json(Some(json!(-1))).is_err());
+    }
+
+    #[test]
+    fn schema() {
+        let schema = Timeout::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "number");
+        assert_eq!(meta.minimum, Some(1.0));
+        assert_eq!(meta.maximum, Some(3600.0));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::{http::HeaderValue, web::Field};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+/// An integer constrained to the inclusive range `MIN..=MAX`, emitting
+/// `minimum` and `maximum` in the schema.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{BoundedInt, ParseFromParameter};
+///
+/// type PageSize = BoundedInt<1, 100>;
+///
+/// assert_eq!(PageSize::parse_from_parameter("50").unwrap().0, 50);
+/// assert!(PageSize::parse_from_parameter("0").is_err());
+/// assert!(PageSize::parse_from_parameter("101").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct BoundedInt<const MIN: i64, const MAX: i64>(pub i64);
+
+impl<const MIN: i64, const MAX: i64> Deref for BoundedInt<MIN, MAX> {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> Display for BoundedInt<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn check_bounds<T: Type>(value: i64, min: i64, max: i64) -> Result<(), ParseError<T>> {
+    if value < min || value > max {
+        return Err(ParseError::custom(format!(
+            "the value must be between {min} and {max}, but got {value}"
+        )));
+    }
+    Ok(())
+}
+
+impl<const MIN: i64, const MAX: i64> Type for BoundedInt<MIN, MAX> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = i64;
+
+    type RawElementValueType = i64;
+
+    fn name() -> Cow<'static, str> {
+        format!("integer_{MIN}_to_{MAX}").into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            minimum: Some(MIN as f64),
+            maximum: Some(MAX as f64),
+            ..MetaSchema::new_with_format("integer", "int64")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> ParseFromJSON for BoundedInt<MIN, MAX> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let n = value
+            .as_i64()
+            .ok_or_else(|| ParseError::expected_type(value.clone()))?;
+        check_bounds(n, MIN, MAX)?;
+        Ok(Self(n))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> ParseFromParameter for BoundedInt<MIN, MAX> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        let n = value.parse::<i64>().map_err(ParseError::custom)?;
+        check_bounds(n, MIN, MAX)?;
+        Ok(Self(n))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> ParseFromMultipartField for BoundedInt<MIN, MAX> {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => Self::parse_from_parameter(&field.text().await?),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> ToJSON for BoundedInt<MIN, MAX> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::from(self.0))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> ToHeader for BoundedInt<MIN, MAX> {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0.to_string()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    type PageSize = BoundedInt<1, 100>;
+
+    #[test]
+    fn parse_in_range() {
+        assert_eq!(PageSize::parse_from_json(Some(json!(1))).unwrap().0, 1);
+        assert_eq!(PageSize::parse_from_json(Some(json!(100))).unwrap().0, 100);
+        assert_eq!(PageSize::parse_from_parameter("42").unwrap().0, 42);
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        let err = PageSize::parse_from_json(Some(json!(0))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("the value must be between 1 and 100, but got 0")
+        );
+        assert!(PageSize::parse_from_parameter("101").is_err());
+        assert!(PageSize::parse_from_parameter("abc").is_err());
+    }
+
+    #[test]
+    fn schema() {
+        let schema = PageSize::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "integer");
+        assert_eq!(meta.minimum, Some(1.0));
+        assert_eq!(meta.maximum, Some(100.0));
+    }
+}
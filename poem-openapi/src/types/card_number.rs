@@ -0,0 +1,167 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Debug, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A payment card number validated with the Luhn checksum.
+///
+/// Only digits are accepted (no spaces or dashes), the length must be between
+/// 8 and 19 digits, and the Luhn check digit must match. `Debug` and
+/// `Display` redact everything but the last four digits so the full number
+/// does not end up in logs.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct CardNumber(String);
+
+impl CardNumber {
+    /// Create a new card number, returning `None` if the value is not
+    /// digits-only or fails the Luhn checksum.
+    pub fn new(number: impl Into<String>) -> Option<Self> {
+        let number = number.into();
+        if is_valid_card_number(&number) {
+            Some(Self(number))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the full card number.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the last four digits of the card number.
+    pub fn last_four(&self) -> &str {
+        &self.0[self.0.len() - 4..]
+    }
+
+    /// Consumes this object and returns the full card number.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Debug for CardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CardNumber")
+            .field(&format!("****{}", self.last_four()))
+            .finish()
+    }
+}
+
+impl Display for CardNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "****{}", self.last_four())
+    }
+}
+
+fn is_valid_card_number(number: &str) -> bool {
+    if !(8..=19).contains(&number.len()) || !number.bytes().all(|ch| ch.is_ascii_digit()) {
+        return false;
+    }
+
+    let sum: u32 = number
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(idx, ch)| {
+            let digit = u32::from(ch - b'0');
+            if idx % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+impl Type for CardNumber {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_card-number".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^[0-9]{8,19}$".to_string()),
+            ..MetaSchema::new_with_format("string", "card-number")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for CardNumber {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            CardNumber::new(value).ok_or_else(|| ParseError::custom("invalid card number"))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for CardNumber {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        CardNumber::new(value).ok_or_else(|| ParseError::custom("invalid card number"))
+    }
+}
+
+impl ToJSON for CardNumber {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_luhn() {
+        let number = CardNumber::parse_from_json(Some(json!("4242424242424242"))).unwrap();
+        assert_eq!(number.as_str(), "4242424242424242");
+        assert_eq!(number.last_four(), "4242");
+    }
+
+    #[test]
+    fn reject_invalid_numbers() {
+        // bad check digit
+        assert!(CardNumber::parse_from_json(Some(json!("4242424242424241"))).is_err());
+        // non-digits
+        assert!(CardNumber::parse_from_json(Some(json!("4242 4242 4242 4242"))).is_err());
+        // too short
+        assert!(CardNumber::parse_from_json(Some(json!("4242424"))).is_err());
+    }
+
+    #[test]
+    fn redacted_debug() {
+        let number = CardNumber::new("4242424242424242").unwrap();
+        assert_eq!(format!("{number:?}"), r#"CardNumber("****4242")"#);
+        assert_eq!(number.to_string(), "****4242");
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// An RGBA color.
+///
+/// Accepts either a `#RRGGBBAA` hex string or a `{"r", "g", "b", "a"}` object
+/// on input, and always serializes to the hex string form.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Color {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+    /// The alpha component.
+    pub a: u8,
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+}
+
+fn parse_hex(value: &str) -> Option<Color> {
+    let value = value.strip_prefix('#')?;
+    if value.len() != 8 || !value.bytes().all(|ch| ch.is_ascii_hexdigit()) {
+        return None;
+    }
+    Some(Color {
+        r: u8::from_str_radix(&value[0..2], 16).ok()?,
+        g: u8::from_str_radix(&value[2..4], 16).ok()?,
+        b: u8::from_str_radix(&value[4..6], 16).ok()?,
+        a: u8::from_str_radix(&value[6..8], 16).ok()?,
+    })
+}
+
+impl Type for Color {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "color".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^#[0-9a-fA-F]{8}$".to_string()),
+            ..MetaSchema::new_with_format("string", "color")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Color {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::String(value) => parse_hex(&value)
+                .ok_or_else(|| ParseError::custom(format!("invalid color: {value}"))),
+            Value::Object(obj) => {
+                let component = |name: &str| -> Result<u8, ParseError<Self>> {
+                    let value = obj
+                        .get(name)
+                        .ok_or_else(|| ParseError::custom(format!("missing field `{name}`")))?;
+                    value
+                        .as_u64()
+                        .and_then(|value| u8::try_from(value).ok())
+                        .ok_or_else(|| {
+                            ParseError::custom(format!(
+                                "the `{name}` component must be an integer between 0 and 255"
+                            ))
+                        })
+                };
+                Ok(Color {
+                    r: component("r")?,
+                    g: component("g")?,
+                    b: component("b")?,
+                    a: component("a")?,
+                })
+            }
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl ToJSON for Color {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_hex_string() {
+        let color = Color::parse_from_json(Some(json!("#11223344"))).unwrap();
+        assert_eq!(
+            color,
+            Color {
+                r: 0x11,
+                g: 0x22,
+                b: 0x33,
+                a: 0x44
+            }
+        );
+        assert_eq!(color.to_json(), Some(json!("#11223344")));
+    }
+
+    #[test]
+    fn parse_object() {
+        let color =
+            Color::parse_from_json(Some(json!({"r": 255, "g": 0, "b": 128, "a": 255}))).unwrap();
+        assert_eq!(
+            color,
+            Color {
+                r: 255,
+                g: 0,
+                b: 128,
+                a: 255
+            }
+        );
+        assert_eq!(color.to_json(), Some(json!("#FF0080FF")));
+    }
+
+    #[test]
+    fn invalid_hex() {
+        assert!(Color::parse_from_json(Some(json!("#112233"))).is_err());
+        assert!(Color::parse_from_json(Some(json!("11223344"))).is_err());
+        assert!(Color::parse_from_json(Some(json!("#1122334G"))).is_err());
+    }
+}
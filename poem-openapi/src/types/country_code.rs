@@ -0,0 +1,176 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// The officially assigned ISO 3166-1 alpha-2 codes.
+#[rustfmt::skip]
+const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT",
+    "AU", "AW", "AX", "AZ", "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI",
+    "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS", "BT", "BV", "BW", "BY",
+    "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM",
+    "DO", "DZ", "EC", "EE", "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK",
+    "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF", "GG", "GH", "GI", "GL",
+    "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR",
+    "IS", "IT", "JE", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN",
+    "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS",
+    "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW",
+    "MX", "MY", "MZ", "NA", "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP",
+    "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG", "PH", "PK", "PL", "PM",
+    "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM",
+    "SN", "SO", "SR", "SS", "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF",
+    "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO", "TR", "TT", "TV", "TW",
+    "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// An ISO 3166-1 alpha-2 country code, validated against the list of
+/// officially assigned codes.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CountryCode(&'static str);
+
+impl CountryCode {
+    /// Create a new country code, returning `None` if the value is not an
+    /// officially assigned code.
+    pub fn new(code: &str) -> Option<Self> {
+        COUNTRY_CODES
+            .binary_search(&code)
+            .ok()
+            .map(|idx| Self(COUNTRY_CODES[idx]))
+    }
+
+    /// Returns the code as a string.
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+
+impl Deref for CountryCode {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.0
+    }
+}
+
+impl Display for CountryCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Type for CountryCode {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_country-code".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            enum_items: COUNTRY_CODES
+                .iter()
+                .map(|code| Value::from(*code))
+                .collect(),
+            ..MetaSchema::new_with_format("string", "country-code")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for CountryCode {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            CountryCode::new(&value)
+                .ok_or_else(|| ParseError::custom(format!("invalid country code: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for CountryCode {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        CountryCode::new(value)
+            .ok_or_else(|| ParseError::custom(format!("invalid country code: {value}")))
+    }
+}
+
+impl ToJSON for CountryCode {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::from(self.0))
+    }
+}
+
+impl ToHeader for CountryCode {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_code() {
+        let code = CountryCode::parse_from_json(Some(json!("US"))).unwrap();
+        assert_eq!(code.as_str(), "US");
+        assert_eq!(code.to_json(), Some(json!("US")));
+    }
+
+    #[test]
+    fn reject_invalid_codes() {
+        for value in ["ZZ", "us", "USA", ""] {
+            assert!(
+                CountryCode::parse_from_json(Some(json!(value))).is_err(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn enum_schema() {
+        let schema = CountryCode::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "string");
+        assert!(meta.enum_items.contains(&json!("US")));
+        assert!(!meta.enum_items.contains(&json!("ZZ")));
+    }
+
+    #[test]
+    fn codes_are_sorted() {
+        // `CountryCode::new` relies on binary search
+        assert!(COUNTRY_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+}
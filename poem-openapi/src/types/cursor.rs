@@ -0,0 +1,128 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use base64::engine::{Engine, general_purpose::URL_SAFE_NO_PAD};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An opaque pagination cursor.
+///
+/// The token is URL-safe base64 without padding, so it can be passed in a
+/// query string unescaped. It is validated and decoded on parse; malformed
+/// tokens are rejected.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cursor {
+    token: String,
+    bytes: Vec<u8>,
+}
+
+impl Cursor {
+    /// Creates a cursor encoding the given payload.
+    pub fn new(bytes: impl Into<Vec<u8>>) -> Self {
+        let bytes = bytes.into();
+        Self {
+            token: URL_SAFE_NO_PAD.encode(&bytes),
+            bytes,
+        }
+    }
+
+    /// The encoded token.
+    pub fn as_str(&self) -> &str {
+        &self.token
+    }
+
+    /// The decoded payload.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.token)
+    }
+}
+
+fn parse_cursor<T: Type>(token: &str) -> Result<Cursor, ParseError<T>> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(token)
+        .map_err(|err| ParseError::custom(format!("invalid cursor: {err}")))?;
+    Ok(Cursor {
+        token: token.to_string(),
+        bytes,
+    })
+}
+
+impl Type for Cursor {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_cursor".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "cursor")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Cursor {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_cursor(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for Cursor {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_cursor(value)
+    }
+}
+
+impl ToJSON for Cursor {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.token.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trip() {
+        let cursor = Cursor::new(*b"item:42");
+        let parsed = Cursor::parse_from_parameter(cursor.as_str()).unwrap();
+        assert_eq!(parsed, cursor);
+        assert_eq!(parsed.bytes(), b"item:42");
+    }
+
+    #[test]
+    fn reject_invalid_cursor() {
+        let err = Cursor::parse_from_parameter("not%valid!").unwrap_err();
+        assert!(err.into_message().contains("invalid cursor"));
+    }
+}
@@ -0,0 +1,192 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use base64::engine::{Engine, general_purpose::STANDARD};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An RFC 2397 data URI, e.g. `data:image/png;base64,...`.
+///
+/// The payload is decoded on parse — base64 when the `;base64` marker is
+/// present, percent-decoding otherwise — and exposed together with the media
+/// type. Malformed data URIs are rejected.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DataUri {
+    uri: String,
+    media_type: String,
+    bytes: Vec<u8>,
+}
+
+impl DataUri {
+    /// The media type, e.g. `image/png`. Defaults to
+    /// `text/plain;charset=US-ASCII` when the URI does not carry one.
+    pub fn media_type(&self) -> &str {
+        &self.media_type
+    }
+
+    /// The decoded payload.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the URI and returns the decoded payload.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl Display for DataUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.uri)
+    }
+}
+
+fn percent_decode(value: &str) -> Result<Vec<u8>, &'static str> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut chars = value.bytes();
+    while let Some(ch) = chars.next() {
+        if ch == b'%' {
+            let hi = chars.next().ok_or("truncated percent sequence")?;
+            let lo = chars.next().ok_or("truncated percent sequence")?;
+            let hex = [hi, lo];
+            let hex = std::str::from_utf8(&hex).map_err(|_| "invalid percent sequence")?;
+            bytes.push(u8::from_str_radix(hex, 16).map_err(|_| "invalid percent sequence")?);
+        } else {
+            bytes.push(ch);
+        }
+    }
+    Ok(bytes)
+}
+
+fn parse_data_uri<T: Type>(uri: &str) -> Result<DataUri, ParseError<T>> {
+    let rest = uri
+        .strip_prefix("data:")
+        .ok_or_else(|| ParseError::custom("a data URI must start with `data:`"))?;
+    let (header, data) = rest
+        .split_once(',')
+        .ok_or_else(|| ParseError::custom("a data URI must contain a `,` separator"))?;
+
+    let (media_type, is_base64) = match header.strip_suffix(";base64") {
+        Some(media_type) => (media_type, true),
+        None => (header, false),
+    };
+    let media_type = if media_type.is_empty() {
+        "text/plain;charset=US-ASCII".to_string()
+    } else {
+        if !media_type.contains('/') {
+            return Err(ParseError::custom(format!(
+                "invalid media type: {media_type:?}"
+            )));
+        }
+        media_type.to_string()
+    };
+
+    let bytes = if is_base64 {
+        STANDARD
+            .decode(data)
+            .map_err(|err| ParseError::custom(format!("invalid base64 payload: {err}")))?
+    } else {
+        percent_decode(data).map_err(ParseError::custom)?
+    };
+
+    Ok(DataUri {
+        uri: uri.to_string(),
+        media_type,
+        bytes,
+    })
+}
+
+impl Type for DataUri {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_uri".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some("An RFC 2397 data URI."),
+            ..MetaSchema::new_with_format("string", "uri")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for DataUri {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_data_uri(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for DataUri {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_data_uri(value)
+    }
+}
+
+impl ToJSON for DataUri {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.uri.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_base64_data_uri() {
+        let uri = DataUri::parse_from_parameter("data:image/png;base64,aGVsbG8=").unwrap();
+        assert_eq!(uri.media_type(), "image/png");
+        assert_eq!(uri.bytes(), b"hello");
+        assert_eq!(
+            uri.to_json(),
+            Some(Value::String("data:image/png;base64,aGVsbG8=".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_percent_encoded_data_uri() {
+        let uri = DataUri::parse_from_parameter("data:,Hello%2C%20World%21").unwrap();
+        assert_eq!(uri.media_type(), "text/plain;charset=US-ASCII");
+        assert_eq!(uri.bytes(), b"Hello, World!");
+    }
+
+    #[test]
+    fn reject_malformed_data_uris() {
+        // not a data URI
+        assert!(DataUri::parse_from_parameter("https://example.com/a.png").is_err());
+        // missing separator
+        assert!(DataUri::parse_from_parameter("data:image/png;base64").is_err());
+        // invalid base64
+        assert!(DataUri::parse_from_parameter("data:image/png;base64,!!!").is_err());
+        // invalid media type
+        assert!(DataUri::parse_from_parameter("data:png;base64,aGVsbG8=").is_err());
+        // truncated percent sequence
+        assert!(DataUri::parse_from_parameter("data:,abc%2").is_err());
+    }
+}
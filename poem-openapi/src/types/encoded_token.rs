@@ -0,0 +1,173 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An opaque token transported in percent-encoded form.
+///
+/// Parsing percent-decodes the value and validates that the decoded bytes are
+/// valid UTF-8; serializing percent-encodes everything outside the unreserved
+/// set (`A-Z a-z 0-9 - . _ ~`), so the emitted value is always safe to embed
+/// in a URL.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EncodedToken(pub String);
+
+impl EncodedToken {
+    /// Returns the decoded token.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the percent-encoded form of the token.
+    pub fn to_encoded(&self) -> String {
+        let mut encoded = String::new();
+        for byte in self.0.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(byte as char);
+            } else {
+                encoded.push('%');
+                encoded.push(
+                    char::from_digit(u32::from(byte >> 4), 16)
+                        .unwrap()
+                        .to_ascii_uppercase(),
+                );
+                encoded.push(
+                    char::from_digit(u32::from(byte & 0xf), 16)
+                        .unwrap()
+                        .to_ascii_uppercase(),
+                );
+            }
+        }
+        encoded
+    }
+
+    /// Consumes this object and returns the decoded token.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for EncodedToken {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for EncodedToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn percent_decode<T: Type>(value: &str) -> Result<String, ParseError<T>> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut iter = value.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hi = iter.next().and_then(|ch| (ch as char).to_digit(16));
+            let lo = iter.next().and_then(|ch| (ch as char).to_digit(16));
+            match (hi, lo) {
+                (Some(hi), Some(lo)) => bytes.push((hi * 16 + lo) as u8),
+                _ => return Err(ParseError::custom("invalid percent sequence")),
+            }
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| ParseError::custom("the decoded token is not valid UTF-8"))
+}
+
+impl Type for EncodedToken {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_encoded-token".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
+            "string",
+            "encoded-token",
+        )))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ParseFromJSON for EncodedToken {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self(percent_decode(&value)?))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for EncodedToken {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(Self(percent_decode(value)?))
+    }
+}
+
+impl ToJSON for EncodedToken {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_encoded()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let token = EncodedToken::parse_from_json(Some(json!("sig%3Dabc%2F123%20x"))).unwrap();
+        assert_eq!(token.as_str(), "sig=abc/123 x");
+        assert_eq!(token.to_json(), Some(json!("sig%3Dabc%2F123%20x")));
+
+        // unreserved characters pass through unchanged
+        let token = EncodedToken::parse_from_json(Some(json!("plain-token_1.2~3"))).unwrap();
+        assert_eq!(token.to_json(), Some(json!("plain-token_1.2~3")));
+    }
+
+    #[test]
+    fn invalid_sequences() {
+        // truncated percent sequence
+        assert!(EncodedToken::parse_from_json(Some(json!("abc%2"))).is_err());
+        // non-hex digits
+        assert!(EncodedToken::parse_from_json(Some(json!("abc%zz"))).is_err());
+        // decodes to invalid UTF-8
+        assert!(EncodedToken::parse_from_json(Some(json!("%ff%fe"))).is_err());
+    }
+}
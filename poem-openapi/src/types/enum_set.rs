@@ -0,0 +1,155 @@
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An enum whose items are known at compile time.
+///
+/// This trait is implemented by the [`Enum`](crate::Enum) derive macro.
+pub trait EnumItems: Type {
+    /// The names of all items in declaration order.
+    const ITEMS: &'static [&'static str];
+}
+
+/// A deduplicated set of enum values that preserves the order of first
+/// occurrence.
+///
+/// When used as a query parameter, unknown names are rejected with an error
+/// that lists the valid options.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::{Enum, types::{EnumSet, ParseFromParameter}};
+///
+/// #[derive(Debug, Enum, Copy, Clone, Eq, PartialEq)]
+/// #[oai(rename_all = "lowercase")]
+/// enum Feature {
+///     Alpha,
+///     Beta,
+///     Gamma,
+/// }
+///
+/// let set = EnumSet::<Feature>::parse_from_parameters(["alpha", "gamma", "alpha"]).unwrap();
+/// assert_eq!(set.0, vec![Feature::Alpha, Feature::Gamma]);
+///
+/// let err = EnumSet::<Feature>::parse_from_parameters(["delta"]).unwrap_err();
+/// assert!(
+///     err.into_message()
+///         .contains(r#"invalid value "delta", expected one of: alpha, beta, gamma"#)
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EnumSet<T>(pub Vec<T>);
+
+impl<T> Deref for EnumSet<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for EnumSet<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: EnumItems> Type for EnumSet<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("set_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(T::schema_ref())),
+            unique_items: Some(true),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn parse_item<T: EnumItems + ParseFromParameter>(value: &str) -> ParseResult<T> {
+    T::parse_from_parameter(value).map_err(|_| {
+        ParseError::custom(format!(
+            "invalid value \"{value}\", expected one of: {}",
+            T::ITEMS.join(", ")
+        ))
+    })
+}
+
+impl<T: EnumItems + ParseFromParameter + PartialEq> ParseFromParameter for EnumSet<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Self::parse_from_parameters([value])
+    }
+
+    fn parse_from_parameters<I: IntoIterator<Item = A>, A: AsRef<str>>(
+        iter: I,
+    ) -> ParseResult<Self> {
+        let mut items = Vec::new();
+        for value in iter {
+            let item = parse_item::<T>(value.as_ref()).map_err(ParseError::propagate)?;
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+        Ok(Self(items))
+    }
+}
+
+impl<T: EnumItems + ParseFromJSON + PartialEq> ParseFromJSON for EnumSet<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::Array(values) => {
+                let mut items = Vec::new();
+                for value in values {
+                    let item = T::parse_from_json(Some(value)).map_err(ParseError::propagate)?;
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Ok(Self(items))
+            }
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<T: EnumItems + ToJSON> ToJSON for EnumSet<T> {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
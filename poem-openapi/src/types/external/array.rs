@@ -20,7 +20,7 @@ impl<T: Type, const LEN: usize> Type for [T; LEN] {
 
     fn schema_ref() -> MetaSchemaRef {
         MetaSchemaRef::Inline(Box::new(MetaSchema {
-            items: Some(Box::new(T::schema_ref())),
+            items: Some(Box::new(crate::types::item_schema_ref::<T>())),
             max_length: Some(LEN),
             min_length: Some(LEN),
             ..MetaSchema::new("array")
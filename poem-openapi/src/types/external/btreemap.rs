@@ -4,7 +4,7 @@ use serde_json::Value;
 
 use crate::{
     registry::{MetaSchema, MetaSchemaRef, Registry},
-    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
 };
 
 impl<K, V> Type for BTreeMap<K, V>
@@ -72,6 +72,34 @@ where
     }
 }
 
+impl<K, V> ParseFromParameter for BTreeMap<K, V>
+where
+    K: ToString + FromStr + Ord + Sync + Send,
+    K::Err: Display,
+    V: ParseFromParameter,
+{
+    fn parse_from_parameter(_value: &str) -> ParseResult<Self> {
+        Err(ParseError::custom(
+            "map parameters must use the deepObject style",
+        ))
+    }
+
+    fn parse_from_deep_object<'a, I>(pairs: I) -> ParseResult<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut obj = BTreeMap::new();
+        for (key, value) in pairs {
+            let key = key
+                .parse()
+                .map_err(|err| ParseError::custom(format!("object key: {err}")))?;
+            let value = V::parse_from_parameter(value).map_err(ParseError::propagate)?;
+            obj.insert(key, value);
+        }
+        Ok(obj)
+    }
+}
+
 impl<K, V> ToJSON for BTreeMap<K, V>
 where
     K: ToString + FromStr + Ord + Sync + Send,
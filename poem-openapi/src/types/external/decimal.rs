@@ -87,3 +87,147 @@ impl ToHeader for Decimal {
         HeaderValue::from_str(&self.normalize().to_string()).ok()
     }
 }
+
+/// A non-negative decimal amount with at most two decimal places.
+///
+/// Negative values and values with more than two decimal places are rejected
+/// on parse. The schema carries `minimum: 0` and the `price` format; amounts
+/// serialize in normalized string form like [`Decimal`].
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{ParseFromParameter, Price};
+///
+/// assert!(Price::parse_from_parameter("19.99").is_ok());
+/// assert!(Price::parse_from_parameter("-1").is_err());
+/// assert!(Price::parse_from_parameter("19.999").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Price(Decimal);
+
+/// The maximum number of decimal places a [`Price`] may carry.
+const PRICE_SCALE: u32 = 2;
+
+impl Price {
+    /// Creates a `Price`, returning an error message if the value is negative
+    /// or has more than two decimal places.
+    pub fn new(value: Decimal) -> Result<Self, String> {
+        if value.is_sign_negative() && !value.is_zero() {
+            return Err(format!("the price must not be negative, but got {value}"));
+        }
+        if value.normalize().scale() > PRICE_SCALE {
+            return Err(format!(
+                "the price must have at most {PRICE_SCALE} decimal places, but got {value}"
+            ));
+        }
+        Ok(Self(value))
+    }
+
+    /// Returns the inner decimal value.
+    #[inline]
+    pub fn as_decimal(&self) -> Decimal {
+        self.0
+    }
+}
+
+impl std::fmt::Display for Price {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.normalize())
+    }
+}
+
+impl Type for Price {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Decimal;
+
+    type RawElementValueType = Decimal;
+
+    fn name() -> Cow<'static, str> {
+        "string_price".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            minimum: Some(0.0),
+            description: Some("A non-negative amount with at most 2 decimal places."),
+            ..MetaSchema::new_with_format("string", "price")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Price {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = Decimal::parse_from_json(value).map_err(ParseError::propagate)?;
+        Price::new(value).map_err(ParseError::custom)
+    }
+}
+
+impl ParseFromParameter for Price {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        let value = Decimal::parse_from_parameter(value).map_err(ParseError::propagate)?;
+        Price::new(value).map_err(ParseError::custom)
+    }
+}
+
+impl ParseFromMultipartField for Price {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        let value = Decimal::parse_from_multipart(field)
+            .await
+            .map_err(ParseError::propagate)?;
+        Price::new(value).map_err(ParseError::custom)
+    }
+}
+
+impl ToJSON for Price {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
+
+impl ToHeader for Price {
+    fn to_header(&self) -> Option<HeaderValue> {
+        self.0.to_header()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn price_accepts_valid_amounts() {
+        let price = Price::parse_from_json(Some(json!("19.99"))).unwrap();
+        assert_eq!(price.as_decimal(), Decimal::new(1999, 2));
+        assert_eq!(price.to_json(), Some(json!("19.99")));
+
+        // integers and trailing zeros are fine
+        assert!(Price::parse_from_parameter("0").is_ok());
+        assert!(Price::parse_from_parameter("10.500").is_ok());
+    }
+
+    #[test]
+    fn price_rejects_negative_amounts() {
+        let err = Price::parse_from_json(Some(json!("-0.01"))).unwrap_err();
+        assert!(err.into_message().contains("must not be negative"));
+    }
+
+    #[test]
+    fn price_rejects_over_scale_amounts() {
+        let err = Price::parse_from_parameter("19.999").unwrap_err();
+        assert!(err.into_message().contains("at most 2 decimal places"));
+    }
+}
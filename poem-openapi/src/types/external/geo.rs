@@ -85,7 +85,7 @@ impl_geojson_types!(MultiPolygon, "MultiPolygon", Vec<Vec<Vec<[T; 2]>>>);
 
 #[cfg(test)]
 mod tests {
-    use geo_types::Point;
+    use geo_types::{LineString, Point};
 
     use crate::types::{ParseFromJSON, ToJSON};
 
@@ -112,4 +112,28 @@ mod tests {
             point_geo()
         )
     }
+
+    #[test]
+    fn line_string_round_trip() {
+        let line = LineString::from(vec![(0.0, 0.0), (3.0, 4.0)]);
+        let json = serde_json::json!({
+            "type": "LineString",
+            "coordinates": [[0.0, 0.0], [3.0, 4.0]]
+        });
+        assert_eq!(line.to_json().unwrap(), json);
+        assert_eq!(LineString::parse_from_json(Some(json)).unwrap(), line);
+    }
+
+    #[test]
+    fn rejects_mismatched_discriminator() {
+        // a valid geometry of the wrong kind must not parse
+        assert!(
+            Point::parse_from_json(Some(serde_json::json!({
+                "type": "LineString",
+                "coordinates": [[0.0, 0.0], [3.0, 4.0]]
+            })))
+            .is_err()
+        );
+        assert!(LineString::parse_from_json(Some(point_json())).is_err());
+    }
 }
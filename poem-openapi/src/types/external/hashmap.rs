@@ -10,7 +10,7 @@ use serde_json::Value;
 
 use crate::{
     registry::{MetaSchema, MetaSchemaRef, Registry},
-    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
 };
 
 impl<K, V, R> Type for HashMap<K, V, R>
@@ -80,6 +80,35 @@ where
     }
 }
 
+impl<K, V, R> ParseFromParameter for HashMap<K, V, R>
+where
+    K: ToString + FromStr + Eq + Hash + Sync + Send,
+    K::Err: Display,
+    V: ParseFromParameter,
+    R: Sync + Send + Default + BuildHasher,
+{
+    fn parse_from_parameter(_value: &str) -> ParseResult<Self> {
+        Err(ParseError::custom(
+            "map parameters must use the deepObject style",
+        ))
+    }
+
+    fn parse_from_deep_object<'a, I>(pairs: I) -> ParseResult<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut obj = HashMap::with_hasher(R::default());
+        for (key, value) in pairs {
+            let key = key
+                .parse()
+                .map_err(|err| ParseError::custom(format!("object key: {err}")))?;
+            let value = V::parse_from_parameter(value).map_err(ParseError::propagate)?;
+            obj.insert(key, value);
+        }
+        Ok(obj)
+    }
+}
+
 impl<K, V, R> ToJSON for HashMap<K, V, R>
 where
     K: ToString + FromStr + Eq + Hash + Sync + Send,
@@ -11,6 +11,57 @@ use crate::{
     },
 };
 
+/// Converts a JSON number to `i64`.
+///
+/// Unless the `strict-integers` feature is enabled, a float with a zero
+/// fractional part (e.g. `3.0`) is accepted as an integer.
+fn as_integer(n: &serde_json::Number) -> Option<i64> {
+    if let Some(n) = n.as_i64() {
+        return Some(n);
+    }
+    #[cfg(not(feature = "strict-integers"))]
+    if let Some(f) = n.as_f64() {
+        if f.fract() == 0.0 && f >= i64::MIN as f64 && f <= i64::MAX as f64 {
+            return Some(f as i64);
+        }
+    }
+    None
+}
+
+/// Converts a JSON number to `u64`.
+///
+/// Unless the `strict-integers` feature is enabled, a float with a zero
+/// fractional part (e.g. `3.0`) is accepted as an integer.
+fn as_unsigned_integer(n: &serde_json::Number) -> Option<u64> {
+    if let Some(n) = n.as_u64() {
+        return Some(n);
+    }
+    #[cfg(not(feature = "strict-integers"))]
+    if let Some(f) = n.as_f64() {
+        if f.fract() == 0.0 && f >= 0.0 && f <= u64::MAX as f64 {
+            return Some(f as u64);
+        }
+    }
+    None
+}
+
+/// Converts an integer to a JSON value.
+///
+/// When the `js-safe-integers` feature is enabled, values outside the
+/// JavaScript safe integer range (`±(2^53 - 1)`) are serialized as strings to
+/// avoid silent precision loss in JS clients.
+#[allow(unused_variables)]
+fn int_to_value(value: i128, to_number: impl FnOnce() -> serde_json::Number) -> Value {
+    #[cfg(feature = "js-safe-integers")]
+    {
+        const JS_SAFE_INTEGER_MAX: i128 = (1 << 53) - 1;
+        if value > JS_SAFE_INTEGER_MAX || value < -JS_SAFE_INTEGER_MAX {
+            return Value::String(value.to_string());
+        }
+    }
+    Value::Number(to_number())
+}
+
 macro_rules! impl_type_for_integers {
     ($(($ty:ty, $format:literal)),*) => {
         $(
@@ -44,8 +95,7 @@ macro_rules! impl_type_for_integers {
              fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
                  let value = value.unwrap_or_default();
                  if let Value::Number(n) = value {
-                     let n = n
-                         .as_i64()
+                     let n = as_integer(&n)
                          .ok_or_else(|| ParseError::from("invalid integer"))?;
 
                      if n < Self::MIN as i64 || n > Self::MAX as i64 {
@@ -67,8 +117,7 @@ macro_rules! impl_type_for_integers {
              fn parse_from_xml(value: Option<Value>) -> ParseResult<Self> {
                  let value = value.unwrap_or_default();
                  if let Value::Number(n) = value {
-                     let n = n
-                         .as_i64()
+                     let n = as_integer(&n)
                          .ok_or_else(|| ParseError::from("invalid integer"))?;
 
                      if n < Self::MIN as i64 || n > Self::MAX as i64 {
@@ -103,7 +152,7 @@ macro_rules! impl_type_for_integers {
 
         impl ToJSON for $ty {
             fn to_json(&self) -> Option<Value> {
-                Some(Value::Number((*self).into()))
+                Some(int_to_value(*self as i128, || (*self).into()))
             }
         }
 
@@ -159,8 +208,7 @@ macro_rules! impl_type_for_unsigneds {
              fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
                  let value = value.unwrap_or_default();
                  if let Value::Number(n) = value {
-                     let n = n
-                         .as_u64()
+                     let n = as_unsigned_integer(&n)
                          .ok_or_else(|| ParseError::from("invalid integer"))?;
 
                      if n < Self::MIN as u64 || n > Self::MAX as u64 {
@@ -195,7 +243,7 @@ macro_rules! impl_type_for_unsigneds {
 
         impl ToJSON for $ty {
             fn to_json(&self) -> Option<Value> {
-                Some(Value::Number((*self).into()))
+                Some(int_to_value(*self as i128, || (*self).into()))
             }
         }
 
@@ -221,3 +269,62 @@ impl_type_for_unsigneds!(
     (u64, "uint64"),
     (usize, "uint64")
 );
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_integer() {
+        assert_eq!(i32::parse_from_json(Some(json!(3))).unwrap(), 3);
+        assert!(i32::parse_from_json(Some(json!(3.5))).is_err());
+
+        assert_eq!(u32::parse_from_json(Some(json!(3))).unwrap(), 3);
+        assert!(u32::parse_from_json(Some(json!(3.5))).is_err());
+    }
+
+    #[cfg(not(feature = "strict-integers"))]
+    #[test]
+    fn parse_lossless_float() {
+        assert_eq!(i32::parse_from_json(Some(json!(3.0))).unwrap(), 3);
+        assert_eq!(u32::parse_from_json(Some(json!(3.0))).unwrap(), 3);
+    }
+
+    #[cfg(feature = "strict-integers")]
+    #[test]
+    fn reject_float_in_strict_mode() {
+        assert!(i32::parse_from_json(Some(json!(3.0))).is_err());
+        assert!(u32::parse_from_json(Some(json!(3.0))).is_err());
+    }
+
+    #[cfg(feature = "js-safe-integers")]
+    #[test]
+    fn large_integers_as_strings() {
+        assert_eq!(3i64.to_json(), Some(json!(3)));
+        assert_eq!(
+            (-9007199254740991i64).to_json(),
+            Some(json!(-9007199254740991i64))
+        );
+        assert_eq!(
+            9007199254740992i64.to_json(),
+            Some(json!("9007199254740992"))
+        );
+        assert_eq!(
+            (-9007199254740992i64).to_json(),
+            Some(json!("-9007199254740992"))
+        );
+        assert_eq!(u64::MAX.to_json(), Some(json!("18446744073709551615")));
+        assert_eq!(3u64.to_json(), Some(json!(3)));
+    }
+
+    #[cfg(not(feature = "js-safe-integers"))]
+    #[test]
+    fn large_integers_as_numbers() {
+        assert_eq!(
+            9007199254740992i64.to_json(),
+            Some(json!(9007199254740992i64))
+        );
+    }
+}
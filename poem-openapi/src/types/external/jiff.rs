@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+
+use jiff::{
+    SignedDuration, Span, Timestamp, Zoned,
+    civil::{Date, DateTime, Time},
+    tz::TimeZone,
+};
+use poem::web::Field;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToJSON, Type,
+    },
+};
+
+macro_rules! impl_jiff_types {
+    ($ty:ty, $type_name:literal, $format:literal, $example:literal) => {
+        impl Type for $ty {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> Cow<'static, str> {
+                concat!($type_name, "_", $format).into()
+            }
+
+            fn schema_ref() -> MetaSchemaRef {
+                MetaSchemaRef::Inline(Box::new(MetaSchema {
+                    example: Some(Value::String($example.to_string())),
+                    ..MetaSchema::new_with_format($type_name, $format)
+                }))
+            }
+
+            fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                Some(self)
+            }
+
+            fn raw_element_iter<'a>(
+                &'a self,
+            ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+                Box::new(self.as_raw_value().into_iter())
+            }
+        }
+
+        impl ParseFromJSON for $ty {
+            fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+                let value = value.unwrap_or_default();
+                if let Value::String(value) = value {
+                    Ok(value.parse()?)
+                } else {
+                    Err(ParseError::expected_type(value))
+                }
+            }
+        }
+
+        impl ParseFromParameter for $ty {
+            fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+                // tolerate surrounding whitespace left over from URL decoding
+                Ok(value.trim().parse()?)
+            }
+        }
+
+        impl ParseFromMultipartField for $ty {
+            async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+                match field {
+                    Some(field) => Ok(field.text().await?.parse()?),
+                    None => Err(ParseError::expected_input()),
+                }
+            }
+        }
+
+        impl ToJSON for $ty {
+            fn to_json(&self) -> Option<Value> {
+                Some(Value::String(self.to_string()))
+            }
+        }
+    };
+}
+
+impl_jiff_types!(Timestamp, "string", "date-time", "2024-03-10T10:00:00Z");
+impl_jiff_types!(Date, "string", "date", "2024-03-10");
+impl_jiff_types!(Time, "string", "time", "10:00:00");
+impl_jiff_types!(DateTime, "string", "date-time", "2024-03-10T10:00:00");
+// `Zoned` uses the bracketed zone form, e.g.
+// `2024-03-10T10:00:00+01:00[Europe/Paris]`, so the IANA zone survives a
+// round-trip instead of being flattened to a UTC offset
+impl_jiff_types!(
+    Zoned,
+    "string",
+    "date-time",
+    "2024-03-10T10:00:00+01:00[Europe/Paris]"
+);
+impl_jiff_types!(Span, "string", "duration", "PT1H30M");
+impl_jiff_types!(SignedDuration, "string", "duration", "PT1H30M");
+
+impl Type for TimeZone {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_time-zone".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            example: Some(Value::String("America/New_York".to_string())),
+            ..MetaSchema::new_with_format("string", "time-zone")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for TimeZone {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            TimeZone::get(&value).map_err(ParseError::custom)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for TimeZone {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        TimeZone::get(value.trim()).map_err(ParseError::custom)
+    }
+}
+
+impl ParseFromMultipartField for TimeZone {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => TimeZone::get(&field.text().await?).map_err(ParseError::custom),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl ToJSON for TimeZone {
+    fn to_json(&self) -> Option<Value> {
+        self.iana_name().map(|name| Value::String(name.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn schemas_carry_examples() {
+        fn check_example<T: ParseFromJSON>() {
+            let schema_ref = T::schema_ref();
+            let example = schema_ref.unwrap_inline().example.clone();
+            assert!(example.is_some(), "{} has no example", T::name());
+            // the example must itself be a valid value of the type
+            assert!(
+                T::parse_from_json(example).is_ok(),
+                "the example of {} does not parse",
+                T::name()
+            );
+        }
+
+        check_example::<Timestamp>();
+        check_example::<Date>();
+        check_example::<Time>();
+        check_example::<DateTime>();
+        check_example::<Zoned>();
+        check_example::<Span>();
+        check_example::<SignedDuration>();
+        check_example::<TimeZone>();
+
+        assert_eq!(
+            Timestamp::schema_ref().unwrap_inline().example,
+            Some(json!("2024-03-10T10:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn timestamp() {
+        let timestamp = Timestamp::parse_from_json(Some(json!("2024-06-19T15:22:45Z"))).unwrap();
+        assert_eq!(timestamp.to_json(), Some(json!("2024-06-19T15:22:45Z")));
+    }
+
+    #[test]
+    fn civil_types_round_trip() {
+        let date = Date::parse_from_json(Some(json!("2024-06-19"))).unwrap();
+        assert_eq!(date.to_json(), Some(json!("2024-06-19")));
+
+        let time = Time::parse_from_parameter("15:22:45").unwrap();
+        assert_eq!(time.to_json(), Some(json!("15:22:45")));
+
+        let date_time = DateTime::parse_from_json(Some(json!("2024-06-19T15:22:45"))).unwrap();
+        assert_eq!(date_time.to_json(), Some(json!("2024-06-19T15:22:45")));
+    }
+
+    #[test]
+    fn durations_round_trip() {
+        let span = Span::parse_from_json(Some(json!("PT1H30M"))).unwrap();
+        assert_eq!(span.to_json(), Some(json!("PT1H30M")));
+
+        // negative spans keep their sign
+        let span = Span::parse_from_parameter("-PT90S").unwrap();
+        assert_eq!(span.to_json(), Some(json!("-PT90S")));
+
+        let duration = SignedDuration::parse_from_json(Some(json!("PT1H30M"))).unwrap();
+        assert_eq!(duration.to_json(), Some(json!("PT1H30M")));
+
+        let duration = SignedDuration::parse_from_parameter("-PT90S").unwrap();
+        assert_eq!(duration, SignedDuration::from_secs(-90));
+
+        assert!(Span::parse_from_parameter("PT").is_err());
+        assert!(SignedDuration::parse_from_parameter("PT").is_err());
+    }
+
+    #[test]
+    fn zoned_round_trip_preserves_zone() {
+        let zoned =
+            Zoned::parse_from_json(Some(json!("2024-03-10T10:00:00+01:00[Europe/Paris]"))).unwrap();
+        assert_eq!(zoned.time_zone().iana_name(), Some("Europe/Paris"));
+        // the zone annotation survives, it is not flattened to an offset
+        assert_eq!(
+            zoned.to_json(),
+            Some(json!("2024-03-10T10:00:00+01:00[Europe/Paris]"))
+        );
+
+        assert!(Zoned::parse_from_parameter("2024-03-10T10:00:00").is_err());
+    }
+
+    #[test]
+    fn time_zone() {
+        let tz = TimeZone::parse_from_parameter("Europe/Paris").unwrap();
+        assert_eq!(tz.to_json(), Some(json!("Europe/Paris")));
+
+        assert!(TimeZone::parse_from_parameter("Mars/Olympus_Mons").is_err());
+        assert!(TimeZone::parse_from_json(Some(json!("Not/A_Zone"))).is_err());
+    }
+
+    #[test]
+    fn parameter_trims_surrounding_whitespace() {
+        // some proxies forward URL-decoded values with stray spaces
+        let timestamp = Timestamp::parse_from_parameter("2024-03-10T10:00:00Z ").unwrap();
+        assert_eq!(timestamp.to_json(), Some(json!("2024-03-10T10:00:00Z")));
+        let timestamp = Timestamp::parse_from_parameter(" 2024-03-10T10:00:00Z").unwrap();
+        assert_eq!(timestamp.to_json(), Some(json!("2024-03-10T10:00:00Z")));
+        assert!(Date::parse_from_parameter(" 2024-03-10 ").is_ok());
+        assert!(TimeZone::parse_from_parameter(" Europe/Paris ").is_ok());
+
+        // whitespace inside the value is still an error
+        assert!(Timestamp::parse_from_parameter("2024-03-10 T10:00:00Z").is_err());
+    }
+
+    #[test]
+    fn reject_invalid_values() {
+        assert!(Date::parse_from_json(Some(json!("2024-13-40"))).is_err());
+        assert!(Time::parse_from_json(Some(json!("25:00:00"))).is_err());
+        assert!(DateTime::parse_from_json(Some(json!("not a date"))).is_err());
+        assert!(Timestamp::parse_from_json(Some(json!(123))).is_err());
+    }
+}
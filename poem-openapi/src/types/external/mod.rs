@@ -8,7 +8,7 @@ mod char;
 #[cfg(feature = "chrono")]
 mod chrono;
 #[cfg(feature = "rust_decimal")]
-mod decimal;
+pub(crate) mod decimal;
 mod floats;
 #[cfg(feature = "geo")]
 mod geo;
@@ -20,16 +20,26 @@ mod humantime;
 mod humantime_wrapper;
 mod integers;
 mod ip;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+pub(crate) mod ndarray;
 mod optional;
 #[cfg(feature = "prost-wkt-types")]
 mod prost_wkt_types;
 mod regex;
+#[cfg(feature = "semver")]
+pub(crate) mod semver;
 mod slice;
 #[cfg(feature = "sqlx")]
 mod sqlx;
 mod string;
 #[cfg(feature = "time")]
 mod time;
+#[cfg(feature = "ulid")]
+mod ulid;
 mod uri;
 #[cfg(feature = "url")]
 mod url;
@@ -0,0 +1,190 @@
+use std::borrow::Cow;
+
+use nalgebra::{DMatrix, Scalar};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+impl<T: Type + Scalar> Type for DMatrix<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema {
+                items: Some(Box::new(T::schema_ref())),
+                ..MetaSchema::new("array")
+            })))),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        // also publish the wrapper schema as a named component so tooling can
+        // `$ref` it by `name()`
+        registry.create_schema::<Self, _>(Self::name().into_owned(), |registry| {
+            T::register(registry);
+            MetaSchema {
+                items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema {
+                    items: Some(Box::new(T::schema_ref())),
+                    ..MetaSchema::new("array")
+                })))),
+                ..MetaSchema::new("array")
+            }
+        });
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        DMatrix::is_empty(self)
+    }
+}
+
+/// Refuse to pre-allocate for shapes that cannot plausibly be backed by a
+/// real request body.
+const MAX_ELEMENTS: usize = 1 << 28;
+
+impl<T: ParseFromJSON + Scalar> ParseFromJSON for DMatrix<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Value::Array(rows) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+
+        // cheap structural validation first, so ragged input fails before any
+        // element is parsed or the data vector is allocated
+        let mut columns = None;
+        for (idx, row) in rows.iter().enumerate() {
+            let Value::Array(row) = row else {
+                return Err(ParseError::custom(format!(
+                    "expected an array at row {idx}"
+                )));
+            };
+            match columns {
+                Some(columns) => {
+                    if row.len() != columns {
+                        return Err(ParseError::custom(format!(
+                            "ragged matrix: row {idx} has length {}, expected {columns}",
+                            row.len()
+                        )));
+                    }
+                }
+                None => columns = Some(row.len()),
+            }
+        }
+
+        let num_rows = rows.len();
+        let num_columns = columns.unwrap_or_default();
+        let total = num_rows
+            .checked_mul(num_columns)
+            .filter(|total| *total <= MAX_ELEMENTS)
+            .ok_or_else(|| {
+                ParseError::custom(format!(
+                    "matrix of shape ({num_rows}, {num_columns}) is too large"
+                ))
+            })?;
+
+        let mut data = Vec::with_capacity(total);
+        for (idx, row) in rows.into_iter().enumerate() {
+            let Value::Array(row) = row else {
+                unreachable!()
+            };
+            for (col, value) in row.into_iter().enumerate() {
+                data.push(T::parse_from_json(Some(value)).map_err(|err| {
+                    ParseError::custom(format!("{} (at [{idx}, {col}])", err.message()))
+                })?);
+            }
+        }
+
+        Ok(DMatrix::from_row_iterator(num_rows, num_columns, data))
+    }
+}
+
+impl<T: ToJSON + Scalar> ToJSON for DMatrix<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::Array(
+            self.row_iter()
+                .map(|row| {
+                    Value::Array(
+                        row.iter()
+                            .map(|item| item.to_json().unwrap_or(Value::Null))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn dmatrix_round_trip() {
+        let matrix =
+            DMatrix::<f64>::parse_from_json(Some(json!([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])))
+                .unwrap();
+        assert_eq!(matrix, dmatrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0]);
+        assert_eq!(
+            matrix.to_json(),
+            Some(json!([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+        );
+    }
+
+    #[test]
+    fn dmatrix_rejects_ragged_rows() {
+        let err = DMatrix::<i32>::parse_from_json(Some(json!([[1, 2], [3]]))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("row 1 has length 1, expected 2")
+        );
+
+        // the structural pass runs first, so raggedness is reported even when
+        // an earlier row contains an unparseable element
+        let err = DMatrix::<i32>::parse_from_json(Some(json!([["x"], [1, 2]]))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("row 1 has length 2, expected 1")
+        );
+    }
+
+    #[test]
+    fn dmatrix_element_errors_include_position() {
+        let err = DMatrix::<i32>::parse_from_json(Some(json!([[1, 2], [3, "x"]]))).unwrap_err();
+        assert!(err.into_message().contains("(at [1, 1])"));
+    }
+
+    #[test]
+    fn dmatrix_registered_component() {
+        let mut registry = Registry::default();
+        <DMatrix<i32> as Type>::register(&mut registry);
+        let name = <DMatrix<i32> as Type>::name();
+        let schema = registry.schemas.get(&*name).unwrap();
+        assert_eq!(schema.ty, "array");
+        let row_schema = schema.items.as_ref().unwrap().unwrap_inline();
+        assert_eq!(row_schema.ty, "array");
+    }
+}
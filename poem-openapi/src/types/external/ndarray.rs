@@ -0,0 +1,1243 @@
+use std::borrow::Cow;
+
+use ndarray::{Array1, Array2, ArrayD, ArrayViewD, IxDyn};
+use poem::web::Field;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToJSON, Type,
+    },
+};
+
+impl<T: Type> Type for Array1<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("vector_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(crate::types::item_schema_ref::<T>())),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array1::is_empty(self)
+    }
+}
+
+impl<T: ParseFromJSON> ParseFromJSON for Array1<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::Array(values) => {
+                let mut data = Vec::with_capacity(values.len());
+                for value in values {
+                    data.push(T::parse_from_json(Some(value)).map_err(ParseError::propagate)?);
+                }
+                Ok(Array1::from_vec(data))
+            }
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<T: ToJSON> ToJSON for Array1<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::Array(
+            self.iter()
+                .map(|item| item.to_json().unwrap_or(Value::Null))
+                .collect(),
+        ))
+    }
+}
+
+impl<T: Type> Type for Array2<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        // reflect `T`'s nullability in the leaf schema so `Array2<Option<T>>`
+        // documents that cells may be `null`
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema {
+                items: Some(Box::new(crate::types::item_schema_ref::<T>())),
+                ..MetaSchema::new("array")
+            })))),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        // also publish the wrapper schema as a named component so tooling can
+        // `$ref` it by `name()`
+        registry.create_schema::<Self, _>(Self::name().into_owned(), |registry| {
+            T::register(registry);
+            MetaSchema {
+                items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema {
+                    items: Some(Box::new(crate::types::item_schema_ref::<T>())),
+                    ..MetaSchema::new("array")
+                })))),
+                ..MetaSchema::new("array")
+            }
+        });
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array2::is_empty(self)
+    }
+}
+
+/// Refuse to pre-allocate for shapes that cannot plausibly be backed by a
+/// real request body.
+const MAX_ELEMENTS: usize = 1 << 28;
+
+/// Returns the total number of elements of the shape, or `None` if it
+/// overflows or exceeds [`MAX_ELEMENTS`].
+fn checked_element_count(shape: &[usize]) -> Option<usize> {
+    shape
+        .iter()
+        .try_fold(1usize, |total, len| total.checked_mul(*len))
+        .filter(|total| *total <= MAX_ELEMENTS)
+}
+
+/// Parses the `{"shape": [r, c], "data": [...]}` form produced by numeric
+/// clients that serialize tensors as a flat buffer plus a shape.
+fn parse_flat_matrix<T: ParseFromJSON>(value: Value) -> ParseResult<Array2<T>> {
+    let Value::Object(mut fields) = value else {
+        unreachable!()
+    };
+    let shape = <[usize; 2]>::parse_from_json(fields.remove("shape"))
+        .map_err(|err| ParseError::<Array2<T>>::custom(format!("shape: {}", err.into_message())))?;
+    let Some(Value::Array(values)) = fields.remove("data") else {
+        return Err(ParseError::custom("expected a `data` array"));
+    };
+
+    let [num_rows, num_columns] = shape;
+    let total = checked_element_count(&shape).ok_or_else(|| {
+        ParseError::custom(format!(
+            "matrix of shape ({num_rows}, {num_columns}) is too large"
+        ))
+    })?;
+    if values.len() != total {
+        return Err(ParseError::custom(format!(
+            "shape ({num_rows}, {num_columns}) requires {total} elements, but `data` has {}",
+            values.len()
+        )));
+    }
+
+    let mut data = Vec::with_capacity(total);
+    for (idx, value) in values.into_iter().enumerate() {
+        data.push(
+            T::parse_from_json(Some(value))
+                .map_err(|err| ParseError::custom(format!("{} (at {idx})", err.message())))?,
+        );
+    }
+
+    Array2::from_shape_vec((num_rows, num_columns), data).map_err(ParseError::custom)
+}
+
+/// Validates that the rows form a rectangular matrix of acceptable size and
+/// returns its shape.
+fn validate_matrix_shape<U: Type>(rows: &[Value]) -> Result<(usize, usize), ParseError<U>> {
+    // cheap structural validation first, so ragged input fails before any
+    // element is parsed or the data vector is allocated
+    let mut columns = None;
+    for (idx, row) in rows.iter().enumerate() {
+        let Value::Array(row) = row else {
+            return Err(ParseError::custom(format!(
+                "expected an array at row {idx}"
+            )));
+        };
+        match columns {
+            Some(columns) => {
+                if row.len() != columns {
+                    return Err(ParseError::custom(format!(
+                        "ragged matrix: row {idx} has length {}, expected {columns}",
+                        row.len()
+                    )));
+                }
+            }
+            None => columns = Some(row.len()),
+        }
+    }
+
+    let num_rows = rows.len();
+    let num_columns = columns.unwrap_or_default();
+    checked_element_count(&[num_rows, num_columns]).ok_or_else(|| {
+        ParseError::custom(format!(
+            "matrix of shape ({num_rows}, {num_columns}) is too large"
+        ))
+    })?;
+    Ok((num_rows, num_columns))
+}
+
+/// Parses nested rows into a dense matrix, with structural validation and
+/// positioned element errors shared by [`Array2`] and [`CoercingArray2`].
+fn parse_nested_matrix<T, U: Type>(
+    rows: Vec<Value>,
+    mut parse_element: impl FnMut(Value) -> Result<T, String>,
+) -> Result<Array2<T>, ParseError<U>> {
+    let (num_rows, num_columns) = validate_matrix_shape(&rows)?;
+
+    let mut data = Vec::with_capacity(num_rows * num_columns);
+    for (idx, row) in rows.into_iter().enumerate() {
+        let Value::Array(row) = row else {
+            unreachable!()
+        };
+        for (col, value) in row.into_iter().enumerate() {
+            data.push(
+                parse_element(value)
+                    .map_err(|msg| ParseError::custom(format!("{msg} (at [{idx}, {col}])")))?,
+            );
+        }
+    }
+
+    Array2::from_shape_vec((num_rows, num_columns), data).map_err(ParseError::custom)
+}
+
+impl<T: ParseFromJSON> ParseFromJSON for Array2<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if value.is_object() {
+            return parse_flat_matrix(value);
+        }
+        let Value::Array(rows) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+        parse_nested_matrix(rows, |value| {
+            T::parse_from_json(Some(value)).map_err(|err| err.into_message())
+        })
+    }
+}
+
+/// Parses comma-separated rows with the same equal-length validation as the
+/// JSON path.
+fn parse_separated_rows<'a, T: ParseFromParameter>(
+    rows: impl Iterator<Item = &'a str>,
+) -> ParseResult<Array2<T>> {
+    let mut columns = None;
+    let mut data = Vec::new();
+    let mut num_rows = 0;
+
+    for (idx, row) in rows.enumerate() {
+        num_rows += 1;
+        let row_len = row.split(',').count();
+        match columns {
+            Some(columns) => {
+                if row_len != columns {
+                    return Err(ParseError::custom(format!(
+                        "ragged matrix: row {idx} has length {row_len}, expected {columns}"
+                    )));
+                }
+            }
+            None => columns = Some(row_len),
+        }
+        for item in row.split(',') {
+            data.push(T::parse_from_parameter(item).map_err(ParseError::propagate)?);
+        }
+    }
+
+    Array2::from_shape_vec((num_rows, columns.unwrap_or_default()), data)
+        .map_err(ParseError::custom)
+}
+
+impl<T: ParseFromParameter> ParseFromParameter for Array2<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        // `1,2,3;4,5,6` - semicolons separate rows, commas separate columns
+        if value.is_empty() {
+            return parse_separated_rows(std::iter::empty());
+        }
+        parse_separated_rows(value.split(';'))
+    }
+}
+
+impl<T: ParseFromParameter> ParseFromMultipartField for Array2<T> {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => {
+                // CSV-style grid: newlines separate rows, commas separate
+                // columns
+                let text = field.text().await?;
+                parse_separated_rows(text.lines())
+            }
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl<T: ToJSON> ToJSON for Array2<T> {
+    fn to_json(&self) -> Option<Value> {
+        let (nrows, ncols) = self.dim();
+        let mut rows = Vec::with_capacity(nrows);
+
+        if ncols == 0 {
+            rows.resize(nrows, Value::Array(Vec::new()));
+        } else if let Some(slice) = self.as_slice() {
+            // standard-layout arrays can be walked as one flat slice, which
+            // avoids creating a view per row
+            for row in slice.chunks_exact(ncols) {
+                let mut cols = Vec::with_capacity(ncols);
+                cols.extend(row.iter().map(|item| item.to_json().unwrap_or(Value::Null)));
+                rows.push(Value::Array(cols));
+            }
+        } else {
+            for row in self.rows() {
+                let mut cols = Vec::with_capacity(ncols);
+                cols.extend(row.iter().map(|item| item.to_json().unwrap_or(Value::Null)));
+                rows.push(Value::Array(cols));
+            }
+        }
+
+        Some(Value::Array(rows))
+    }
+
+    fn to_json_writer(&self, writer: &mut dyn std::io::Write) -> std::io::Result<()> {
+        // stream one cell at a time instead of materializing the whole
+        // `Value` tree first
+        serde_json::to_writer(writer, &StreamedArray2(self)).map_err(std::io::Error::other)
+    }
+}
+
+/// An [`Array2`] wrapper that also accepts a 1-D JSON array, promoting it to
+/// a single row.
+///
+/// `[1, 2, 3]` parses to shape `(1, 3)`, while `[[1, 2, 3]]` parses as usual.
+/// Plain `Array2` keeps the strict behavior and rejects 1-D input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LenientArray2<T>(pub Array2<T>);
+
+impl<T> LenientArray2<T> {
+    /// Consumes the wrapper and returns the inner matrix.
+    pub fn into_inner(self) -> Array2<T> {
+        self.0
+    }
+}
+
+impl<T: Type> Type for LenientArray2<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("lenient_matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        <Array2<T>>::schema_ref()
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array2::is_empty(&self.0)
+    }
+}
+
+impl<T: ParseFromJSON> ParseFromJSON for LenientArray2<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        // a non-empty array containing no nested arrays is a single row
+        let value = match value {
+            Value::Array(items)
+                if !items.is_empty() && items.iter().all(|item| !item.is_array()) =>
+            {
+                Value::Array(vec![Value::Array(items)])
+            }
+            value => value,
+        };
+        <Array2<T>>::parse_from_json(Some(value))
+            .map(Self)
+            .map_err(ParseError::propagate)
+    }
+}
+
+impl<T: ToJSON> ToJSON for LenientArray2<T> {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
+
+/// An [`Array2`] wrapper that coerces string cells through
+/// [`ParseFromParameter`].
+///
+/// JavaScript clients often encode matrix cells as strings
+/// (`[["1","2"],["3","4"]]`) to sidestep number precision issues. Each cell is
+/// parsed with `parse_from_json` first; a string cell that fails is retried
+/// via `T::parse_from_parameter`. Plain `Array2` keeps the strict behavior
+/// and rejects string cells for numeric `T`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoercingArray2<T>(pub Array2<T>);
+
+impl<T> CoercingArray2<T> {
+    /// Consumes the wrapper and returns the inner matrix.
+    pub fn into_inner(self) -> Array2<T> {
+        self.0
+    }
+}
+
+impl<T: Type> Type for CoercingArray2<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("coercing_matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        <Array2<T>>::schema_ref()
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array2::is_empty(&self.0)
+    }
+}
+
+impl<T: ParseFromJSON + ParseFromParameter> ParseFromJSON for CoercingArray2<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Value::Array(rows) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+        parse_nested_matrix(rows, |value| {
+            let retry = match &value {
+                Value::String(value) => Some(value.clone()),
+                _ => None,
+            };
+            T::parse_from_json(Some(value)).or_else(|err| match retry {
+                Some(value) => T::parse_from_parameter(&value).map_err(|err| err.into_message()),
+                None => Err(err.into_message()),
+            })
+        })
+        .map(Self)
+    }
+}
+
+impl<T: ToJSON> ToJSON for CoercingArray2<T> {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
+
+/// An [`Array2`] wrapper that reports every invalid cell at once.
+///
+/// Plain `Array2` stops at the first element that fails to parse, which is
+/// the right default for performance but unhelpful for data-entry tools.
+/// This wrapper keeps parsing and returns a single error listing each
+/// `[row, col]` and its reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExhaustiveArray2<T>(pub Array2<T>);
+
+impl<T> ExhaustiveArray2<T> {
+    /// Consumes the wrapper and returns the inner matrix.
+    pub fn into_inner(self) -> Array2<T> {
+        self.0
+    }
+}
+
+impl<T: Type> Type for ExhaustiveArray2<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("exhaustive_matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        <Array2<T>>::schema_ref()
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array2::is_empty(&self.0)
+    }
+}
+
+impl<T: ParseFromJSON> ParseFromJSON for ExhaustiveArray2<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Value::Array(rows) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+        let (num_rows, num_columns) = validate_matrix_shape(&rows)?;
+
+        let mut data = Vec::with_capacity(num_rows * num_columns);
+        let mut errors = Vec::new();
+        for (idx, row) in rows.into_iter().enumerate() {
+            let Value::Array(row) = row else {
+                unreachable!()
+            };
+            for (col, value) in row.into_iter().enumerate() {
+                match T::parse_from_json(Some(value)) {
+                    Ok(value) => data.push(value),
+                    Err(err) => {
+                        errors.push(format!("[{idx}, {col}]: {}", err.into_message()));
+                    }
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ParseError::custom(format!(
+                "invalid cells: {}",
+                errors.join("; ")
+            )));
+        }
+        Array2::from_shape_vec((num_rows, num_columns), data)
+            .map(Self)
+            .map_err(ParseError::custom)
+    }
+}
+
+impl<T: ToJSON> ToJSON for ExhaustiveArray2<T> {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
+
+/// An [`Array2`] wrapper serialized in coordinate (COO) form.
+///
+/// `ToJSON` emits `{"shape": [r, c], "entries": [{"row": i, "col": j,
+/// "value": v}, ...]}` listing only cells that differ from `T::default()`,
+/// which is far smaller than dense nested arrays for mostly-zero matrices.
+/// `ParseFromJSON` reconstructs the dense matrix, filling unspecified cells
+/// with `T::default()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseArray2<T>(pub Array2<T>);
+
+impl<T> SparseArray2<T> {
+    /// Consumes the wrapper and returns the inner matrix.
+    pub fn into_inner(self) -> Array2<T> {
+        self.0
+    }
+}
+
+impl<T: Type> Type for SparseArray2<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("sparse_matrix_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        let index_schema = || {
+            MetaSchemaRef::Inline(Box::new(MetaSchema {
+                minimum: Some(0.0),
+                ..MetaSchema::new_with_format("integer", "int64")
+            }))
+        };
+        let entry_schema = MetaSchema {
+            required: vec!["row", "col", "value"],
+            properties: vec![
+                ("row", index_schema()),
+                ("col", index_schema()),
+                ("value", T::schema_ref()),
+            ],
+            ..MetaSchema::new("object")
+        };
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            required: vec!["shape", "entries"],
+            properties: vec![
+                (
+                    "shape",
+                    MetaSchemaRef::Inline(Box::new(MetaSchema {
+                        items: Some(Box::new(index_schema())),
+                        max_items: Some(2),
+                        min_items: Some(2),
+                        ..MetaSchema::new("array")
+                    })),
+                ),
+                (
+                    "entries",
+                    MetaSchemaRef::Inline(Box::new(MetaSchema {
+                        items: Some(Box::new(MetaSchemaRef::Inline(Box::new(entry_schema)))),
+                        ..MetaSchema::new("array")
+                    })),
+                ),
+            ],
+            ..MetaSchema::new("object")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        Array2::is_empty(&self.0)
+    }
+}
+
+impl<T: ParseFromJSON + Default> ParseFromJSON for SparseArray2<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Value::Object(mut fields) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+
+        let shape = <[usize; 2]>::parse_from_json(fields.remove("shape"))
+            .map_err(|err| ParseError::<Self>::custom(format!("shape: {}", err.into_message())))?;
+        let [num_rows, num_columns] = shape;
+        checked_element_count(&shape).ok_or_else(|| {
+            ParseError::custom(format!(
+                "matrix of shape ({num_rows}, {num_columns}) is too large"
+            ))
+        })?;
+        let Some(Value::Array(entries)) = fields.remove("entries") else {
+            return Err(ParseError::custom("expected an `entries` array"));
+        };
+
+        let mut matrix = Array2::from_shape_simple_fn((num_rows, num_columns), T::default);
+        for (idx, entry) in entries.into_iter().enumerate() {
+            let Value::Object(mut entry) = entry else {
+                return Err(ParseError::custom(format!(
+                    "expected an object at entry {idx}"
+                )));
+            };
+            let row = usize::parse_from_json(entry.remove("row")).map_err(|err| {
+                ParseError::<Self>::custom(format!("entry {idx}: row: {}", err.into_message()))
+            })?;
+            let col = usize::parse_from_json(entry.remove("col")).map_err(|err| {
+                ParseError::<Self>::custom(format!("entry {idx}: col: {}", err.into_message()))
+            })?;
+            if row >= num_rows || col >= num_columns {
+                return Err(ParseError::custom(format!(
+                    "entry {idx}: position [{row}, {col}] is outside shape ({num_rows}, {num_columns})"
+                )));
+            }
+            matrix[(row, col)] = T::parse_from_json(entry.remove("value")).map_err(|err| {
+                ParseError::<Self>::custom(format!("entry {idx}: value: {}", err.into_message()))
+            })?;
+        }
+
+        Ok(Self(matrix))
+    }
+}
+
+impl<T: ToJSON + Default + PartialEq> ToJSON for SparseArray2<T> {
+    fn to_json(&self) -> Option<Value> {
+        let (num_rows, num_columns) = self.0.dim();
+        let default = T::default();
+        let entries = self
+            .0
+            .indexed_iter()
+            .filter(|(_, item)| **item != default)
+            .map(|((row, col), item)| {
+                let mut entry = serde_json::Map::new();
+                entry.insert("row".to_string(), Value::from(row));
+                entry.insert("col".to_string(), Value::from(col));
+                entry.insert("value".to_string(), item.to_json().unwrap_or(Value::Null));
+                Value::Object(entry)
+            })
+            .collect();
+        Some(serde_json::json!({
+            "shape": [num_rows, num_columns],
+            "entries": Value::Array(entries),
+        }))
+    }
+}
+
+/// A borrowed [`Array2`] that serializes row-by-row via [`serde::Serialize`].
+///
+/// [`ToJSON::to_json`] materializes the whole `serde_json::Value` tree before
+/// anything is written, which doubles peak memory for very large matrices.
+/// This wrapper feeds the serializer one cell at a time instead, so the body
+/// can be produced incrementally with `serde_json::to_writer` (for example
+/// into a response body). The output bytes are identical to serializing the
+/// [`ToJSON`] output; use `to_json` when the matrix must be embedded in a
+/// larger `Value`.
+#[derive(Debug, Clone, Copy)]
+pub struct StreamedArray2<'a, T>(pub &'a Array2<T>);
+
+impl<T: ToJSON> serde::Serialize for StreamedArray2<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeSeq;
+
+        struct Row<'a, T>(ndarray::ArrayView1<'a, T>);
+
+        impl<T: ToJSON> serde::Serialize for Row<'_, T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for item in &self.0 {
+                    seq.serialize_element(&item.to_json().unwrap_or(Value::Null))?;
+                }
+                seq.end()
+            }
+        }
+
+        let mut seq = serializer.serialize_seq(Some(self.0.nrows()))?;
+        for row in self.0.rows() {
+            seq.serialize_element(&Row(row))?;
+        }
+        seq.end()
+    }
+}
+
+impl<T: Type> Type for ArrayD<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("tensor_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        // the rank is not known statically, so the schema can only promise
+        // nested arrays of unspecified depth
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema::ANY)))),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        ArrayD::is_empty(self)
+    }
+}
+
+/// Infers the shape of a nested JSON array by descending along the first
+/// elements.
+fn infer_shape(mut value: &Value) -> Vec<usize> {
+    let mut shape = Vec::new();
+    while let Value::Array(values) = value {
+        shape.push(values.len());
+        match values.first() {
+            Some(first) => value = first,
+            None => break,
+        }
+    }
+    shape
+}
+
+fn collect_elements<T: ParseFromJSON, U: Type>(
+    value: Value,
+    shape: &[usize],
+    path: &mut String,
+    data: &mut Vec<T>,
+) -> Result<(), ParseError<U>> {
+    match shape.split_first() {
+        Some((len, rest)) => {
+            let Value::Array(values) = value else {
+                return Err(ParseError::custom(format!("expected an array at `{path}`")));
+            };
+            if values.len() != *len {
+                return Err(ParseError::custom(format!(
+                    "ragged array: sub-array at `{path}` has length {}, expected {len}",
+                    values.len()
+                )));
+            }
+            let prefix_len = path.len();
+            for (idx, value) in values.into_iter().enumerate() {
+                path.push('/');
+                path.push_str(&idx.to_string());
+                collect_elements(value, rest, path, data)?;
+                path.truncate(prefix_len);
+            }
+            Ok(())
+        }
+        None => {
+            data.push(T::parse_from_json(Some(value)).map_err(ParseError::propagate)?);
+            Ok(())
+        }
+    }
+}
+
+impl<T: ParseFromJSON> ParseFromJSON for ArrayD<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if !value.is_array() {
+            return Err(ParseError::expected_type(value));
+        }
+
+        let shape = infer_shape(&value);
+        let total = checked_element_count(&shape)
+            .ok_or_else(|| ParseError::custom(format!("array of shape {shape:?} is too large")))?;
+        let mut data = Vec::with_capacity(total);
+        collect_elements(value, &shape, &mut String::new(), &mut data)?;
+        ArrayD::from_shape_vec(IxDyn(&shape), data).map_err(ParseError::custom)
+    }
+}
+
+fn dyn_to_json<T: ToJSON>(array: ArrayViewD<'_, T>) -> Value {
+    if array.ndim() == 0 {
+        array
+            .iter()
+            .next()
+            .and_then(|item| item.to_json())
+            .unwrap_or(Value::Null)
+    } else {
+        Value::Array(array.outer_iter().map(dyn_to_json).collect())
+    }
+}
+
+impl<T: ToJSON> ToJSON for ArrayD<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(dyn_to_json(self.view()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::array;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn array1_round_trip() {
+        let array = Array1::<f64>::parse_from_json(Some(json!([1.0, 2.0, 3.0]))).unwrap();
+        assert_eq!(array, array![1.0, 2.0, 3.0]);
+        assert_eq!(array.to_json(), Some(json!([1.0, 2.0, 3.0])));
+    }
+
+    #[test]
+    fn array2_round_trip() {
+        let array = Array2::<i32>::parse_from_json(Some(json!([[1, 2, 3], [4, 5, 6]]))).unwrap();
+        assert_eq!(array, array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(array.to_json(), Some(json!([[1, 2, 3], [4, 5, 6]])));
+    }
+
+    #[test]
+    fn array2_none_elements_serialize_as_null() {
+        // elements whose `to_json` yields nothing must still occupy their
+        // slot, otherwise the serialized array no longer matches the shape
+        let array = ndarray::arr2(&[[Some(1), None], [Some(3), Some(4)]]);
+        assert_eq!(array.to_json(), Some(json!([[1, null], [3, 4]])));
+    }
+
+    #[test]
+    fn array2_rejects_ragged_rows() {
+        let err = Array2::<i32>::parse_from_json(Some(json!([[1, 2], [3]]))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("row 1 has length 1, expected 2")
+        );
+    }
+
+    #[test]
+    fn array2_ragged_checked_before_elements() {
+        // the structural pass runs first, so raggedness is reported even when
+        // an earlier row contains an unparseable element
+        let err = Array2::<i32>::parse_from_json(Some(json!([["x"], [1, 2]]))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("row 1 has length 2, expected 1")
+        );
+    }
+
+    #[test]
+    fn huge_declared_shape_rejected() {
+        assert_eq!(checked_element_count(&[usize::MAX, 2]), None);
+        assert_eq!(checked_element_count(&[1 << 20, 1 << 20]), None);
+        assert_eq!(checked_element_count(&[3, 4]), Some(12));
+
+        // the shape of an `ArrayD` is inferred from the first spine, so a
+        // small hostile document can declare a huge shape; it must be
+        // rejected before the data vector is allocated
+        let mut rows = vec![json!(vec![0; 100_000])];
+        rows.resize(100_000, json!(0));
+        let err = ArrayD::<i32>::parse_from_json(Some(Value::Array(rows))).unwrap_err();
+        assert!(err.into_message().contains("too large"));
+    }
+
+    #[test]
+    fn array2_from_flat_shape_and_data() {
+        let nested = Array2::<i32>::parse_from_json(Some(json!([[1, 2, 3], [4, 5, 6]]))).unwrap();
+        let flat = Array2::<i32>::parse_from_json(Some(json!({
+            "shape": [2, 3],
+            "data": [1, 2, 3, 4, 5, 6],
+        })))
+        .unwrap();
+        assert_eq!(nested, flat);
+
+        let err = Array2::<i32>::parse_from_json(Some(json!({
+            "shape": [2, 3],
+            "data": [1, 2, 3, 4, 5],
+        })))
+        .unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("requires 6 elements, but `data` has 5")
+        );
+
+        let err = Array2::<i32>::parse_from_json(Some(json!({ "data": [1] }))).unwrap_err();
+        assert!(err.into_message().contains("shape:"));
+    }
+
+    #[test]
+    fn array2_from_parameter() {
+        let array = Array2::<f64>::parse_from_parameter("1,2,3;4,5,6").unwrap();
+        assert_eq!(array, array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let err = Array2::<f64>::parse_from_parameter("1,2;3").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("row 1 has length 1, expected 2")
+        );
+    }
+
+    #[test]
+    fn array2_element_errors_include_position() {
+        let err = Array2::<i32>::parse_from_json(Some(json!([[1, 2], [3, "x"]]))).unwrap_err();
+        assert!(err.into_message().contains("(at [1, 1])"));
+    }
+
+    #[test]
+    fn arrayd_round_trip_3d() {
+        let value = json!([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+        let array = ArrayD::<i64>::parse_from_json(Some(value.clone())).unwrap();
+        assert_eq!(array.shape(), &[2, 2, 2]);
+        assert_eq!(array[IxDyn(&[1, 0, 1])], 6);
+        assert_eq!(array.to_json(), Some(value));
+    }
+
+    #[test]
+    fn arrayd_rejects_ragged_sub_arrays() {
+        let err = ArrayD::<i64>::parse_from_json(Some(json!([[[1, 2], [3, 4]], [[5, 6], [7]]])))
+            .unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("sub-array at `/1/1` has length 1, expected 2")
+        );
+    }
+
+    #[test]
+    fn array2_to_json_matches_reference() {
+        fn reference<T: ToJSON>(array: &Array2<T>) -> Value {
+            Value::Array(
+                array
+                    .rows()
+                    .into_iter()
+                    .map(|row| {
+                        Value::Array(
+                            row.iter()
+                                .map(|item| item.to_json().unwrap_or(Value::Null))
+                                .collect(),
+                        )
+                    })
+                    .collect(),
+            )
+        }
+
+        let array = Array2::from_shape_fn((100, 100), |(i, j)| (i * 100 + j) as f64 / 3.0);
+        assert_eq!(array.to_json(), Some(reference(&array)));
+
+        // a non-standard-layout array exercises the per-row fallback
+        let array = Array2::from_shape_fn((10, 20), |(i, j)| (i * 20 + j) as i64).reversed_axes();
+        assert!(array.as_slice().is_none());
+        assert_eq!(array.to_json(), Some(reference(&array)));
+
+        // zero-column matrices keep their row count
+        let array = Array2::<i32>::from_shape_fn((3, 0), |_| 0);
+        assert_eq!(array.to_json(), Some(json!([[], [], []])));
+    }
+
+    #[test]
+    fn lenient_array2_promotes_single_row() {
+        // a 1-D array becomes a single row
+        let matrix = LenientArray2::<i32>::parse_from_json(Some(json!([1, 2, 3]))).unwrap();
+        assert_eq!(matrix.0, array![[1, 2, 3]]);
+
+        // nested input still parses as usual
+        let matrix = LenientArray2::<i32>::parse_from_json(Some(json!([[1, 2], [3, 4]]))).unwrap();
+        assert_eq!(matrix.0, array![[1, 2], [3, 4]]);
+
+        // the strict type keeps rejecting 1-D input
+        assert!(Array2::<i32>::parse_from_json(Some(json!([1, 2, 3]))).is_err());
+        // a mix of scalars and arrays is still invalid
+        assert!(LenientArray2::<i32>::parse_from_json(Some(json!([1, [2, 3]]))).is_err());
+    }
+
+    #[test]
+    fn array2_element_format_reflects_precision() {
+        fn leaf_format<T: Type>() -> Option<&'static str> {
+            let mut registry = Registry::default();
+            <Array2<T> as Type>::register(&mut registry);
+            let schema = registry
+                .schemas
+                .remove(&*<Array2<T> as Type>::name())
+                .unwrap();
+            let row_schema = schema.items.as_ref().unwrap().unwrap_inline();
+            row_schema.items.as_ref().unwrap().unwrap_inline().format
+        }
+
+        // single- and double-precision elements must be distinguishable
+        assert_eq!(leaf_format::<f32>(), Some("float"));
+        assert_eq!(leaf_format::<f64>(), Some("double"));
+    }
+
+    #[test]
+    fn optional_array2() {
+        // `Option` relaxes `IS_REQUIRED` for matrices just like for scalars
+        assert!(<Array2<i32> as Type>::IS_REQUIRED);
+        assert!(!<Option<Array2<i32>> as Type>::IS_REQUIRED);
+
+        assert_eq!(Option::<Array2<i32>>::parse_from_json(None).unwrap(), None);
+        assert_eq!(
+            Option::<Array2<i32>>::parse_from_json(Some(Value::Null)).unwrap(),
+            None
+        );
+        assert_eq!(
+            Option::<Array2<i32>>::parse_from_json(Some(json!([[1, 2], [3, 4]]))).unwrap(),
+            Some(array![[1, 2], [3, 4]])
+        );
+        assert!(Option::<Array2<i32>>::parse_from_json(Some(json!([[1, 2], [3]]))).is_err());
+    }
+
+    #[test]
+    fn exhaustive_array2_reports_all_invalid_cells() {
+        let err = ExhaustiveArray2::<i32>::parse_from_json(Some(json!([[1, "x"], ["y", 4]])))
+            .unwrap_err();
+        let message = err.into_message();
+        assert!(message.contains("[0, 1]"));
+        assert!(message.contains("[1, 0]"));
+
+        // the strict type still stops at the first bad cell
+        let err = Array2::<i32>::parse_from_json(Some(json!([[1, "x"], ["y", 4]]))).unwrap_err();
+        let message = err.into_message();
+        assert!(message.contains("(at [0, 1])"));
+        assert!(!message.contains("[1, 0]"));
+
+        // valid input still parses
+        let matrix =
+            ExhaustiveArray2::<i32>::parse_from_json(Some(json!([[1, 2], [3, 4]]))).unwrap();
+        assert_eq!(matrix.0, array![[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn coercing_array2_accepts_numeric_strings() {
+        let matrix =
+            CoercingArray2::<i64>::parse_from_json(Some(json!([["1", "2"], ["3", "4"]]))).unwrap();
+        assert_eq!(matrix.0, array![[1, 2], [3, 4]]);
+
+        // mixed numbers and strings are fine, the strict type still rejects
+        // string cells
+        let matrix = CoercingArray2::<f64>::parse_from_json(Some(json!([[1.5, "2.5"]]))).unwrap();
+        assert_eq!(matrix.0, array![[1.5, 2.5]]);
+        assert!(Array2::<i64>::parse_from_json(Some(json!([["1", "2"]]))).is_err());
+
+        // unparseable strings still fail with a positioned error
+        let err = CoercingArray2::<i64>::parse_from_json(Some(json!([["1", "x"]]))).unwrap_err();
+        assert!(err.into_message().contains("(at [0, 1])"));
+    }
+
+    #[test]
+    fn sparse_array2_round_trip() {
+        let mut dense = Array2::<i32>::zeros((3, 3));
+        dense[(0, 1)] = 7;
+        dense[(2, 2)] = -3;
+        let sparse = SparseArray2(dense.clone());
+
+        let value = sparse.to_json().unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "shape": [3, 3],
+                "entries": [
+                    { "row": 0, "col": 1, "value": 7 },
+                    { "row": 2, "col": 2, "value": -3 },
+                ],
+            })
+        );
+
+        let parsed = SparseArray2::<i32>::parse_from_json(Some(value)).unwrap();
+        assert_eq!(parsed.0, dense);
+    }
+
+    #[test]
+    fn sparse_array2_rejects_out_of_bounds_entries() {
+        let err = SparseArray2::<i32>::parse_from_json(Some(json!({
+            "shape": [2, 2],
+            "entries": [{ "row": 2, "col": 0, "value": 1 }],
+        })))
+        .unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("position [2, 0] is outside shape (2, 2)")
+        );
+
+        let err = SparseArray2::<i32>::parse_from_json(Some(json!({ "entries": [] }))).unwrap_err();
+        assert!(err.into_message().contains("shape:"));
+    }
+
+    #[test]
+    fn array2_optional_elements_are_nullable() {
+        fn leaf_schema<T: Type>() -> MetaSchema {
+            let schema_ref = T::schema_ref();
+            let matrix = schema_ref.unwrap_inline();
+            let row = matrix.items.as_ref().unwrap().unwrap_inline();
+            row.items.as_ref().unwrap().unwrap_inline().clone()
+        }
+
+        let required = leaf_schema::<Array2<i32>>();
+        assert_eq!(required.ty, "integer");
+        assert!(!required.nullable);
+
+        let nullable = leaf_schema::<Array2<Option<i32>>>();
+        assert_eq!(nullable.ty, "integer");
+        assert!(nullable.nullable);
+
+        // a null cell parses for optional elements, and only for them
+        let matrix = Array2::<Option<i32>>::parse_from_json(Some(json!([[1, null]]))).unwrap();
+        assert_eq!(matrix, array![[Some(1), None]]);
+        assert!(Array2::<i32>::parse_from_json(Some(json!([[1, null]]))).is_err());
+    }
+
+    #[test]
+    fn streamed_array2_matches_to_json_bytes() {
+        let matrix = Array2::from_shape_fn((50, 50), |(row, col)| (row * 50 + col) as i64);
+        let streamed = serde_json::to_vec(&StreamedArray2(&matrix)).unwrap();
+        let materialized = serde_json::to_vec(&matrix.to_json().unwrap()).unwrap();
+        assert_eq!(streamed, materialized);
+    }
+
+    #[test]
+    fn to_json_writer_matches_to_json() {
+        let matrix = Array2::from_shape_fn((40, 30), |(row, col)| (row * 30 + col) as f64 / 7.0);
+        let mut streamed = Vec::new();
+        matrix.to_json_writer(&mut streamed).unwrap();
+        let materialized = serde_json::to_vec(&matrix.to_json().unwrap()).unwrap();
+        assert_eq!(streamed, materialized);
+
+        // the default implementation on a plain type also round-trips
+        let mut buf = Vec::new();
+        42i32.to_json_writer(&mut buf).unwrap();
+        assert_eq!(buf, b"42");
+    }
+
+    #[test]
+    fn array2_registered_component() {
+        let mut registry = Registry::default();
+        <Array2<i32> as Type>::register(&mut registry);
+        let name = <Array2<i32> as Type>::name();
+        assert_eq!(&*name, &format!("matrix_{}", i32::name()));
+        let schema = registry.schemas.get(&*name).unwrap();
+        assert_eq!(schema.ty, "array");
+        let row_schema = schema.items.as_ref().unwrap().unwrap_inline();
+        assert_eq!(row_schema.ty, "array");
+    }
+}
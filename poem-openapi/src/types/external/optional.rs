@@ -64,20 +64,29 @@ impl<T: ParseFromJSON> ParseFromJSON for Option<T> {
 }
 
 impl<T: ParseFromParameter> ParseFromParameter for Option<T> {
-    fn parse_from_parameter(_value: &str) -> ParseResult<Self> {
-        unreachable!()
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        // an empty element in a delimited list (e.g. `?a=x,,y`) means "no
+        // value" at that position
+        if value.is_empty() {
+            return Ok(None);
+        }
+        T::parse_from_parameter(value)
+            .map_err(ParseError::propagate)
+            .map(Some)
     }
 
     fn parse_from_parameters<I: IntoIterator<Item = A>, A: AsRef<str>>(
         iter: I,
     ) -> ParseResult<Self> {
-        let mut iter = iter.into_iter().peekable();
+        let values = iter.into_iter().collect::<Vec<_>>();
 
-        if iter.peek().is_none() {
+        // an absent parameter and a present-but-empty parameter (e.g.
+        // `?active=`) both mean "no value"
+        if values.is_empty() || (values.len() == 1 && values[0].as_ref().is_empty()) {
             return Ok(None);
         }
 
-        T::parse_from_parameters(iter)
+        T::parse_from_parameters(values)
             .map_err(ParseError::propagate)
             .map(Some)
     }
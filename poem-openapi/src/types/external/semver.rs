@@ -0,0 +1,158 @@
+use std::borrow::Cow;
+
+use poem::{http::HeaderValue, web::Field};
+use semver::{Version, VersionReq};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+macro_rules! impl_semver_types {
+    ($ty:ty, $format:literal) => {
+        impl Type for $ty {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> Cow<'static, str> {
+                concat!("string_", $format).into()
+            }
+
+            fn schema_ref() -> MetaSchemaRef {
+                MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", $format)))
+            }
+
+            fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                Some(self)
+            }
+
+            fn raw_element_iter<'a>(
+                &'a self,
+            ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+                Box::new(self.as_raw_value().into_iter())
+            }
+        }
+
+        impl ParseFromJSON for $ty {
+            fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+                let value = value.unwrap_or_default();
+                if let Value::String(value) = value {
+                    Ok(value.parse()?)
+                } else {
+                    Err(ParseError::expected_type(value))
+                }
+            }
+        }
+
+        impl ParseFromParameter for $ty {
+            fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+                value.parse().map_err(ParseError::custom)
+            }
+        }
+
+        impl ParseFromMultipartField for $ty {
+            async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+                match field {
+                    Some(field) => Ok(field.text().await?.parse()?),
+                    None => Err(ParseError::expected_input()),
+                }
+            }
+        }
+
+        impl ToJSON for $ty {
+            fn to_json(&self) -> Option<Value> {
+                Some(Value::String(self.to_string()))
+            }
+        }
+
+        impl ToHeader for $ty {
+            fn to_header(&self) -> Option<HeaderValue> {
+                HeaderValue::from_str(&self.to_string()).ok()
+            }
+        }
+    };
+}
+
+impl_semver_types!(Version, "semver");
+impl_semver_types!(VersionReq, "semver-req");
+
+/// An extension trait for sorting a list of versions by semver precedence.
+///
+/// Parameter parsing keeps versions in the order the client sent them; this
+/// trait produces a copy ordered by the precedence rules of the semver
+/// specification, which place pre-releases before the corresponding release.
+pub trait SortByPrecedence {
+    /// Returns the versions sorted by semver precedence, lowest first.
+    fn sorted_by_precedence(&self) -> Vec<Version>;
+}
+
+impl SortByPrecedence for [Version] {
+    fn sorted_by_precedence(&self) -> Vec<Version> {
+        let mut versions = self.to_vec();
+        versions.sort();
+        versions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version_req_from_parameter() {
+        let req = VersionReq::parse_from_parameter("^1.2.3").unwrap();
+        assert!(req.matches(&Version::new(1, 5, 0)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+
+        assert!(VersionReq::parse_from_parameter("^x.y").is_err());
+    }
+
+    #[test]
+    fn parse_version_from_parameter() {
+        let version = Version::parse_from_parameter("1.2.3").unwrap();
+        assert_eq!(version, Version::new(1, 2, 3));
+
+        assert!(Version::parse_from_parameter("not a version").is_err());
+    }
+
+    #[test]
+    fn parse_version_list_from_parameters() {
+        let versions =
+            Vec::<Version>::parse_from_parameters(["1.2.3", "0.9.0", "2.0.0-rc.1"]).unwrap();
+        assert_eq!(
+            versions,
+            vec![
+                Version::new(1, 2, 3),
+                Version::new(0, 9, 0),
+                "2.0.0-rc.1".parse().unwrap(),
+            ]
+        );
+
+        assert!(Vec::<Version>::parse_from_parameters(["1.2.3", "oops"]).is_err());
+    }
+
+    #[test]
+    fn sort_versions_by_precedence() {
+        let versions: Vec<Version> = ["2.0.0", "2.0.0-rc.1", "1.10.0", "2.0.0-alpha", "1.2.3"]
+            .iter()
+            .map(|v| v.parse().unwrap())
+            .collect();
+        let sorted = versions.sorted_by_precedence();
+        assert_eq!(
+            sorted
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+            ["1.2.3", "1.10.0", "2.0.0-alpha", "2.0.0-rc.1", "2.0.0"]
+        );
+        // the original order is untouched
+        assert_eq!(versions[0].to_string(), "2.0.0");
+    }
+}
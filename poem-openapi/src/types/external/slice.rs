@@ -20,7 +20,7 @@ impl<T: Type> Type for &[T] {
 
     fn schema_ref() -> MetaSchemaRef {
         MetaSchemaRef::Inline(Box::new(MetaSchema {
-            items: Some(Box::new(T::schema_ref())),
+            items: Some(Box::new(crate::types::item_schema_ref::<T>())),
             ..MetaSchema::new("array")
         }))
     }
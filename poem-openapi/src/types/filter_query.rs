@@ -0,0 +1,250 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// The comparison operator of a [`FilterClause`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FilterOp {
+    /// `field:value`
+    Eq,
+    /// `field:!=value`
+    Ne,
+    /// `field:>value`
+    Gt,
+    /// `field:>=value`
+    Ge,
+    /// `field:<value`
+    Lt,
+    /// `field:<=value`
+    Le,
+}
+
+impl Display for FilterOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterOp::Eq => write!(f, ""),
+            FilterOp::Ne => write!(f, "!="),
+            FilterOp::Gt => write!(f, ">"),
+            FilterOp::Ge => write!(f, ">="),
+            FilterOp::Lt => write!(f, "<"),
+            FilterOp::Le => write!(f, "<="),
+        }
+    }
+}
+
+/// A single `field:value` clause of a [`FilterQuery`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FilterClause {
+    /// The field name.
+    pub field: String,
+    /// The comparison operator.
+    pub op: FilterOp,
+    /// The value to compare against.
+    pub value: String,
+}
+
+/// A search filter parsed from a simple key-value DSL, e.g.
+/// `?q=status:active age:>30`.
+///
+/// The grammar is a whitespace-separated list of clauses of the form
+/// `field:value`, where `value` may be prefixed with one of the operators
+/// `!=`, `>`, `>=`, `<` or `<=` (equality if omitted). Malformed clauses are
+/// rejected with an error that reports the offset of the offending token.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{FilterOp, FilterQuery, ParseFromParameter};
+///
+/// let query = FilterQuery::parse_from_parameter("status:active age:>30").unwrap();
+/// assert_eq!(query[0].field, "status");
+/// assert_eq!(query[0].op, FilterOp::Eq);
+/// assert_eq!(query[0].value, "active");
+/// assert_eq!(query[1].op, FilterOp::Gt);
+/// assert_eq!(query[1].value, "30");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FilterQuery(pub Vec<FilterClause>);
+
+impl Deref for FilterQuery {
+    type Target = [FilterClause];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn parse_clause<T: Type>(token: &str, offset: usize) -> Result<FilterClause, ParseError<T>> {
+    let malformed = || {
+        ParseError::custom(format!(
+            "malformed filter clause \"{token}\" at offset {offset}"
+        ))
+    };
+
+    let (field, value) = token.split_once(':').ok_or_else(malformed)?;
+    if field.is_empty()
+        || !field
+            .bytes()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == b'_')
+    {
+        return Err(malformed());
+    }
+
+    let (op, value) = if let Some(value) = value.strip_prefix("!=") {
+        (FilterOp::Ne, value)
+    } else if let Some(value) = value.strip_prefix(">=") {
+        (FilterOp::Ge, value)
+    } else if let Some(value) = value.strip_prefix("<=") {
+        (FilterOp::Le, value)
+    } else if let Some(value) = value.strip_prefix('>') {
+        (FilterOp::Gt, value)
+    } else if let Some(value) = value.strip_prefix('<') {
+        (FilterOp::Lt, value)
+    } else {
+        (FilterOp::Eq, value)
+    };
+
+    if value.is_empty() {
+        return Err(malformed());
+    }
+
+    Ok(FilterClause {
+        field: field.to_string(),
+        op,
+        value: value.to_string(),
+    })
+}
+
+fn parse_query<T: Type>(value: &str) -> Result<Vec<FilterClause>, ParseError<T>> {
+    let mut clauses = Vec::new();
+    let mut offset = 0;
+    for token in value.split(' ') {
+        if !token.is_empty() {
+            clauses.push(parse_clause(token, offset)?);
+        }
+        offset += token.len() + 1;
+    }
+    Ok(clauses)
+}
+
+impl Type for FilterQuery {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_filter".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some(
+                "A whitespace-separated list of `field:value` clauses; the value may be \
+                 prefixed with one of the operators `!=`, `>`, `>=`, `<` or `<=`.",
+            ),
+            ..MetaSchema::new_with_format("string", "filter")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ParseFromJSON for FilterQuery {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self(parse_query(&value)?))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for FilterQuery {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(Self(parse_query(value)?))
+    }
+}
+
+impl ToJSON for FilterQuery {
+    fn to_json(&self) -> Option<Value> {
+        let clauses = self
+            .0
+            .iter()
+            .map(|clause| format!("{}:{}{}", clause.field, clause.op, clause.value))
+            .collect::<Vec<_>>();
+        Some(Value::String(clauses.join(" ")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_clauses() {
+        let query = FilterQuery::parse_from_parameter("status:active age:>=30 name:!=bob").unwrap();
+        assert_eq!(
+            query.0,
+            vec![
+                FilterClause {
+                    field: "status".to_string(),
+                    op: FilterOp::Eq,
+                    value: "active".to_string()
+                },
+                FilterClause {
+                    field: "age".to_string(),
+                    op: FilterOp::Ge,
+                    value: "30".to_string()
+                },
+                FilterClause {
+                    field: "name".to_string(),
+                    op: FilterOp::Ne,
+                    value: "bob".to_string()
+                },
+            ]
+        );
+        assert_eq!(
+            query.to_json(),
+            Some(json!("status:active age:>=30 name:!=bob"))
+        );
+    }
+
+    #[test]
+    fn malformed_clause() {
+        let err = FilterQuery::parse_from_parameter("status:active age>30").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains(r#"malformed filter clause "age>30" at offset 14"#)
+        );
+
+        assert!(FilterQuery::parse_from_parameter("status:").is_err());
+        assert!(FilterQuery::parse_from_parameter(":active").is_err());
+    }
+}
@@ -0,0 +1,137 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use jiff::{Timestamp, fmt::temporal::DateTimePrinter};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A timestamp that serializes with a fixed number of fractional-second
+/// digits.
+///
+/// `ToJSON for Timestamp` emits whatever precision the value happens to
+/// carry, so `2024-03-10T10:00:00Z` and `2024-03-10T10:00:00.000Z` round-trip
+/// inconsistently across clients. This wrapper always formats with exactly
+/// `PRECISION` subsecond digits (`0` for whole seconds, `3` for milliseconds,
+/// `6` for microseconds, `9` for nanoseconds), truncating any extra
+/// precision. Parsing accepts any precision.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct FixedPrecision<const PRECISION: u8>(pub Timestamp);
+
+impl<const PRECISION: u8> Deref for FixedPrecision<PRECISION> {
+    type Target = Timestamp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const PRECISION: u8> DerefMut for FixedPrecision<PRECISION> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const PRECISION: u8> Display for FixedPrecision<PRECISION> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // subsecond precision beyond nanoseconds does not exist
+        let printer = DateTimePrinter::new().precision(Some(PRECISION.min(9)));
+        f.write_str(&printer.timestamp_to_string(&self.0))
+    }
+}
+
+impl<const PRECISION: u8> Type for FixedPrecision<PRECISION> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_date-time".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "date-time")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<const PRECISION: u8> ParseFromJSON for FixedPrecision<PRECISION> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self(value.parse()?))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<const PRECISION: u8> ParseFromParameter for FixedPrecision<PRECISION> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        value.parse().map(Self).map_err(ParseError::custom)
+    }
+}
+
+impl<const PRECISION: u8> ToJSON for FixedPrecision<PRECISION> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_precision_output() {
+        let timestamp: Timestamp = "2024-03-10T10:00:00.123456789Z".parse().unwrap();
+        assert_eq!(
+            FixedPrecision::<3>(timestamp).to_json(),
+            Some(Value::String("2024-03-10T10:00:00.123Z".to_string()))
+        );
+        assert_eq!(
+            FixedPrecision::<0>(timestamp).to_string(),
+            "2024-03-10T10:00:00Z"
+        );
+        assert_eq!(
+            FixedPrecision::<9>(timestamp).to_string(),
+            "2024-03-10T10:00:00.123456789Z"
+        );
+    }
+
+    #[test]
+    fn whole_seconds_keep_fixed_digits() {
+        let timestamp: Timestamp = "2024-03-10T10:00:00Z".parse().unwrap();
+        assert_eq!(
+            FixedPrecision::<3>(timestamp).to_string(),
+            "2024-03-10T10:00:00.000Z"
+        );
+    }
+
+    #[test]
+    fn parse_any_precision() {
+        let timestamp =
+            FixedPrecision::<3>::parse_from_parameter("2024-03-10T10:00:00.123456789Z").unwrap();
+        assert_eq!(
+            timestamp.0,
+            "2024-03-10T10:00:00.123456789Z".parse().unwrap()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A presence-only boolean parameter.
+///
+/// Unlike `bool`, a `Flag` does not require a value: `?verbose` parses as
+/// `true` and an absent parameter parses as `false`. An explicit value is
+/// still accepted, so `?verbose=false` parses as `false`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Flag(pub bool);
+
+impl Type for Flag {
+    const IS_REQUIRED: bool = false;
+
+    const ALLOW_EMPTY_VALUE: bool = true;
+
+    type RawValueType = bool;
+
+    type RawElementValueType = bool;
+
+    fn name() -> Cow<'static, str> {
+        "flag".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new("boolean")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromParameter for Flag {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        if value.is_empty() {
+            return Ok(Flag(true));
+        }
+        value.parse().map(Flag).map_err(ParseError::custom)
+    }
+
+    fn parse_from_parameters<I: IntoIterator<Item = A>, A: AsRef<str>>(
+        iter: I,
+    ) -> ParseResult<Self> {
+        match iter.into_iter().next() {
+            Some(value) => Self::parse_from_parameter(value.as_ref()),
+            None => Ok(Flag(false)),
+        }
+    }
+}
+
+impl ParseFromJSON for Flag {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        match value {
+            Some(Value::Bool(value)) => Ok(Flag(value)),
+            None | Some(Value::Null) => Ok(Flag(false)),
+            Some(value) => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl ToJSON for Flag {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::Bool(self.0))
+    }
+}
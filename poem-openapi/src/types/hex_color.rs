@@ -0,0 +1,144 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A strict `#RRGGBB` hex color.
+///
+/// Unlike [`Color`](crate::types::Color) there is no alpha channel: exactly
+/// six hex digits are required, so `#abc` shorthand and `#RRGGBBAA` are
+/// rejected. Input is case-insensitive and normalized to lowercase.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct HexColor {
+    /// The red component.
+    pub r: u8,
+    /// The green component.
+    pub g: u8,
+    /// The blue component.
+    pub b: u8,
+}
+
+impl Display for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+fn parse_hex_color<T: Type>(value: &str) -> Result<HexColor, ParseError<T>> {
+    let digits = value
+        .strip_prefix('#')
+        .ok_or_else(|| ParseError::custom(format!("invalid hex color: {value}")))?;
+    match digits.len() {
+        6 if digits.bytes().all(|ch| ch.is_ascii_hexdigit()) => Ok(HexColor {
+            r: u8::from_str_radix(&digits[0..2], 16).unwrap(),
+            g: u8::from_str_radix(&digits[2..4], 16).unwrap(),
+            b: u8::from_str_radix(&digits[4..6], 16).unwrap(),
+        }),
+        3 => Err(ParseError::custom(format!(
+            "shorthand hex colors are not accepted, expand `{value}` to six digits"
+        ))),
+        8 => Err(ParseError::custom(format!(
+            "hex colors with an alpha channel are not accepted: {value}"
+        ))),
+        _ => Err(ParseError::custom(format!("invalid hex color: {value}"))),
+    }
+}
+
+impl Type for HexColor {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_hex-color".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^#[0-9a-fA-F]{6}$".to_string()),
+            ..MetaSchema::new_with_format("string", "hex-color")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for HexColor {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_hex_color(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for HexColor {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_hex_color(value)
+    }
+}
+
+impl ToJSON for HexColor {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_and_normalize() {
+        let color = HexColor::parse_from_json(Some(json!("#FF8000"))).unwrap();
+        assert_eq!(
+            color,
+            HexColor {
+                r: 0xff,
+                g: 0x80,
+                b: 0x00
+            }
+        );
+        // uppercase input serializes back as lowercase
+        assert_eq!(color.to_json(), Some(json!("#ff8000")));
+    }
+
+    #[test]
+    fn reject_shorthand() {
+        let err = HexColor::parse_from_json(Some(json!("#abc"))).unwrap_err();
+        assert!(err.into_message().contains("shorthand hex colors"));
+    }
+
+    #[test]
+    fn reject_alpha() {
+        let err = HexColor::parse_from_json(Some(json!("#11223344"))).unwrap_err();
+        assert!(err.into_message().contains("alpha channel"));
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!(HexColor::parse_from_parameter("112233").is_err());
+        assert!(HexColor::parse_from_parameter("#11223g").is_err());
+    }
+}
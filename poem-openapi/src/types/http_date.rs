@@ -0,0 +1,130 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use jiff::{Timestamp, fmt::strtime, tz::TimeZone};
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+const IMF_FIXDATE: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// An HTTP date in the RFC 7231 `IMF-fixdate` form.
+///
+/// Only the `Sun, 06 Nov 1994 08:49:37 GMT` form is accepted; the obsolete
+/// RFC 850 and asctime forms are rejected.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct HttpDate(pub Timestamp);
+
+impl Deref for HttpDate {
+    type Target = Timestamp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HttpDate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Display for HttpDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let zoned = self.0.to_zoned(TimeZone::UTC);
+        match strtime::format(IMF_FIXDATE, &zoned) {
+            Ok(s) => f.write_str(&s),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
+
+fn parse_imf_fixdate(value: &str) -> Result<Timestamp, jiff::Error> {
+    let datetime = strtime::parse(IMF_FIXDATE, value)?.to_datetime()?;
+    Ok(datetime.to_zoned(TimeZone::UTC)?.timestamp())
+}
+
+impl Type for HttpDate {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_http-date".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "http-date")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for HttpDate {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self(parse_imf_fixdate(&value)?))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for HttpDate {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_imf_fixdate(value)
+            .map(Self)
+            .map_err(ParseError::custom)
+    }
+}
+
+impl ToJSON for HttpDate {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+impl ToHeader for HttpDate {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.to_string()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_imf_fixdate() {
+        let date = HttpDate::parse_from_parameter("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert_eq!(date.0.as_second(), 784111777);
+        assert_eq!(date.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    }
+
+    #[test]
+    fn reject_other_forms() {
+        // RFC 850
+        assert!(HttpDate::parse_from_parameter("Sunday, 06-Nov-94 08:49:37 GMT").is_err());
+        // asctime
+        assert!(HttpDate::parse_from_parameter("Sun Nov  6 08:49:37 1994").is_err());
+        assert!(HttpDate::parse_from_parameter("1994-11-06T08:49:37Z").is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An International Bank Account Number validated on parse.
+///
+/// Input is normalized to uppercase with spaces removed, then checked against
+/// the country-specific length and the ISO 13616 mod-97 checksum. Invalid
+/// checksums, unknown country codes, and wrong lengths are rejected with a
+/// message naming the problem.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{Iban, ParseFromParameter};
+///
+/// let iban = Iban::parse_from_parameter("de89 3704 0044 0532 0130 00").unwrap();
+/// assert_eq!(iban.as_str(), "DE89370400440532013000");
+/// assert_eq!(iban.country_code(), "DE");
+/// assert!(Iban::parse_from_parameter("DE89370400440532013001").is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Iban(String);
+
+/// IBAN lengths by country, from the ISO 13616 registry.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BR", 29),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MT", 31),
+    ("MU", 30),
+    ("NL", 18),
+    ("NO", 15),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("SA", 24),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VG", 24),
+    ("XK", 20),
+];
+
+impl Iban {
+    /// Creates an `Iban` from the given value, normalizing to uppercase
+    /// no-spaces form. Returns an error message describing the first problem
+    /// found.
+    pub fn new(value: impl AsRef<str>) -> Result<Self, String> {
+        let normalized = value
+            .as_ref()
+            .chars()
+            .filter(|ch| !ch.is_ascii_whitespace())
+            .map(|ch| ch.to_ascii_uppercase())
+            .collect::<String>();
+
+        if normalized.len() < 4 || !normalized.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+            return Err("invalid IBAN".to_string());
+        }
+        let country = &normalized[..2];
+        if !country.chars().all(|ch| ch.is_ascii_uppercase()) {
+            return Err("invalid IBAN".to_string());
+        }
+
+        match IBAN_LENGTHS
+            .binary_search_by_key(&country, |(country, _)| country)
+            .map(|idx| IBAN_LENGTHS[idx].1)
+        {
+            Ok(expected) if normalized.len() != expected => {
+                return Err(format!(
+                    "invalid IBAN length for {country}: expected {expected} characters, got {}",
+                    normalized.len()
+                ));
+            }
+            Ok(_) => {}
+            Err(_) => return Err(format!("unknown IBAN country code `{country}`")),
+        }
+
+        // mod-97: move the first four characters to the end, substitute
+        // letters with their position (A=10, ..., Z=35), and require a
+        // remainder of 1
+        let mut remainder = 0u32;
+        for ch in normalized[4..].chars().chain(normalized[..4].chars()) {
+            let digit = ch.to_digit(36).expect("checked alphanumeric");
+            remainder = if digit < 10 {
+                (remainder * 10 + digit) % 97
+            } else {
+                (remainder * 100 + digit) % 97
+            };
+        }
+        if remainder != 1 {
+            return Err("invalid IBAN checksum".to_string());
+        }
+
+        Ok(Self(normalized))
+    }
+
+    /// Returns the normalized IBAN.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the two-letter country code.
+    #[inline]
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// Consumes this object and returns the normalized IBAN.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Type for Iban {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_iban".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^[A-Z]{2}[0-9]{2}[A-Za-z0-9]{11,30}$".to_string()),
+            ..MetaSchema::new_with_format("string", "iban")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Iban {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Iban::new(value).map_err(ParseError::custom)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for Iban {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Iban::new(value).map_err(ParseError::custom)
+    }
+}
+
+impl ToJSON for Iban {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn lengths_table_is_sorted() {
+        // `new` relies on binary search
+        assert!(IBAN_LENGTHS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn parse_valid_iban() {
+        let iban = Iban::parse_from_json(Some(json!("DE89370400440532013000"))).unwrap();
+        assert_eq!(iban.as_str(), "DE89370400440532013000");
+        assert_eq!(iban.country_code(), "DE");
+
+        // lowercase and spaces are normalized away
+        let iban = Iban::parse_from_parameter("gb82 west 1234 5698 7654 32").unwrap();
+        assert_eq!(iban.as_str(), "GB82WEST12345698765432");
+        assert_eq!(iban.to_json(), Some(json!("GB82WEST12345698765432")));
+    }
+
+    #[test]
+    fn reject_checksum_failure() {
+        let err = Iban::parse_from_json(Some(json!("DE89370400440532013001"))).unwrap_err();
+        assert!(err.into_message().contains("invalid IBAN checksum"));
+    }
+
+    #[test]
+    fn reject_wrong_length_and_unknown_country() {
+        let err = Iban::parse_from_parameter("DE8937040044053201300").unwrap_err();
+        assert!(err.into_message().contains("invalid IBAN length for DE"));
+
+        let err = Iban::parse_from_parameter("ZZ89370400440532013000").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("unknown IBAN country code `ZZ`")
+        );
+
+        assert!(Iban::parse_from_parameter("DE").is_err());
+        assert!(Iban::parse_from_json(Some(json!(123))).is_err());
+    }
+}
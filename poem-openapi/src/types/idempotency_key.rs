@@ -0,0 +1,159 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::{http::HeaderValue, web::Field};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+/// A validated idempotency key, usually extracted from the `Idempotency-Key`
+/// request header.
+///
+/// Keys must be between 1 and 255 visible ASCII characters; a UUID is the
+/// recommended form. Storage and replay of previously seen keys is left to the
+/// application.
+///
+/// # Examples
+///
+/// ```ignore
+/// async fn create(
+///     &self,
+///     #[oai(name = "Idempotency-Key")] key: Header<IdempotencyKey>,
+/// ) -> CreateResponse {
+///     ...
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct IdempotencyKey(String);
+
+impl IdempotencyKey {
+    /// Create a new idempotency key, returning `None` if the value is not a
+    /// valid key.
+    pub fn new(key: impl Into<String>) -> Option<Self> {
+        let key = key.into();
+        if is_valid_key(&key) {
+            Some(Self(key))
+        } else {
+            None
+        }
+    }
+
+    /// Consumes this object and returns the key as a string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for IdempotencyKey {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for IdempotencyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty() && key.len() <= 255 && key.bytes().all(|ch| ch.is_ascii_graphic())
+}
+
+impl Type for IdempotencyKey {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_idempotency-key".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            min_length: Some(1),
+            max_length: Some(255),
+            ..MetaSchema::new_with_format("string", "idempotency-key")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for IdempotencyKey {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            IdempotencyKey::new(value).ok_or_else(|| ParseError::custom("invalid idempotency key"))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for IdempotencyKey {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        IdempotencyKey::new(value).ok_or_else(|| ParseError::custom("invalid idempotency key"))
+    }
+}
+
+impl ParseFromMultipartField for IdempotencyKey {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => Self::parse_from_parameter(&field.text().await?),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl ToJSON for IdempotencyKey {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for IdempotencyKey {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_valid_key() {
+        let key =
+            IdempotencyKey::parse_from_parameter("8e03978e-40d5-43e8-bc93-6894a57f9324").unwrap();
+        assert_eq!(&*key, "8e03978e-40d5-43e8-bc93-6894a57f9324");
+    }
+
+    #[test]
+    fn reject_malformed_key() {
+        assert!(IdempotencyKey::parse_from_parameter("").is_err());
+        assert!(IdempotencyKey::parse_from_parameter("has space").is_err());
+        assert!(IdempotencyKey::parse_from_parameter(&"x".repeat(256)).is_err());
+    }
+}
@@ -0,0 +1,458 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{JsonPointer, ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A single RFC 6902 patch operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatchOperation {
+    /// Adds a value at the target location.
+    Add {
+        /// The target location.
+        path: JsonPointer,
+        /// The value to add.
+        value: Value,
+    },
+    /// Removes the value at the target location.
+    Remove {
+        /// The target location.
+        path: JsonPointer,
+    },
+    /// Replaces the value at the target location.
+    Replace {
+        /// The target location.
+        path: JsonPointer,
+        /// The replacement value.
+        value: Value,
+    },
+    /// Moves the value from one location to another.
+    Move {
+        /// The location to move the value from.
+        from: JsonPointer,
+        /// The target location.
+        path: JsonPointer,
+    },
+    /// Copies the value from one location to another.
+    Copy {
+        /// The location to copy the value from.
+        from: JsonPointer,
+        /// The target location.
+        path: JsonPointer,
+    },
+    /// Tests that the value at the target location equals the specified value.
+    Test {
+        /// The target location.
+        path: JsonPointer,
+        /// The expected value.
+        value: Value,
+    },
+}
+
+/// An error that occurs while applying a [`JsonPatch`] to a document.
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum PatchApplyError {
+    /// The path does not exist in the document.
+    #[error("path `{0}` does not exist")]
+    PathNotFound(String),
+
+    /// An array index is not valid for the target array.
+    #[error("invalid array index `{0}`")]
+    InvalidIndex(String),
+
+    /// A `test` operation did not match the expected value.
+    #[error("test failed at `{0}`")]
+    TestFailed(String),
+}
+
+/// An RFC 6902 JSON Patch document.
+///
+/// The patch is an array of operations which is validated on parse: `add`,
+/// `replace` and `test` require a `value`, while `move` and `copy` require a
+/// `from` pointer. The parsed patch can be applied to a
+/// [`serde_json::Value`] with [`apply`](Self::apply).
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{JsonPatch, ParseFromJSON};
+/// use serde_json::json;
+///
+/// let patch = JsonPatch::parse_from_json(Some(json!([
+///     { "op": "add", "path": "/name", "value": "sunli" },
+///     { "op": "remove", "path": "/age" },
+/// ])))
+/// .unwrap();
+///
+/// let mut doc = json!({ "age": 100 });
+/// patch.apply(&mut doc).unwrap();
+/// assert_eq!(doc, json!({ "name": "sunli" }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPatch(pub Vec<PatchOperation>);
+
+impl JsonPatch {
+    /// Applies all operations of this patch to the document in order.
+    ///
+    /// If any operation fails the document is left in the partially patched
+    /// state; apply the patch to a clone if atomicity is required.
+    pub fn apply(&self, document: &mut Value) -> Result<(), PatchApplyError> {
+        for operation in &self.0 {
+            match operation {
+                PatchOperation::Add { path, value } => add(document, path, value.clone())?,
+                PatchOperation::Remove { path } => {
+                    remove(document, path)?;
+                }
+                PatchOperation::Replace { path, value } => {
+                    *get_mut(document, path)? = value.clone();
+                }
+                PatchOperation::Move { from, path } => {
+                    let value = remove(document, from)?;
+                    add(document, path, value)?;
+                }
+                PatchOperation::Copy { from, path } => {
+                    let value = get_mut(document, from)?.clone();
+                    add(document, path, value)?;
+                }
+                PatchOperation::Test { path, value } => {
+                    if get_mut(document, path)? != value {
+                        return Err(PatchApplyError::TestFailed(path.to_string()));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn array_index(token: &str, len: usize) -> Option<usize> {
+    // leading zeros are not allowed by RFC 6901
+    if token != "0" && token.starts_with('0') {
+        return None;
+    }
+    token.parse().ok().filter(|idx| *idx < len)
+}
+
+fn step_mut<'a>(document: &'a mut Value, token: &str) -> Option<&'a mut Value> {
+    match document {
+        Value::Object(map) => map.get_mut(token),
+        Value::Array(array) => {
+            let idx = array_index(token, array.len())?;
+            array.get_mut(idx)
+        }
+        _ => None,
+    }
+}
+
+fn get_mut<'a>(
+    mut document: &'a mut Value,
+    pointer: &JsonPointer,
+) -> Result<&'a mut Value, PatchApplyError> {
+    for token in pointer.tokens() {
+        document = step_mut(document, &token)
+            .ok_or_else(|| PatchApplyError::PathNotFound(pointer.to_string()))?;
+    }
+    Ok(document)
+}
+
+fn parent_mut<'a>(
+    mut document: &'a mut Value,
+    pointer: &JsonPointer,
+) -> Result<(&'a mut Value, String), PatchApplyError> {
+    let tokens = pointer.tokens();
+    let (last, parents) = tokens
+        .split_last()
+        .ok_or_else(|| PatchApplyError::PathNotFound(pointer.to_string()))?;
+    for token in parents {
+        document = step_mut(document, token)
+            .ok_or_else(|| PatchApplyError::PathNotFound(pointer.to_string()))?;
+    }
+    Ok((document, last.clone()))
+}
+
+fn add(document: &mut Value, pointer: &JsonPointer, value: Value) -> Result<(), PatchApplyError> {
+    // an empty pointer replaces the whole document
+    if pointer.tokens().is_empty() {
+        *document = value;
+        return Ok(());
+    }
+    let (parent, last) = parent_mut(document, pointer)?;
+    match parent {
+        Value::Object(map) => {
+            map.insert(last, value);
+            Ok(())
+        }
+        Value::Array(array) => {
+            let idx = if last == "-" {
+                array.len()
+            } else {
+                array_index(&last, array.len() + 1).ok_or(PatchApplyError::InvalidIndex(last))?
+            };
+            array.insert(idx, value);
+            Ok(())
+        }
+        _ => Err(PatchApplyError::PathNotFound(pointer.to_string())),
+    }
+}
+
+fn remove(document: &mut Value, pointer: &JsonPointer) -> Result<Value, PatchApplyError> {
+    let (parent, last) = parent_mut(document, pointer)?;
+    match parent {
+        Value::Object(map) => map
+            .remove(&last)
+            .ok_or_else(|| PatchApplyError::PathNotFound(pointer.to_string())),
+        Value::Array(array) => {
+            let idx = array_index(&last, array.len())
+                .ok_or_else(|| PatchApplyError::PathNotFound(pointer.to_string()))?;
+            Ok(array.remove(idx))
+        }
+        _ => Err(PatchApplyError::PathNotFound(pointer.to_string())),
+    }
+}
+
+fn operation_schema() -> MetaSchema {
+    MetaSchema {
+        required: vec!["op", "path"],
+        properties: vec![
+            (
+                "op",
+                MetaSchemaRef::Inline(Box::new(MetaSchema {
+                    enum_items: ["add", "remove", "replace", "move", "copy", "test"]
+                        .into_iter()
+                        .map(Value::from)
+                        .collect(),
+                    ..MetaSchema::new("string")
+                })),
+            ),
+            ("path", JsonPointer::schema_ref()),
+            ("value", MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))),
+            ("from", JsonPointer::schema_ref()),
+        ],
+        ..MetaSchema::new("object")
+    }
+}
+
+impl Type for JsonPatch {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "json-patch".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(MetaSchemaRef::Inline(
+                Box::new(operation_schema()),
+            ))),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn parse_pointer(
+    object: &serde_json::Map<String, Value>,
+    idx: usize,
+    name: &str,
+) -> Result<JsonPointer, ParseError<JsonPatch>> {
+    match object.get(name) {
+        Some(Value::String(value)) => JsonPointer::new(value.clone()).ok_or_else(|| {
+            ParseError::custom(format!("operation {idx}: invalid JSON pointer: {value}"))
+        }),
+        Some(_) => Err(ParseError::custom(format!(
+            "operation {idx}: `{name}` must be a string"
+        ))),
+        None => Err(ParseError::custom(format!(
+            "operation {idx}: missing `{name}`"
+        ))),
+    }
+}
+
+fn parse_value(
+    object: &serde_json::Map<String, Value>,
+    idx: usize,
+    op: &str,
+) -> Result<Value, ParseError<JsonPatch>> {
+    object
+        .get("value")
+        .cloned()
+        .ok_or_else(|| ParseError::custom(format!("operation {idx}: `{op}` requires a `value`")))
+}
+
+impl ParseFromJSON for JsonPatch {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let Value::Array(items) = value else {
+            return Err(ParseError::expected_type(value));
+        };
+
+        let mut operations = Vec::with_capacity(items.len());
+        for (idx, item) in items.into_iter().enumerate() {
+            let Value::Object(object) = item else {
+                return Err(ParseError::custom(format!(
+                    "operation {idx}: expected an object"
+                )));
+            };
+            let Some(Value::String(op)) = object.get("op") else {
+                return Err(ParseError::custom(format!("operation {idx}: missing `op`")));
+            };
+            let path = parse_pointer(&object, idx, "path")?;
+            operations.push(match op.as_str() {
+                "add" => PatchOperation::Add {
+                    path,
+                    value: parse_value(&object, idx, "add")?,
+                },
+                "remove" => PatchOperation::Remove { path },
+                "replace" => PatchOperation::Replace {
+                    path,
+                    value: parse_value(&object, idx, "replace")?,
+                },
+                "move" => PatchOperation::Move {
+                    from: parse_pointer(&object, idx, "from")?,
+                    path,
+                },
+                "copy" => PatchOperation::Copy {
+                    from: parse_pointer(&object, idx, "from")?,
+                    path,
+                },
+                "test" => PatchOperation::Test {
+                    path,
+                    value: parse_value(&object, idx, "test")?,
+                },
+                op => {
+                    return Err(ParseError::custom(format!(
+                        "operation {idx}: unknown op `{op}`"
+                    )));
+                }
+            });
+        }
+        Ok(Self(operations))
+    }
+}
+
+impl ToJSON for JsonPatch {
+    fn to_json(&self) -> Option<Value> {
+        let mut items = Vec::with_capacity(self.0.len());
+        for operation in &self.0 {
+            let mut object = serde_json::Map::new();
+            let (op, path) = match operation {
+                PatchOperation::Add { path, value } => {
+                    object.insert("value".to_string(), value.clone());
+                    ("add", path)
+                }
+                PatchOperation::Remove { path } => ("remove", path),
+                PatchOperation::Replace { path, value } => {
+                    object.insert("value".to_string(), value.clone());
+                    ("replace", path)
+                }
+                PatchOperation::Move { from, path } => {
+                    object.insert("from".to_string(), Value::from(from.to_string()));
+                    ("move", path)
+                }
+                PatchOperation::Copy { from, path } => {
+                    object.insert("from".to_string(), Value::from(from.to_string()));
+                    ("copy", path)
+                }
+                PatchOperation::Test { path, value } => {
+                    object.insert("value".to_string(), value.clone());
+                    ("test", path)
+                }
+            };
+            object.insert("op".to_string(), Value::from(op));
+            object.insert("path".to_string(), Value::from(path.to_string()));
+            items.push(Value::Object(object));
+        }
+        Some(Value::Array(items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_and_apply_valid_patch() {
+        let patch = JsonPatch::parse_from_json(Some(json!([
+            { "op": "add", "path": "/tags/-", "value": "new" },
+            { "op": "replace", "path": "/name", "value": "sunli" },
+            { "op": "remove", "path": "/age" },
+            { "op": "move", "from": "/name", "path": "/user" },
+            { "op": "copy", "from": "/user", "path": "/copied" },
+            { "op": "test", "path": "/copied", "value": "sunli" },
+        ])))
+        .unwrap();
+
+        let mut doc = json!({ "name": "bob", "age": 100, "tags": ["old"] });
+        patch.apply(&mut doc).unwrap();
+        assert_eq!(
+            doc,
+            json!({ "tags": ["old", "new"], "user": "sunli", "copied": "sunli" })
+        );
+    }
+
+    #[test]
+    fn reject_invalid_op() {
+        let err = JsonPatch::parse_from_json(Some(json!([
+            { "op": "merge", "path": "/name", "value": "sunli" },
+        ])))
+        .unwrap_err();
+        assert!(err.into_message().contains("unknown op `merge`"));
+    }
+
+    #[test]
+    fn reject_missing_required_fields() {
+        for patch in [
+            json!([{ "op": "add", "path": "/name" }]),
+            json!([{ "op": "move", "path": "/name" }]),
+            json!([{ "op": "test", "value": 1 }]),
+        ] {
+            assert!(JsonPatch::parse_from_json(Some(patch)).is_err());
+        }
+    }
+
+    #[test]
+    fn apply_errors() {
+        let mut doc = json!({ "name": "sunli" });
+
+        let patch = JsonPatch(vec![PatchOperation::Remove {
+            path: JsonPointer::new("/missing").unwrap(),
+        }]);
+        assert_eq!(
+            patch.apply(&mut doc),
+            Err(PatchApplyError::PathNotFound("/missing".to_string()))
+        );
+
+        let patch = JsonPatch(vec![PatchOperation::Test {
+            path: JsonPointer::new("/name").unwrap(),
+            value: json!("bob"),
+        }]);
+        assert_eq!(
+            patch.apply(&mut doc),
+            Err(PatchApplyError::TestFailed("/name".to_string()))
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// An RFC 6901 JSON Pointer, e.g. `/foo/0/bar`.
+///
+/// A valid pointer is either empty (the whole document) or a sequence of
+/// `/`-prefixed reference tokens where `~` only appears in the escape
+/// sequences `~0` (`~`) and `~1` (`/`).
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct JsonPointer(String);
+
+impl JsonPointer {
+    /// Create a new JSON pointer, returning `None` if the value is not a
+    /// valid RFC 6901 pointer.
+    pub fn new(pointer: impl Into<String>) -> Option<Self> {
+        let pointer = pointer.into();
+        if is_valid_pointer(&pointer) {
+            Some(Self(pointer))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the unescaped reference tokens of this pointer.
+    pub fn tokens(&self) -> Vec<String> {
+        self.0
+            .split('/')
+            .skip(1)
+            .map(|token| token.replace("~1", "/").replace("~0", "~"))
+            .collect()
+    }
+
+    /// Consumes this object and returns the pointer as a string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for JsonPointer {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for JsonPointer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn is_valid_pointer(pointer: &str) -> bool {
+    if pointer.is_empty() {
+        return true;
+    }
+    if !pointer.starts_with('/') {
+        return false;
+    }
+    // `~` must be followed by `0` or `1`
+    let mut bytes = pointer.bytes();
+    while let Some(ch) = bytes.next() {
+        if ch == b'~' && !matches!(bytes.next(), Some(b'0') | Some(b'1')) {
+            return false;
+        }
+    }
+    true
+}
+
+impl Type for JsonPointer {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_json-pointer".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
+            "string",
+            "json-pointer",
+        )))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ParseFromJSON for JsonPointer {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            JsonPointer::new(value.clone())
+                .ok_or_else(|| ParseError::custom(format!("invalid JSON pointer: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for JsonPointer {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        JsonPointer::new(value)
+            .ok_or_else(|| ParseError::custom(format!("invalid JSON pointer: {value}")))
+    }
+}
+
+impl ToJSON for JsonPointer {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for JsonPointer {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_pointers() {
+        for value in ["", "/foo/0/bar", "/", "//", "/a~0b/c~1d", "/ "] {
+            assert!(
+                JsonPointer::parse_from_json(Some(json!(value))).is_ok(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn reject_invalid_pointers() {
+        for value in ["foo", "foo/bar", "/foo~", "/foo~2", "/~x"] {
+            assert!(
+                JsonPointer::parse_from_json(Some(json!(value))).is_err(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn unescape_tokens() {
+        let pointer = JsonPointer::new("/a~0b/c~1d/0").unwrap();
+        assert_eq!(pointer.tokens(), vec!["a~b", "c/d", "0"]);
+        assert_eq!(pointer.to_json(), Some(json!("/a~0b/c~1d/0")));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// A BCP 47 language tag, e.g. `en-US` or `zh-Hant-TW`.
+///
+/// Only the structure is validated: a primary language subtag of 2-8 letters
+/// followed by any number of 1-8 character alphanumeric subtags, separated by
+/// hyphens. The subtags are not checked against the IANA registry.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LanguageTag(String);
+
+impl LanguageTag {
+    /// Create a new language tag, returning `None` if the value is not
+    /// structurally valid.
+    pub fn new(tag: impl Into<String>) -> Option<Self> {
+        let tag = tag.into();
+        if is_valid_tag(&tag) {
+            Some(Self(tag))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the primary language subtag, e.g. `en` for `en-US`.
+    pub fn primary_language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// Consumes this object and returns the tag as a string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for LanguageTag {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn is_valid_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+
+    match subtags.next() {
+        Some(primary)
+            if (2..=8).contains(&primary.len())
+                && primary.bytes().all(|ch| ch.is_ascii_alphabetic()) => {}
+        _ => return false,
+    }
+
+    subtags.all(|subtag| {
+        (1..=8).contains(&subtag.len()) && subtag.bytes().all(|ch| ch.is_ascii_alphanumeric())
+    })
+}
+
+impl Type for LanguageTag {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_bcp47".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^[a-zA-Z]{2,8}(-[a-zA-Z0-9]{1,8})*$".to_string()),
+            ..MetaSchema::new_with_format("string", "bcp47")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for LanguageTag {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            LanguageTag::new(value.clone())
+                .ok_or_else(|| ParseError::custom(format!("invalid language tag: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for LanguageTag {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        LanguageTag::new(value)
+            .ok_or_else(|| ParseError::custom(format!("invalid language tag: {value}")))
+    }
+}
+
+impl ToJSON for LanguageTag {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for LanguageTag {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_tags() {
+        for value in ["en", "en-US", "zh-Hant-TW", "de-DE-1996", "sr-Latn"] {
+            let tag = LanguageTag::parse_from_json(Some(json!(value))).unwrap();
+            assert_eq!(&*tag, value);
+        }
+        assert_eq!(
+            LanguageTag::new("zh-Hant-TW").unwrap().primary_language(),
+            "zh"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_tags() {
+        for value in ["", "e", "en--US", "-en", "en-", "en_US", "abcdefghi"] {
+            assert!(
+                LanguageTag::parse_from_json(Some(json!(value))).is_err(),
+                "{value:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use jiff::Timestamp;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An RFC 3339 timestamp that tolerates leap seconds.
+///
+/// Some upstream systems emit leap-second timestamps such as
+/// `2016-12-31T23:59:60Z`, which `jiff::Timestamp` silently truncates to
+/// `:59`. This type parses normal timestamps unchanged and handles a `:60`
+/// seconds field according to `ROUND_UP`:
+///
+/// - `false` (the default): clamp to the last representable instant of the
+///   preceding second (`23:59:59.999999999`).
+/// - `true`: roll over to the next second (`00:00:00`).
+///
+/// Any fractional part of the leap second is discarded.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct LenientTimestamp<const ROUND_UP: bool = false>(pub Timestamp);
+
+impl<const ROUND_UP: bool> Deref for LenientTimestamp<ROUND_UP> {
+    type Target = Timestamp;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const ROUND_UP: bool> DerefMut for LenientTimestamp<ROUND_UP> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const ROUND_UP: bool> Display for LenientTimestamp<ROUND_UP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Rewrites a leap-second timestamp to use `:59` seconds, dropping any
+/// fractional part of the leap second. Returns `None` if the value does not
+/// contain a `:60` seconds field.
+fn replace_leap_second(value: &str) -> Option<String> {
+    let sep = value.find(['T', 't', ' '])?;
+    let time = value.get(sep + 1..)?.as_bytes();
+    if time.len() < 8 || time[2] != b':' || time[5] != b':' || &time[6..8] != b"60" {
+        return None;
+    }
+    let mut end = sep + 1 + 8;
+    let bytes = value.as_bytes();
+    if bytes.get(end) == Some(&b'.') {
+        end += 1;
+        while bytes.get(end).is_some_and(u8::is_ascii_digit) {
+            end += 1;
+        }
+    }
+    Some(format!("{}59{}", &value[..sep + 1 + 6], &value[end..]))
+}
+
+fn parse_lenient<const ROUND_UP: bool>(value: &str) -> Result<Timestamp, jiff::Error> {
+    match replace_leap_second(value) {
+        Some(replaced) => {
+            let timestamp = replaced.parse::<Timestamp>()?;
+            if ROUND_UP {
+                Timestamp::new(timestamp.as_second() + 1, 0)
+            } else {
+                Timestamp::new(timestamp.as_second(), 999_999_999)
+            }
+        }
+        None => value.parse(),
+    }
+}
+
+impl<const ROUND_UP: bool> Type for LenientTimestamp<ROUND_UP> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_date-time".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "date-time")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<const ROUND_UP: bool> ParseFromJSON for LenientTimestamp<ROUND_UP> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self(parse_lenient::<ROUND_UP>(&value)?))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<const ROUND_UP: bool> ParseFromParameter for LenientTimestamp<ROUND_UP> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_lenient::<ROUND_UP>(value)
+            .map(Self)
+            .map_err(ParseError::custom)
+    }
+}
+
+impl<const ROUND_UP: bool> ToJSON for LenientTimestamp<ROUND_UP> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_normal_timestamp() {
+        let timestamp =
+            LenientTimestamp::<false>::parse_from_parameter("2024-06-19T15:22:45Z").unwrap();
+        assert_eq!(timestamp.0, "2024-06-19T15:22:45Z".parse().unwrap());
+    }
+
+    #[test]
+    fn parse_leap_second_clamped() {
+        let timestamp =
+            LenientTimestamp::<false>::parse_from_parameter("2016-12-31T23:59:60Z").unwrap();
+        let expected: Timestamp = "2016-12-31T23:59:59.999999999Z".parse().unwrap();
+        assert_eq!(timestamp.0, expected);
+
+        // the fractional part of the leap second is discarded
+        let timestamp =
+            LenientTimestamp::<false>::parse_from_parameter("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!(timestamp.0, expected);
+    }
+
+    #[test]
+    fn parse_leap_second_rounded_up() {
+        let timestamp =
+            LenientTimestamp::<true>::parse_from_parameter("2016-12-31T23:59:60Z").unwrap();
+        assert_eq!(timestamp.0, "2017-01-01T00:00:00Z".parse().unwrap());
+    }
+
+    #[test]
+    fn reject_invalid_timestamps() {
+        assert!(LenientTimestamp::<false>::parse_from_parameter("2016-12-31T23:59:61Z").is_err());
+        assert!(LenientTimestamp::<false>::parse_from_parameter("not a timestamp").is_err());
+    }
+}
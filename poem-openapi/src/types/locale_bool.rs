@@ -0,0 +1,192 @@
+use std::{borrow::Cow, marker::PhantomData, ops::Deref};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// The truthy and falsy words accepted by a [`LocaleBool`].
+pub trait LocaleWords: Send + Sync {
+    /// Words parsed as `true`.
+    const TRUTHY: &'static [&'static str];
+
+    /// Words parsed as `false`.
+    const FALSY: &'static [&'static str];
+}
+
+/// The default word set covering a few common locales.
+///
+/// Accepts `true`/`yes`/`oui`/`ja`/`si`/`da`/`1` as true and
+/// `false`/`no`/`non`/`nein`/`nee`/`net`/`0` as false.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct CommonLocaleWords;
+
+impl LocaleWords for CommonLocaleWords {
+    const TRUTHY: &'static [&'static str] = &["true", "yes", "oui", "ja", "si", "da", "1"];
+    const FALSY: &'static [&'static str] = &["false", "no", "non", "nein", "nee", "net", "0"];
+}
+
+/// A boolean parsed from locale-specific words.
+///
+/// Matching is case-insensitive against the word set given by `W`; anything
+/// outside the set is rejected with an error listing the accepted words.
+/// Serializes as a plain JSON boolean.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{CommonLocaleWords, LocaleBool, ParseFromParameter};
+///
+/// let agree = LocaleBool::<CommonLocaleWords>::parse_from_parameter("oui").unwrap();
+/// assert!(*agree);
+/// let agree = LocaleBool::<CommonLocaleWords>::parse_from_parameter("NEIN").unwrap();
+/// assert!(!*agree);
+/// assert!(LocaleBool::<CommonLocaleWords>::parse_from_parameter("jein").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LocaleBool<W = CommonLocaleWords>(pub bool, PhantomData<W>);
+
+impl<W> LocaleBool<W> {
+    /// Creates a `LocaleBool` with the given value.
+    pub fn new(value: bool) -> Self {
+        Self(value, PhantomData)
+    }
+}
+
+impl<W> Deref for LocaleBool<W> {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<W> From<LocaleBool<W>> for bool {
+    fn from(value: LocaleBool<W>) -> Self {
+        value.0
+    }
+}
+
+fn parse_word<W: LocaleWords, T: Type>(value: &str) -> Result<bool, ParseError<T>> {
+    let word = value.trim();
+    if W::TRUTHY
+        .iter()
+        .any(|truthy| word.eq_ignore_ascii_case(truthy))
+    {
+        Ok(true)
+    } else if W::FALSY
+        .iter()
+        .any(|falsy| word.eq_ignore_ascii_case(falsy))
+    {
+        Ok(false)
+    } else {
+        Err(ParseError::custom(format!(
+            "unknown boolean word `{word}`, expected one of: {}, {}",
+            W::TRUTHY.join(", "),
+            W::FALSY.join(", ")
+        )))
+    }
+}
+
+impl<W: LocaleWords> Type for LocaleBool<W> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = bool;
+
+    type RawElementValueType = bool;
+
+    fn name() -> Cow<'static, str> {
+        "string_locale-bool".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            enum_items: W::TRUTHY
+                .iter()
+                .chain(W::FALSY)
+                .map(|word| Value::String((*word).to_string()))
+                .collect(),
+            ..MetaSchema::new_with_format("string", "locale-bool")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<W: LocaleWords> ParseFromJSON for LocaleBool<W> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::Bool(value) => Ok(Self::new(value)),
+            Value::String(value) => parse_word::<W, Self>(&value).map(Self::new),
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<W: LocaleWords> ParseFromParameter for LocaleBool<W> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_word::<W, Self>(value).map(Self::new)
+    }
+}
+
+impl<W: LocaleWords> ToJSON for LocaleBool<W> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::Bool(self.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct GermanWords;
+
+    impl LocaleWords for GermanWords {
+        const TRUTHY: &'static [&'static str] = &["ja", "jawohl"];
+        const FALSY: &'static [&'static str] = &["nein"];
+    }
+
+    #[test]
+    fn common_words() {
+        assert!(*LocaleBool::<CommonLocaleWords>::parse_from_parameter("oui").unwrap());
+        assert!(*LocaleBool::<CommonLocaleWords>::parse_from_parameter("Si").unwrap());
+        assert!(!*LocaleBool::<CommonLocaleWords>::parse_from_parameter("non").unwrap());
+        assert!(!*LocaleBool::<CommonLocaleWords>::parse_from_parameter(" no ").unwrap());
+    }
+
+    #[test]
+    fn custom_word_set() {
+        assert!(*LocaleBool::<GermanWords>::parse_from_parameter("jawohl").unwrap());
+        assert!(!*LocaleBool::<GermanWords>::parse_from_parameter("NEIN").unwrap());
+        // words outside the custom set are rejected, even common ones
+        let err = LocaleBool::<GermanWords>::parse_from_parameter("yes").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("unknown boolean word `yes`, expected one of: ja, jawohl, nein")
+        );
+    }
+
+    #[test]
+    fn parse_from_json_forms() {
+        let value = LocaleBool::<CommonLocaleWords>::parse_from_json(Some(json!("ja"))).unwrap();
+        assert!(*value);
+        let value = LocaleBool::<CommonLocaleWords>::parse_from_json(Some(json!(false))).unwrap();
+        assert!(!*value);
+        assert_eq!(value.to_json(), Some(json!(false)));
+        assert!(LocaleBool::<CommonLocaleWords>::parse_from_json(Some(json!(1))).is_err());
+    }
+}
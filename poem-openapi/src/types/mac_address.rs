@@ -0,0 +1,132 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// A MAC address.
+///
+/// Accepts the colon form `aa:bb:cc:dd:ee:ff` and the dash form
+/// `aa-bb-cc-dd-ee-ff` on input, and always serializes to the
+/// colon-separated lowercase form.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+fn parse_mac(value: &str) -> Option<MacAddress> {
+    let sep = if value.contains(':') { ':' } else { '-' };
+    let mut bytes = [0; 6];
+    let mut groups = value.split(sep);
+    for byte in &mut bytes {
+        let group = groups.next()?;
+        if group.len() != 2 {
+            return None;
+        }
+        *byte = u8::from_str_radix(group, 16).ok()?;
+    }
+    if groups.next().is_some() {
+        return None;
+    }
+    Some(MacAddress(bytes))
+}
+
+impl Type for MacAddress {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_mac".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^[0-9a-fA-F]{2}([:-][0-9a-fA-F]{2}){5}$".to_string()),
+            ..MetaSchema::new_with_format("string", "mac")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for MacAddress {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_mac(&value)
+                .ok_or_else(|| ParseError::custom(format!("invalid MAC address: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for MacAddress {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_mac(value).ok_or_else(|| ParseError::custom(format!("invalid MAC address: {value}")))
+    }
+}
+
+impl ToJSON for MacAddress {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+impl ToHeader for MacAddress {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.to_string()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_colon_form() {
+        let mac = MacAddress::parse_from_json(Some(json!("AA:BB:cc:dd:ee:0F"))).unwrap();
+        assert_eq!(mac, MacAddress([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0x0f]));
+        assert_eq!(mac.to_json(), Some(json!("aa:bb:cc:dd:ee:0f")));
+    }
+
+    #[test]
+    fn parse_dash_form() {
+        let mac = MacAddress::parse_from_parameter("aa-bb-cc-dd-ee-0f").unwrap();
+        assert_eq!(mac, MacAddress([0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0x0f]));
+        assert_eq!(mac.to_string(), "aa:bb:cc:dd:ee:0f");
+    }
+
+    #[test]
+    fn invalid_mac() {
+        assert!(MacAddress::parse_from_parameter("aa:bb:cc:dd:ee").is_err());
+        assert!(MacAddress::parse_from_parameter("aa:bb:cc:dd:ee:ff:00").is_err());
+        assert!(MacAddress::parse_from_parameter("aa:bb:cc:dd:ee:gg").is_err());
+        assert!(MacAddress::parse_from_parameter("aabbccddeeff").is_err());
+    }
+}
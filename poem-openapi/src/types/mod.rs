@@ -2,11 +2,59 @@
 
 mod any;
 mod base64_type;
+mod bbox;
 mod binary;
+mod bitmask;
+mod bool_expr;
+mod bounded_duration;
+mod bounded_int;
+mod card_number;
+mod color;
+mod country_code;
+mod cursor;
+mod data_uri;
+mod encoded_token;
+mod enum_set;
 mod error;
 mod external;
+mod filter_query;
+#[cfg(feature = "jiff")]
+mod fixed_precision;
+mod flag;
+mod hex_color;
+#[cfg(feature = "jiff")]
+mod http_date;
+mod iban;
+mod idempotency_key;
+mod json_patch;
+mod json_pointer;
+mod jwt;
+mod language_tag;
+#[cfg(feature = "jiff")]
+mod lenient_timestamp;
+mod locale_bool;
+mod mac_address;
 mod maybe_undefined;
+mod money;
+mod non_empty_string;
+mod ordered_set;
+mod password;
+mod phone_number;
+mod port;
+mod postal_code;
+mod projection;
+mod ratio;
+mod regex_pattern;
+mod scalar;
+mod slug;
+#[cfg(any(feature = "email", feature = "hostname"))]
 mod string_types;
+#[cfg(feature = "jiff")]
+mod time_series;
+mod toggles;
+#[cfg(feature = "url")]
+mod webhook_url;
+mod weighted;
 
 pub mod multipart;
 
@@ -14,24 +62,99 @@ use std::{borrow::Cow, future::Future, sync::Arc};
 
 pub use any::Any;
 pub use base64_type::Base64;
+pub use bbox::BBox;
 pub use binary::Binary;
+pub use bitmask::{Bitmask, EnumBitmask};
+pub use bool_expr::BoolExpr;
+pub use bounded_duration::BoundedDuration;
+pub use bounded_int::BoundedInt;
+pub use card_number::CardNumber;
+pub use color::Color;
+pub use country_code::CountryCode;
+pub use cursor::Cursor;
+pub use data_uri::DataUri;
+pub use encoded_token::EncodedToken;
+pub use enum_set::{EnumItems, EnumSet};
 pub use error::{ParseError, ParseResult};
+#[cfg(feature = "rust_decimal")]
+pub use external::decimal::Price;
+#[cfg(feature = "ndarray")]
+pub use external::ndarray::{
+    CoercingArray2, ExhaustiveArray2, LenientArray2, SparseArray2, StreamedArray2,
+};
+#[cfg(feature = "semver")]
+pub use external::semver::SortByPrecedence;
+pub use filter_query::{FilterClause, FilterOp, FilterQuery};
+#[cfg(feature = "jiff")]
+pub use fixed_precision::FixedPrecision;
+pub use flag::Flag;
+pub use hex_color::HexColor;
+#[cfg(feature = "jiff")]
+pub use http_date::HttpDate;
+pub use iban::Iban;
+pub use idempotency_key::IdempotencyKey;
+pub use json_patch::{JsonPatch, PatchApplyError, PatchOperation};
+pub use json_pointer::JsonPointer;
+pub use jwt::Jwt;
+pub use language_tag::LanguageTag;
+#[cfg(feature = "jiff")]
+pub use lenient_timestamp::LenientTimestamp;
+pub use locale_bool::{CommonLocaleWords, LocaleBool, LocaleWords};
+pub use mac_address::MacAddress;
 pub use maybe_undefined::MaybeUndefined;
+pub use money::Money;
+pub use non_empty_string::NonEmptyString;
+pub use ordered_set::OrderedSet;
+pub use password::Password;
+pub use phone_number::PhoneNumber;
 use poem::{http::HeaderValue, web::Field as PoemField};
+pub use port::Port;
+pub use postal_code::{
+    Canada, Germany, Japan, PostalCode, PostalFormat, UnitedKingdom, UnitedStates,
+};
+pub use projection::{AnyFields, Projection, ProjectionFields};
+pub use ratio::Ratio;
+pub use regex_pattern::RegexPattern;
+pub use scalar::Scalar;
 use serde_json::Value;
+pub use slug::Slug;
 #[cfg(feature = "email")]
 pub use string_types::Email;
 #[cfg(feature = "hostname")]
 pub use string_types::Hostname;
-pub use string_types::Password;
-
-use crate::registry::{MetaSchemaRef, Registry};
+#[cfg(feature = "jiff")]
+pub use time_series::TimeSeries;
+pub use toggles::{NamedFlags, Toggles};
+#[cfg(feature = "url")]
+pub use webhook_url::{AllowedHostSuffix, WebhookUrl};
+pub use weighted::{Weighted, WeightedList};
+
+use crate::registry::{MetaSchema, MetaSchemaRef, Registry};
+
+/// Creates the items schema for an array of `T`.
+///
+/// If `T` is optional (e.g. `Vec<Option<T>>`), the items schema is marked as
+/// nullable so that the document allows `null` elements.
+pub(crate) fn item_schema_ref<T: Type>() -> MetaSchemaRef {
+    if T::IS_REQUIRED {
+        T::schema_ref()
+    } else {
+        T::schema_ref().merge(MetaSchema {
+            nullable: true,
+            ..MetaSchema::ANY
+        })
+    }
+}
 
 /// Represents an OpenAPI type.
 pub trait Type: Send + Sync {
     /// If it is `true`, it means that this type is required.
     const IS_REQUIRED: bool;
 
+    /// If it is `true`, it means that this type allows an empty parameter
+    /// value.
+    const ALLOW_EMPTY_VALUE: bool = false;
+
     /// The raw type used for validator.
     ///
     /// Usually it is `Self`, but the wrapper type is its internal type.
@@ -85,6 +208,18 @@ pub trait Type: Send + Sync {
 /// Represents a object type.
 pub trait IsObjectType: Type {}
 
+/// Represents a union type with a discriminator.
+///
+/// This trait is implemented by the [`Union`](crate::Union) derive macro when
+/// the `discriminator_name` attribute is specified.
+pub trait TaggedUnion: Type {
+    /// The name of the discriminator property.
+    const DISCRIMINATOR_NAME: &'static str;
+
+    /// Returns the discriminator value of this instance.
+    fn discriminator(&self) -> String;
+}
+
 /// Represents a type that can parsing from JSON.
 pub trait ParseFromJSON: Sized + Type {
     /// Parse from [`serde_json::Value`].
@@ -138,6 +273,17 @@ pub trait ParseFromParameter: Sized + Type {
             None => Err(ParseError::expected_input()),
         }
     }
+
+    /// Parse from `deepObject` style key/value pairs, e.g.
+    /// `name[key]=value`. The keys are the bracketed parts only.
+    fn parse_from_deep_object<'a, I>(_pairs: I) -> ParseResult<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        Err(ParseError::custom(
+            "deepObject style is not supported for this type",
+        ))
+    }
 }
 
 /// Represents a type that can parsing from multipart.
@@ -165,6 +311,15 @@ pub trait ToJSON: Type {
     fn to_json_string(&self) -> String {
         serde_json::to_string(&self.to_json()).unwrap_or_default()
     }
+
+    /// Write this value as JSON to `writer`.
+    ///
+    /// The default implementation serializes the [`Value`] returned by
+    /// [`to_json`](Self::to_json); types holding large amounts of data can
+    /// override it to stream directly without building the intermediate value.
+    fn to_json_writer(&self, writer: &mut dyn std::io::Write) -> std::io::Result<()> {
+        serde_json::to_writer(writer, &self.to_json()).map_err(std::io::Error::other)
+    }
 }
 
 /// Represents a type that can converted to XML value.
@@ -0,0 +1,150 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A monetary amount represented as integer minor units plus an ISO 4217
+/// currency code.
+///
+/// `{"amount": 1099, "currency": "USD"}` represents `$10.99`. The amount must
+/// be an integer and the currency must be a three-letter uppercase code.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Money {
+    /// The amount in minor units of the currency.
+    pub amount: i64,
+    /// The ISO 4217 currency code.
+    pub currency: String,
+}
+
+fn is_valid_currency(currency: &str) -> bool {
+    currency.len() == 3 && currency.bytes().all(|ch| ch.is_ascii_uppercase())
+}
+
+impl Type for Money {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "Money".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            required: vec!["amount", "currency"],
+            properties: vec![
+                ("amount", i64::schema_ref()),
+                ("currency", String::schema_ref()),
+            ],
+            ..MetaSchema::new("object")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Money {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::Object(mut obj) = value {
+            let amount = match obj.remove("amount") {
+                Some(Value::Number(n)) => match n.as_i64() {
+                    Some(amount) => amount,
+                    None => {
+                        return Err(ParseError::custom(
+                            "the amount must be an integer of minor units",
+                        ));
+                    }
+                },
+                _ => return Err(ParseError::custom("missing field `amount`")),
+            };
+            let currency = match obj.remove("currency") {
+                Some(Value::String(currency)) => currency,
+                _ => return Err(ParseError::custom("missing field `currency`")),
+            };
+            if !is_valid_currency(&currency) {
+                return Err(ParseError::custom(format!(
+                    "invalid currency code: {currency}"
+                )));
+            }
+            Ok(Money { amount, currency })
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ToJSON for Money {
+    fn to_json(&self) -> Option<Value> {
+        let mut obj = serde_json::Map::new();
+        obj.insert("amount".to_string(), Value::from(self.amount));
+        obj.insert("currency".to_string(), Value::from(self.currency.clone()));
+        Some(Value::Object(obj))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_from_json() {
+        let money =
+            Money::parse_from_json(Some(json!({"amount": 1099, "currency": "USD"}))).unwrap();
+        assert_eq!(
+            money,
+            Money {
+                amount: 1099,
+                currency: "USD".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let money = Money {
+            amount: -250,
+            currency: "EUR".to_string(),
+        };
+        let value = money.to_json().unwrap();
+        assert_eq!(value, json!({"amount": -250, "currency": "EUR"}));
+        assert_eq!(Money::parse_from_json(Some(value)).unwrap(), money);
+    }
+
+    #[test]
+    fn invalid_currency() {
+        let err =
+            Money::parse_from_json(Some(json!({"amount": 1, "currency": "usd"}))).unwrap_err();
+        assert!(err.into_message().contains("invalid currency code: usd"));
+
+        let err =
+            Money::parse_from_json(Some(json!({"amount": 1, "currency": "DOLLARS"}))).unwrap_err();
+        assert!(err.into_message().contains("invalid currency code"));
+    }
+
+    #[test]
+    fn invalid_amount() {
+        let err =
+            Money::parse_from_json(Some(json!({"amount": 10.99, "currency": "USD"}))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("the amount must be an integer of minor units")
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::web::Field;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+/// A string that is guaranteed to contain at least one non-whitespace
+/// character.
+///
+/// Input is trimmed of surrounding whitespace; empty or whitespace-only
+/// values are rejected. The schema carries `minLength: 1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{NonEmptyString, ParseFromParameter};
+///
+/// let name = NonEmptyString::parse_from_parameter("  alice  ").unwrap();
+/// assert_eq!(name.as_str(), "alice");
+/// assert!(NonEmptyString::parse_from_parameter("   ").is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NonEmptyString(String);
+
+impl NonEmptyString {
+    /// Creates a `NonEmptyString`, returning `None` if the trimmed value is
+    /// empty.
+    pub fn new(value: impl Into<String>) -> Option<Self> {
+        let value = value.into();
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return None;
+        }
+        if trimmed.len() == value.len() {
+            Some(Self(value))
+        } else {
+            Some(Self(trimmed.to_string()))
+        }
+    }
+
+    /// Returns the string as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the inner string.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for NonEmptyString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(value: NonEmptyString) -> Self {
+        value.0
+    }
+}
+
+impl Display for NonEmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn parse_non_empty<T: Type>(value: &str) -> Result<NonEmptyString, ParseError<T>> {
+    NonEmptyString::new(value)
+        .ok_or_else(|| ParseError::custom("the string must not be empty or whitespace-only"))
+}
+
+impl Type for NonEmptyString {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_non-empty".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            min_length: Some(1),
+            ..MetaSchema::new("string")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for NonEmptyString {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_non_empty(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for NonEmptyString {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_non_empty(value)
+    }
+}
+
+impl ParseFromMultipartField for NonEmptyString {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => parse_non_empty(&field.text().await?),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl ToJSON for NonEmptyString {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for NonEmptyString {
+    fn to_header(&self) -> Option<poem::http::HeaderValue> {
+        poem::http::HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_string() {
+        let name = NonEmptyString::parse_from_json(Some(json!("alice"))).unwrap();
+        assert_eq!(name.as_str(), "alice");
+        assert_eq!(name.to_json(), Some(json!("alice")));
+
+        // surrounding whitespace is trimmed away
+        let name = NonEmptyString::parse_from_parameter("  alice  ").unwrap();
+        assert_eq!(name.as_str(), "alice");
+    }
+
+    #[test]
+    fn reject_empty_string() {
+        let err = NonEmptyString::parse_from_json(Some(json!(""))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("the string must not be empty or whitespace-only")
+        );
+    }
+
+    #[test]
+    fn reject_whitespace_only_string() {
+        assert!(NonEmptyString::parse_from_json(Some(json!("  \t "))).is_err());
+        assert!(NonEmptyString::parse_from_parameter(" ").is_err());
+    }
+
+    #[test]
+    fn schema() {
+        let schema_ref = NonEmptyString::schema_ref();
+        let schema = schema_ref.unwrap_inline();
+        assert_eq!(schema.ty, "string");
+        assert_eq!(schema.min_length, Some(1));
+    }
+}
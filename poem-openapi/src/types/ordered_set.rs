@@ -0,0 +1,155 @@
+use std::{
+    borrow::Cow,
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A deduplicated list that preserves the order of first occurrence.
+///
+/// Unlike a sorted or hashed set, `?tags=a,b,a,c` yields `[a, b, c]` in the
+/// order the values were first written. The schema is an array with
+/// `uniqueItems: true`.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{OrderedSet, ParseFromParameter};
+///
+/// let set = OrderedSet::<String>::parse_from_parameters(["a", "b", "a", "c"]).unwrap();
+/// assert_eq!(set.0, vec!["a", "b", "c"]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct OrderedSet<T>(pub Vec<T>);
+
+impl<T> Deref for OrderedSet<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for OrderedSet<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Type> Type for OrderedSet<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = T::RawValueType;
+
+    fn name() -> Cow<'static, str> {
+        format!("ordered_set_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(crate::types::item_schema_ref::<T>())),
+            unique_items: Some(true),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.0.iter().filter_map(|item| item.as_raw_value()))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: ParseFromParameter + PartialEq> ParseFromParameter for OrderedSet<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Self::parse_from_parameters([value])
+    }
+
+    fn parse_from_parameters<I: IntoIterator<Item = A>, A: AsRef<str>>(
+        iter: I,
+    ) -> ParseResult<Self> {
+        let mut items = Vec::new();
+        for value in iter {
+            let item = T::parse_from_parameter(value.as_ref()).map_err(ParseError::propagate)?;
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+        Ok(Self(items))
+    }
+}
+
+impl<T: ParseFromJSON + PartialEq> ParseFromJSON for OrderedSet<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::Array(values) => {
+                let mut items = Vec::new();
+                for value in values {
+                    let item = T::parse_from_json(Some(value)).map_err(ParseError::propagate)?;
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                Ok(Self(items))
+            }
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<T: ToJSON> ToJSON for OrderedSet<T> {
+    fn to_json(&self) -> Option<Value> {
+        self.0.to_json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn first_occurrence_order_is_preserved() {
+        let set = OrderedSet::<String>::parse_from_parameters(["a", "b", "a", "c"]).unwrap();
+        assert_eq!(set.0, vec!["a", "b", "c"]);
+
+        let set = OrderedSet::<i32>::parse_from_json(Some(json!([3, 1, 3, 2, 1]))).unwrap();
+        assert_eq!(set.0, vec![3, 1, 2]);
+        assert_eq!(set.to_json(), Some(json!([3, 1, 2])));
+    }
+
+    #[test]
+    fn schema() {
+        let schema_ref = OrderedSet::<String>::schema_ref();
+        let schema = schema_ref.unwrap_inline();
+        assert_eq!(schema.ty, "array");
+        assert_eq!(schema.unique_items, Some(true));
+    }
+
+    #[test]
+    fn invalid_item_propagates() {
+        assert!(OrderedSet::<i32>::parse_from_parameters(["1", "x"]).is_err());
+        assert!(OrderedSet::<i32>::parse_from_json(Some(json!("a"))).is_err());
+    }
+}
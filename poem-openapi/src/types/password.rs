@@ -0,0 +1,160 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Debug},
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A password type.
+///
+/// The schema is a `string` with format `password` and `writeOnly: true`, so
+/// it can be accepted in requests but is never emitted in responses —
+/// [`ToJSON::to_json`] always returns `None`. The `MIN_LENGTH` parameter
+/// optionally enforces a minimum length on parse.
+///
+/// NOTE: It does not protect the data in the memory.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct Password<const MIN_LENGTH: usize = 0>(pub String);
+
+impl<const MIN_LENGTH: usize> Debug for Password<MIN_LENGTH> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Password").field(&"<redacted>").finish()
+    }
+}
+
+impl<const MIN_LENGTH: usize> Deref for Password<MIN_LENGTH> {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MIN_LENGTH: usize> DerefMut for Password<MIN_LENGTH> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const MIN_LENGTH: usize> AsRef<str> for Password<MIN_LENGTH> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn check_min_length<T: Type>(value: &str, min_length: usize) -> Result<(), ParseError<T>> {
+    if min_length > 0 && value.chars().count() < min_length {
+        return Err(ParseError::custom(format!(
+            "the password must be at least {min_length} characters long"
+        )));
+    }
+    Ok(())
+}
+
+impl<const MIN_LENGTH: usize> Type for Password<MIN_LENGTH> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_password".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            write_only: true,
+            min_length: (MIN_LENGTH > 0).then_some(MIN_LENGTH),
+            ..MetaSchema::new_with_format("string", "password")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<const MIN_LENGTH: usize> ParseFromJSON for Password<MIN_LENGTH> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            check_min_length(&value, MIN_LENGTH)?;
+            Ok(Self(value))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<const MIN_LENGTH: usize> ParseFromParameter for Password<MIN_LENGTH> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        check_min_length(value, MIN_LENGTH)?;
+        Ok(Self(value.to_string()))
+    }
+}
+
+impl<const MIN_LENGTH: usize> ToJSON for Password<MIN_LENGTH> {
+    fn to_json(&self) -> Option<Value> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn write_only_schema() {
+        let schema = Password::<0>::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "string");
+        assert_eq!(meta.format, Some("password"));
+        assert!(meta.write_only);
+        assert_eq!(meta.min_length, None);
+
+        let schema = Password::<8>::schema_ref();
+        assert_eq!(schema.unwrap_inline().min_length, Some(8));
+    }
+
+    #[test]
+    fn redacted_debug() {
+        let password: Password = Password("hunter2".to_string());
+        assert_eq!(format!("{password:?}"), r#"Password("<redacted>")"#);
+    }
+
+    #[test]
+    fn never_serialized() {
+        let password: Password = Password("hunter2".to_string());
+        assert_eq!(password.to_json(), None);
+    }
+
+    #[test]
+    fn min_length() {
+        assert!(Password::<8>::parse_from_json(Some(json!("short"))).is_err());
+        let password = Password::<8>::parse_from_json(Some(json!("long enough"))).unwrap();
+        assert_eq!(password.0, "long enough");
+
+        // the default has no minimum length
+        assert!(Password::<0>::parse_from_json(Some(json!(""))).is_ok());
+    }
+}
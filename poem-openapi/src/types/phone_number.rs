@@ -0,0 +1,152 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// An E.164 phone number: a `+` followed by up to 15 digits.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct PhoneNumber(String);
+
+impl PhoneNumber {
+    /// Create a new phone number, returning `None` if the value is not a
+    /// valid E.164 number.
+    pub fn new(number: impl Into<String>) -> Option<Self> {
+        let number = number.into();
+        if is_valid_e164(&number) {
+            Some(Self(number))
+        } else {
+            None
+        }
+    }
+
+    /// Consumes this object and returns the number as a string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for PhoneNumber {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for PhoneNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn is_valid_e164(number: &str) -> bool {
+    match number.strip_prefix('+') {
+        Some(digits) => {
+            !digits.is_empty()
+                && digits.len() <= 15
+                && digits.bytes().all(|ch| ch.is_ascii_digit())
+                && !digits.starts_with('0')
+        }
+        None => false,
+    }
+}
+
+impl Type for PhoneNumber {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_phone".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^\\+[1-9][0-9]{0,14}$".to_string()),
+            ..MetaSchema::new_with_format("string", "phone")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for PhoneNumber {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            PhoneNumber::new(value.clone())
+                .ok_or_else(|| ParseError::custom(format!("invalid phone number: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for PhoneNumber {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        PhoneNumber::new(value)
+            .ok_or_else(|| ParseError::custom(format!("invalid phone number: {value}")))
+    }
+}
+
+impl ToJSON for PhoneNumber {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for PhoneNumber {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_number() {
+        let number = PhoneNumber::parse_from_json(Some(json!("+14155552671"))).unwrap();
+        assert_eq!(&*number, "+14155552671");
+        assert_eq!(number.to_json(), Some(json!("+14155552671")));
+    }
+
+    #[test]
+    fn reject_invalid_numbers() {
+        for value in [
+            "14155552671",
+            "+",
+            "+1415555abc",
+            "+0123456789",
+            "+1234567890123456",
+            "+1 415 555 2671",
+        ] {
+            assert!(
+                PhoneNumber::parse_from_json(Some(json!(value))).is_err(),
+                "{value:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+};
+
+use poem::{http::HeaderValue, web::Field};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+/// A TCP/UDP port number constrained to `1..=65535`.
+///
+/// Port `0` is rejected because it cannot be bound to explicitly; the schema
+/// carries `minimum: 1` and `maximum: 65535`.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{ParseFromParameter, Port};
+///
+/// assert_eq!(Port::parse_from_parameter("8080").unwrap().as_u16(), 8080);
+/// assert!(Port::parse_from_parameter("0").is_err());
+/// assert!(Port::parse_from_parameter("70000").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Port(u16);
+
+impl Port {
+    /// Creates a port number, returning `None` for port `0`.
+    pub fn new(port: u16) -> Option<Self> {
+        if port > 0 { Some(Self(port)) } else { None }
+    }
+
+    /// The port number.
+    pub fn as_u16(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Port {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Port> for u16 {
+    fn from(port: Port) -> Self {
+        port.0
+    }
+}
+
+fn parse_port<T: Type>(value: i64) -> Result<Port, ParseError<T>> {
+    if !(1..=65535).contains(&value) {
+        return Err(ParseError::custom(format!(
+            "the port number must be between 1 and 65535, but got {value}"
+        )));
+    }
+    Ok(Port(value as u16))
+}
+
+impl Type for Port {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "integer_port".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            minimum: Some(1.0),
+            maximum: Some(65535.0),
+            ..MetaSchema::new_with_format("integer", "int32")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Port {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        let n = value
+            .as_i64()
+            .ok_or_else(|| ParseError::expected_type(value.clone()))?;
+        parse_port(n)
+    }
+}
+
+impl ParseFromParameter for Port {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        let n = value.parse::<i64>().map_err(ParseError::custom)?;
+        parse_port(n)
+    }
+}
+
+impl ParseFromMultipartField for Port {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => Self::parse_from_parameter(&field.text().await?),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl ToJSON for Port {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::from(self.0))
+    }
+}
+
+impl ToHeader for Port {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0.to_string()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_port() {
+        assert_eq!(
+            Port::parse_from_json(Some(json!(443))).unwrap().as_u16(),
+            443
+        );
+        assert_eq!(Port::parse_from_parameter("65535").unwrap().as_u16(), 65535);
+    }
+
+    #[test]
+    fn reject_zero() {
+        let err = Port::parse_from_json(Some(json!(0))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("the port number must be between 1 and 65535, but got 0")
+        );
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        assert!(Port::parse_from_json(Some(json!(70000))).is_err());
+        assert!(Port::parse_from_parameter("70000").is_err());
+        assert!(Port::parse_from_parameter("-1").is_err());
+    }
+
+    #[test]
+    fn schema() {
+        let schema = Port::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "integer");
+        assert_eq!(meta.minimum, Some(1.0));
+        assert_eq!(meta.maximum, Some(65535.0));
+    }
+}
@@ -0,0 +1,334 @@
+use std::{borrow::Cow, fmt::Display, marker::PhantomData, ops::Deref};
+
+use poem::http::HeaderValue;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToHeader, ToJSON, Type},
+};
+
+/// The postal code rules of a single country, used by [`PostalCode`].
+pub trait PostalFormat: Send + Sync {
+    /// ISO 3166-1 alpha-2 country code.
+    const COUNTRY: &'static str;
+
+    /// The regular expression emitted as the schema `pattern`.
+    const PATTERN: &'static str;
+
+    /// Returns `true` if `value` is a valid postal code for this country.
+    ///
+    /// The value has already been trimmed and uppercased.
+    fn is_valid(value: &str) -> bool;
+}
+
+fn all_digits(value: &str) -> bool {
+    !value.is_empty() && value.bytes().all(|ch| ch.is_ascii_digit())
+}
+
+/// United States ZIP codes: `12345` or `12345-6789`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct UnitedStates;
+
+impl PostalFormat for UnitedStates {
+    const COUNTRY: &'static str = "US";
+    const PATTERN: &'static str = "^[0-9]{5}(-[0-9]{4})?$";
+
+    fn is_valid(value: &str) -> bool {
+        match value.split_once('-') {
+            Some((zip, plus4)) => {
+                zip.len() == 5 && all_digits(zip) && plus4.len() == 4 && all_digits(plus4)
+            }
+            None => value.len() == 5 && all_digits(value),
+        }
+    }
+}
+
+/// United Kingdom postcodes: `SW1A 1AA`, `M1 1AE`, `B33 8TH`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct UnitedKingdom;
+
+impl PostalFormat for UnitedKingdom {
+    const COUNTRY: &'static str = "GB";
+    const PATTERN: &'static str = "^[A-Z]{1,2}[0-9][A-Z0-9]? [0-9][A-Z]{2}$";
+
+    fn is_valid(value: &str) -> bool {
+        let Some((outward, inward)) = value.split_once(' ') else {
+            return false;
+        };
+        // inward: digit followed by two letters
+        let inward = inward.as_bytes();
+        if inward.len() != 3
+            || !inward[0].is_ascii_digit()
+            || !inward[1..].iter().all(|ch| ch.is_ascii_uppercase())
+        {
+            return false;
+        }
+        // outward: one or two area letters, a district digit, and an
+        // optional trailing letter or digit
+        let outward = outward.as_bytes();
+        if !(2..=4).contains(&outward.len()) {
+            return false;
+        }
+        let letters = outward
+            .iter()
+            .take_while(|ch| ch.is_ascii_uppercase())
+            .count();
+        if !(1..=2).contains(&letters) {
+            return false;
+        }
+        let rest = &outward[letters..];
+        match rest {
+            [digit] => digit.is_ascii_digit(),
+            [digit, last] => digit.is_ascii_digit() && last.is_ascii_alphanumeric(),
+            _ => false,
+        }
+    }
+}
+
+/// German postal codes: five digits.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Germany;
+
+impl PostalFormat for Germany {
+    const COUNTRY: &'static str = "DE";
+    const PATTERN: &'static str = "^[0-9]{5}$";
+
+    fn is_valid(value: &str) -> bool {
+        value.len() == 5 && all_digits(value)
+    }
+}
+
+/// Canadian postal codes: `A1A 1A1`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Canada;
+
+impl PostalFormat for Canada {
+    const COUNTRY: &'static str = "CA";
+    const PATTERN: &'static str = "^[A-Z][0-9][A-Z] [0-9][A-Z][0-9]$";
+
+    fn is_valid(value: &str) -> bool {
+        let bytes = value.as_bytes();
+        matches!(
+            bytes,
+            [a, b, c, b' ', d, e, f]
+                if a.is_ascii_uppercase()
+                    && b.is_ascii_digit()
+                    && c.is_ascii_uppercase()
+                    && d.is_ascii_digit()
+                    && e.is_ascii_uppercase()
+                    && f.is_ascii_digit()
+        )
+    }
+}
+
+/// Japanese postal codes: `123-4567`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Japan;
+
+impl PostalFormat for Japan {
+    const COUNTRY: &'static str = "JP";
+    const PATTERN: &'static str = "^[0-9]{3}-[0-9]{4}$";
+
+    fn is_valid(value: &str) -> bool {
+        matches!(value.split_once('-'), Some((left, right))
+            if left.len() == 3 && all_digits(left) && right.len() == 4 && all_digits(right))
+    }
+}
+
+/// A postal code validated against the rules of country `C`.
+///
+/// The input is trimmed and uppercased before validation, so `"sw1a 1aa"`
+/// parses to `SW1A 1AA`. The schema carries the country-specific `pattern`.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{ParseFromParameter, PostalCode, UnitedKingdom, UnitedStates};
+///
+/// let zip = PostalCode::<UnitedStates>::parse_from_parameter("90210-1234").unwrap();
+/// assert_eq!(&*zip, "90210-1234");
+/// let postcode = PostalCode::<UnitedKingdom>::parse_from_parameter("sw1a 1aa").unwrap();
+/// assert_eq!(&*postcode, "SW1A 1AA");
+/// assert!(PostalCode::<UnitedStates>::parse_from_parameter("SW1A 1AA").is_err());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct PostalCode<C>(String, PhantomData<C>);
+
+impl<C: PostalFormat> PostalCode<C> {
+    /// Parses `value` as a postal code for country `C`.
+    pub fn new(value: impl AsRef<str>) -> Result<Self, String> {
+        let value = value.as_ref().trim().to_uppercase();
+        if !C::is_valid(&value) {
+            return Err(format!("invalid postal code for {}: `{value}`", C::COUNTRY));
+        }
+        Ok(Self(value, PhantomData))
+    }
+
+    /// Consumes the wrapper and returns the normalized postal code.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<C> Deref for PostalCode<C> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<C> AsRef<str> for PostalCode<C> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<C> Display for PostalCode<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<C: PostalFormat> Type for PostalCode<C> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        format!("string_postal-code-{}", C::COUNTRY.to_ascii_lowercase()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some(C::PATTERN.to_string()),
+            ..MetaSchema::new_with_format("string", "postal-code")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<C: PostalFormat> ParseFromJSON for PostalCode<C> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::String(value) => Self::new(value).map_err(ParseError::custom),
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<C: PostalFormat> ParseFromParameter for PostalCode<C> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Self::new(value).map_err(ParseError::custom)
+    }
+}
+
+impl<C: PostalFormat> ToJSON for PostalCode<C> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl<C: PostalFormat> ToHeader for PostalCode<C> {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn us_zip_codes() {
+        assert_eq!(
+            &*PostalCode::<UnitedStates>::parse_from_parameter("90210").unwrap(),
+            "90210"
+        );
+        assert_eq!(
+            &*PostalCode::<UnitedStates>::parse_from_parameter(" 90210-1234 ").unwrap(),
+            "90210-1234"
+        );
+        for invalid in ["9021", "902101", "90210-123", "9021a", "90210-"] {
+            let err = PostalCode::<UnitedStates>::parse_from_parameter(invalid).unwrap_err();
+            assert!(err.into_message().contains("invalid postal code for US"));
+        }
+    }
+
+    #[test]
+    fn uk_postcodes() {
+        for valid in ["SW1A 1AA", "M1 1AE", "B33 8TH", "EC1A 1BB", "cr2 6xh"] {
+            let code = PostalCode::<UnitedKingdom>::parse_from_parameter(valid).unwrap();
+            assert_eq!(&*code, valid.to_uppercase());
+        }
+        for invalid in ["SW1A1AA", "1A 1AA", "SW1A 1A", "SW1A 1A1", "SW1A  1AA"] {
+            assert!(PostalCode::<UnitedKingdom>::parse_from_parameter(invalid).is_err());
+        }
+    }
+
+    #[test]
+    fn country_mismatch() {
+        // a perfectly good UK postcode is not a US ZIP code, and vice versa
+        let err = PostalCode::<UnitedStates>::parse_from_parameter("SW1A 1AA").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("invalid postal code for US: `SW1A 1AA`")
+        );
+        let err = PostalCode::<UnitedKingdom>::parse_from_parameter("90210").unwrap_err();
+        assert!(err.into_message().contains("invalid postal code for GB"));
+    }
+
+    #[test]
+    fn other_countries() {
+        assert!(PostalCode::<Germany>::parse_from_parameter("10115").is_ok());
+        assert!(PostalCode::<Germany>::parse_from_parameter("1011").is_err());
+        assert_eq!(
+            &*PostalCode::<Canada>::parse_from_parameter("k1a 0b1").unwrap(),
+            "K1A 0B1"
+        );
+        assert!(PostalCode::<Canada>::parse_from_parameter("K1A0B1").is_err());
+        assert!(PostalCode::<Japan>::parse_from_parameter("123-4567").is_ok());
+        assert!(PostalCode::<Japan>::parse_from_parameter("1234567").is_err());
+    }
+
+    #[test]
+    fn schema_carries_country_pattern() {
+        let schema_ref = <PostalCode<UnitedStates> as Type>::schema_ref();
+        let schema = schema_ref.unwrap_inline();
+        assert_eq!(schema.ty, "string");
+        assert_eq!(schema.format, Some("postal-code"));
+        assert_eq!(schema.pattern.as_deref(), Some("^[0-9]{5}(-[0-9]{4})?$"));
+        assert_eq!(
+            <PostalCode<UnitedStates> as Type>::name(),
+            "string_postal-code-us"
+        );
+        assert_ne!(
+            <PostalCode<UnitedKingdom> as Type>::schema_ref()
+                .unwrap_inline()
+                .pattern,
+            schema.pattern
+        );
+    }
+
+    #[test]
+    fn parse_from_json_and_to_json() {
+        let code = PostalCode::<Japan>::parse_from_json(Some(json!("123-4567"))).unwrap();
+        assert_eq!(code.to_json(), Some(json!("123-4567")));
+        assert!(PostalCode::<Japan>::parse_from_json(Some(json!(1234567))).is_err());
+    }
+}
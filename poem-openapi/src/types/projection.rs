@@ -0,0 +1,217 @@
+use std::{borrow::Cow, marker::PhantomData, ops::Deref};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// The allowlist of field names accepted by a [`Projection`].
+pub trait ProjectionFields {
+    /// The allowed field names, or `None` to accept any field name.
+    const FIELDS: Option<&'static [&'static str]> = None;
+}
+
+/// A [`ProjectionFields`] implementation that accepts any field name.
+pub struct AnyFields;
+
+impl ProjectionFields for AnyFields {}
+
+/// A field projection parsed from a comma-separated list, e.g.
+/// `?fields=id,name,email`.
+///
+/// Duplicate names are removed while preserving the order of first occurrence.
+/// With the default [`AnyFields`] parameter any field name is accepted; a
+/// custom [`ProjectionFields`] implementation restricts the list to a known
+/// set of names.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{ParseFromParameter, Projection, ProjectionFields};
+///
+/// struct UserFields;
+///
+/// impl ProjectionFields for UserFields {
+///     const FIELDS: Option<&'static [&'static str]> = Some(&["id", "name", "email"]);
+/// }
+///
+/// let projection = Projection::<UserFields>::parse_from_parameter("id,email").unwrap();
+/// assert!(projection.contains("id"));
+/// assert!(!projection.contains("name"));
+///
+/// assert!(Projection::<UserFields>::parse_from_parameter("id,age").is_err());
+/// ```
+pub struct Projection<T: ProjectionFields = AnyFields> {
+    fields: Vec<String>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: ProjectionFields> std::fmt::Debug for Projection<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Projection").field(&self.fields).finish()
+    }
+}
+
+impl<T: ProjectionFields> Clone for Projection<T> {
+    fn clone(&self) -> Self {
+        Self {
+            fields: self.fields.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: ProjectionFields> PartialEq for Projection<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.fields == other.fields
+    }
+}
+
+impl<T: ProjectionFields> Eq for Projection<T> {}
+
+impl<T: ProjectionFields> Projection<T> {
+    /// Returns `true` if the projection includes the specified field.
+    pub fn contains(&self, field: &str) -> bool {
+        self.fields.iter().any(|name| name == field)
+    }
+
+    /// Consumes this object and returns the field names.
+    pub fn into_fields(self) -> Vec<String> {
+        self.fields
+    }
+}
+
+impl<T: ProjectionFields> Deref for Projection<T> {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.fields
+    }
+}
+
+fn parse_fields<T: ProjectionFields, E: Type>(value: &str) -> Result<Vec<String>, ParseError<E>> {
+    let mut fields = Vec::new();
+    for field in value.split(',') {
+        let field = field.trim();
+        if field.is_empty() {
+            return Err(ParseError::custom("empty field name"));
+        }
+        if let Some(allowed) = T::FIELDS {
+            if !allowed.contains(&field) {
+                return Err(ParseError::custom(format!(
+                    "unknown field \"{}\", expected one of: {}",
+                    field,
+                    allowed.join(", ")
+                )));
+            }
+        }
+        if !fields.iter().any(|name| name == field) {
+            fields.push(field.to_string());
+        }
+    }
+    Ok(fields)
+}
+
+impl<T: ProjectionFields + Send + Sync> Type for Projection<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_projection".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            description: Some("A comma-separated list of field names."),
+            ..MetaSchema::new_with_format("string", "projection")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+impl<T: ProjectionFields + Send + Sync> ParseFromJSON for Projection<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self {
+                fields: parse_fields::<T, Self>(&value)?,
+                _marker: PhantomData,
+            })
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<T: ProjectionFields + Send + Sync> ParseFromParameter for Projection<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(Self {
+            fields: parse_fields::<T, Self>(value)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T: ProjectionFields + Send + Sync> ToJSON for Projection<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.fields.join(",")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct UserFields;
+
+    impl ProjectionFields for UserFields {
+        const FIELDS: Option<&'static [&'static str]> = Some(&["id", "name", "email"]);
+    }
+
+    #[test]
+    fn parse_valid_projection() {
+        let projection = Projection::<UserFields>::parse_from_parameter("id,email,id").unwrap();
+        assert_eq!(&*projection, &["id".to_string(), "email".to_string()]);
+        assert!(projection.contains("id"));
+        assert!(!projection.contains("name"));
+        assert_eq!(
+            projection.to_json(),
+            Some(Value::String("id,email".to_string()))
+        );
+    }
+
+    #[test]
+    fn reject_unknown_field() {
+        let err = Projection::<UserFields>::parse_from_parameter("id,age").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains(r#"unknown field "age", expected one of: id, name, email"#)
+        );
+    }
+
+    #[test]
+    fn any_fields() {
+        let projection = Projection::<AnyFields>::parse_from_parameter("foo,bar").unwrap();
+        assert_eq!(&*projection, &["foo".to_string(), "bar".to_string()]);
+        assert!(Projection::<AnyFields>::parse_from_parameter("foo,,bar").is_err());
+    }
+}
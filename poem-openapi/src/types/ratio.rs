@@ -0,0 +1,105 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A ratio constrained to the range `[0.0, 1.0]`.
+///
+/// Useful for percentage-like fields such as discounts or progress values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct Ratio(pub f64);
+
+impl Type for Ratio {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = f64;
+
+    type RawElementValueType = f64;
+
+    fn name() -> Cow<'static, str> {
+        "ratio".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            minimum: Some(0.0),
+            maximum: Some(1.0),
+            ..MetaSchema::new_with_format("number", "double")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(&self.0)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Ratio {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::Number(n) = &value {
+            let n = n
+                .as_f64()
+                .ok_or_else(|| ParseError::expected_type(value.clone()))?;
+            if !(0.0..=1.0).contains(&n) {
+                return Err(ParseError::custom(format!(
+                    "the ratio must be between 0.0 and 1.0, but got {n}"
+                )));
+            }
+            Ok(Ratio(n))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ToJSON for Ratio {
+    fn to_json(&self) -> Option<Value> {
+        Value::from(self.0).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_in_range() {
+        assert_eq!(
+            Ratio::parse_from_json(Some(json!(0.25))).unwrap(),
+            Ratio(0.25)
+        );
+        assert_eq!(Ratio::parse_from_json(Some(json!(0))).unwrap(), Ratio(0.0));
+        assert_eq!(
+            Ratio::parse_from_json(Some(json!(1.0))).unwrap(),
+            Ratio(1.0)
+        );
+    }
+
+    #[test]
+    fn reject_out_of_range() {
+        assert!(Ratio::parse_from_json(Some(json!(1.5))).is_err());
+        assert!(Ratio::parse_from_json(Some(json!(-0.1))).is_err());
+        assert!(Ratio::parse_from_json(Some(json!("0.5"))).is_err());
+    }
+
+    #[test]
+    fn schema() {
+        let schema = Ratio::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "number");
+        assert_eq!(meta.minimum, Some(0.0));
+        assert_eq!(meta.maximum, Some(1.0));
+    }
+}
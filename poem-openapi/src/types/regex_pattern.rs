@@ -0,0 +1,131 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A regular expression pattern validated at parse time.
+///
+/// The pattern is compiled on parse, so invalid patterns are rejected with the
+/// compile error before they reach a handler. Compilation goes through
+/// [`Regex::new`], which bounds the size of the compiled program, so a hostile
+/// pattern cannot blow up memory.
+#[derive(Debug, Clone)]
+pub struct RegexPattern(Regex);
+
+impl RegexPattern {
+    /// The source pattern.
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Consumes the pattern and returns the compiled [`Regex`].
+    pub fn into_regex(self) -> Regex {
+        self.0
+    }
+}
+
+impl Deref for RegexPattern {
+    type Target = Regex;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq for RegexPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for RegexPattern {}
+
+impl Display for RegexPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Type for RegexPattern {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_regex".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "regex")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for RegexPattern {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(RegexPattern(
+                Regex::new(&value).map_err(ParseError::custom)?,
+            ))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for RegexPattern {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(RegexPattern(Regex::new(value).map_err(ParseError::custom)?))
+    }
+}
+
+impl ToJSON for RegexPattern {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.as_str().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_pattern() {
+        let pattern = RegexPattern::parse_from_json(Some(json!("^[a-z]+$"))).unwrap();
+        assert_eq!(pattern.as_str(), "^[a-z]+$");
+        assert!(pattern.is_match("hello"));
+        assert!(!pattern.is_match("Hello"));
+        assert_eq!(pattern.to_json(), Some(json!("^[a-z]+$")));
+    }
+
+    #[test]
+    fn reject_invalid_pattern() {
+        let err = RegexPattern::parse_from_json(Some(json!("(unclosed"))).unwrap_err();
+        assert!(err.into_message().contains("regex parse error"));
+
+        assert!(RegexPattern::parse_from_json(Some(json!(123))).is_err());
+    }
+}
@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+use serde_json::{Number, Value};
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// Any JSON scalar value (string, number, boolean or null).
+///
+/// Unlike [`Any`](crate::types::Any), arrays and objects are rejected. The
+/// schema is a `oneOf` of the scalar types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Scalar {
+    /// A string value.
+    String(String),
+    /// A number value.
+    Number(Number),
+    /// A boolean value.
+    Bool(bool),
+    /// A null value.
+    Null,
+}
+
+impl Type for Scalar {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "scalar".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            one_of: vec![
+                MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                MetaSchemaRef::Inline(Box::new(MetaSchema::new("number"))),
+                MetaSchemaRef::Inline(Box::new(MetaSchema::new("boolean"))),
+            ],
+            ..MetaSchema::ANY
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self, Scalar::Null)
+    }
+}
+
+impl ParseFromJSON for Scalar {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        match value.unwrap_or_default() {
+            Value::String(value) => Ok(Scalar::String(value)),
+            Value::Number(value) => Ok(Scalar::Number(value)),
+            Value::Bool(value) => Ok(Scalar::Bool(value)),
+            Value::Null => Ok(Scalar::Null),
+            value => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl ToJSON for Scalar {
+    fn to_json(&self) -> Option<Value> {
+        Some(match self {
+            Scalar::String(value) => Value::String(value.clone()),
+            Scalar::Number(value) => Value::Number(value.clone()),
+            Scalar::Bool(value) => Value::Bool(*value),
+            Scalar::Null => Value::Null,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_scalars() {
+        assert_eq!(
+            Scalar::parse_from_json(Some(json!("abc"))).unwrap(),
+            Scalar::String("abc".to_string())
+        );
+        assert_eq!(
+            Scalar::parse_from_json(Some(json!(100))).unwrap(),
+            Scalar::Number(100.into())
+        );
+        assert_eq!(
+            Scalar::parse_from_json(Some(json!(true))).unwrap(),
+            Scalar::Bool(true)
+        );
+        assert_eq!(
+            Scalar::parse_from_json(Some(Value::Null)).unwrap(),
+            Scalar::Null
+        );
+
+        assert!(Scalar::parse_from_json(Some(json!({ "a": 1 }))).is_err());
+        assert!(Scalar::parse_from_json(Some(json!([1, 2, 3]))).is_err());
+    }
+
+    #[test]
+    fn scalar_schema() {
+        let schema_ref = Scalar::schema_ref();
+        let schema = schema_ref.unwrap_inline();
+        assert_eq!(schema.one_of.len(), 3);
+        assert_eq!(schema.one_of[0].unwrap_inline().ty, "string");
+        assert_eq!(schema.one_of[1].unwrap_inline().ty, "number");
+        assert_eq!(schema.one_of[2].unwrap_inline().ty, "boolean");
+    }
+
+    #[test]
+    fn to_json() {
+        assert_eq!(
+            Scalar::String("abc".to_string()).to_json(),
+            Some(json!("abc"))
+        );
+        assert_eq!(Scalar::Number(100.into()).to_json(), Some(json!(100)));
+        assert_eq!(Scalar::Bool(false).to_json(), Some(json!(false)));
+        assert_eq!(Scalar::Null.to_json(), Some(Value::Null));
+    }
+}
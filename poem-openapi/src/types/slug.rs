@@ -0,0 +1,172 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use poem::{http::HeaderValue, web::Field};
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToHeader, ToJSON, Type,
+    },
+};
+
+/// A URL slug.
+///
+/// Slugs match `^[a-z0-9]+(?:-[a-z0-9]+)*$`: one or more groups of lowercase
+/// ASCII letters and digits separated by single hyphens.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Slug(String);
+
+impl Slug {
+    /// Create a new slug, returning `None` if the value is not a valid slug.
+    pub fn new(slug: impl Into<String>) -> Option<Self> {
+        let slug = slug.into();
+        if is_valid_slug(&slug) {
+            Some(Self(slug))
+        } else {
+            None
+        }
+    }
+
+    /// Consumes this object and returns the slug as a string.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for Slug {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for Slug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn is_valid_slug(slug: &str) -> bool {
+    !slug.is_empty()
+        && slug.split('-').all(|group| {
+            !group.is_empty()
+                && group
+                    .bytes()
+                    .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit())
+        })
+}
+
+impl Type for Slug {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_slug".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            pattern: Some("^[a-z0-9]+(?:-[a-z0-9]+)*$".to_string()),
+            ..MetaSchema::new_with_format("string", "slug")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl ParseFromJSON for Slug {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Slug::new(value.clone())
+                .ok_or_else(|| ParseError::custom(format!("invalid slug: {value}")))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ParseFromParameter for Slug {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Slug::new(value).ok_or_else(|| ParseError::custom(format!("invalid slug: {value}")))
+    }
+}
+
+impl ParseFromMultipartField for Slug {
+    async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+        match field {
+            Some(field) => Self::parse_from_parameter(&field.text().await?),
+            None => Err(ParseError::expected_input()),
+        }
+    }
+}
+
+impl ToJSON for Slug {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.0.clone()))
+    }
+}
+
+impl ToHeader for Slug {
+    fn to_header(&self) -> Option<HeaderValue> {
+        HeaderValue::from_str(&self.0).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_valid_slug() {
+        let slug = Slug::parse_from_json(Some(json!("my-first-post-2"))).unwrap();
+        assert_eq!(&*slug, "my-first-post-2");
+        assert_eq!(slug.to_json(), Some(json!("my-first-post-2")));
+    }
+
+    #[test]
+    fn reject_invalid_slugs() {
+        for value in [
+            "",
+            "My-Post",
+            "has space",
+            "-leading",
+            "trailing-",
+            "a--b",
+            "émoji",
+        ] {
+            assert!(
+                Slug::parse_from_json(Some(json!(value))).is_err(),
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn schema() {
+        let schema = Slug::schema_ref();
+        let meta = schema.unwrap_inline();
+        assert_eq!(meta.ty, "string");
+        assert_eq!(meta.pattern.as_deref(), Some("^[a-z0-9]+(?:-[a-z0-9]+)*$"));
+    }
+}
@@ -104,16 +104,6 @@ macro_rules! impl_string_types {
     };
 }
 
-impl_string_types!(
-    /// A password type.
-    ///
-    /// NOTE: Its type is `string` and the format is `password`, and it does not
-    /// protect the data in the memory.
-    Password,
-    "string",
-    "password"
-);
-
 #[cfg(feature = "email")]
 impl_string_types!(
     /// A email address type.
@@ -133,3 +123,51 @@ impl_string_types!(
     "hostname",
     hostname_validator::is_valid
 );
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "email")]
+    #[test]
+    fn email() {
+        use crate::types::{ParseFromJSON, ParseFromParameter, Type};
+
+        assert_eq!(Email::schema_ref().unwrap_inline().format, Some("email"));
+
+        let email =
+            Email::parse_from_json(Some(Value::String("sunli@example.com".to_string()))).unwrap();
+        assert_eq!(email.0, "sunli@example.com");
+
+        assert!(Email::parse_from_json(Some(Value::String("not an email".to_string()))).is_err());
+
+        let email = Email::parse_from_parameter("sunli@example.com").unwrap();
+        assert_eq!(email.0, "sunli@example.com");
+
+        assert!(Email::parse_from_parameter("@example.com").is_err());
+    }
+
+    #[cfg(feature = "hostname")]
+    #[test]
+    fn hostname() {
+        use crate::types::{ParseFromJSON, ParseFromParameter, Type};
+
+        assert_eq!(
+            Hostname::schema_ref().unwrap_inline().format,
+            Some("hostname")
+        );
+
+        let hostname =
+            Hostname::parse_from_json(Some(Value::String("api.example-1.com".to_string())))
+                .unwrap();
+        assert_eq!(hostname.0, "api.example-1.com");
+
+        // bad characters
+        assert!(Hostname::parse_from_parameter("exa_mple.com").is_err());
+        assert!(Hostname::parse_from_parameter("exam ple.com").is_err());
+
+        // too long
+        assert!(Hostname::parse_from_parameter(&"a".repeat(300)).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use jiff::Timestamp;
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseResult, ToJSON, Type},
+};
+
+/// A series of `[timestamp, value]` points.
+///
+/// Each point is a fixed two-element array holding an RFC 3339 timestamp and a
+/// numeric value, e.g. `[["2020-01-01T00:00:00Z", 1.5], ...]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeSeries(pub Vec<(Timestamp, f64)>);
+
+impl Type for TimeSeries {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "time-series".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema {
+                min_items: Some(2),
+                max_items: Some(2),
+                ..MetaSchema::new("array")
+            })))),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl ParseFromJSON for TimeSeries {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::Array(points) = value {
+            let mut series = Vec::with_capacity(points.len());
+            for point in points {
+                let Value::Array(point) = point else {
+                    return Err(ParseError::custom("expected a `[timestamp, value]` point"));
+                };
+                let [timestamp, value] = <[Value; 2]>::try_from(point)
+                    .map_err(|_| ParseError::custom("a point must have exactly two elements"))?;
+                let Value::String(timestamp) = timestamp else {
+                    return Err(ParseError::custom("the timestamp must be a string"));
+                };
+                let timestamp = timestamp.parse::<Timestamp>().map_err(ParseError::custom)?;
+                let Some(value) = value.as_f64() else {
+                    return Err(ParseError::custom("the value must be a number"));
+                };
+                series.push((timestamp, value));
+            }
+            Ok(TimeSeries(series))
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl ToJSON for TimeSeries {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::Array(
+            self.0
+                .iter()
+                .map(|(timestamp, value)| {
+                    Value::Array(vec![
+                        Value::String(timestamp.to_string()),
+                        Value::from(*value),
+                    ])
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_points() {
+        let series = TimeSeries::parse_from_json(Some(json!([
+            ["2020-01-01T00:00:00Z", 1.5],
+            ["2020-01-01T00:01:00Z", 2],
+            ["2020-01-01T00:02:00Z", -0.5],
+        ])))
+        .unwrap();
+        assert_eq!(series.0.len(), 3);
+        assert_eq!(series.0[0].0.to_string(), "2020-01-01T00:00:00Z");
+        assert_eq!(series.0[1].1, 2.0);
+
+        assert_eq!(
+            series.to_json(),
+            Some(json!([
+                ["2020-01-01T00:00:00Z", 1.5],
+                ["2020-01-01T00:01:00Z", 2.0],
+                ["2020-01-01T00:02:00Z", -0.5],
+            ]))
+        );
+    }
+
+    #[test]
+    fn wrong_arity() {
+        let err = TimeSeries::parse_from_json(Some(json!([["2020-01-01T00:00:00Z", 1.5, 3.0]])))
+            .unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("a point must have exactly two elements")
+        );
+
+        let err = TimeSeries::parse_from_json(Some(json!([["2020-01-01T00:00:00Z"]]))).unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("a point must have exactly two elements")
+        );
+    }
+}
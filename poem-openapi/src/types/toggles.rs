@@ -0,0 +1,274 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::{Deref, DerefMut},
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// A struct of named boolean flags that can be filled from a list of names.
+///
+/// Implement this on a struct of `bool` fields and wrap it in [`Toggles`] to
+/// parse `?features=a,c` into `{a: true, b: false, c: true}`.
+pub trait NamedFlags: Default + Send + Sync {
+    /// All accepted flag names; anything else is rejected on parse.
+    const NAMES: &'static [&'static str];
+
+    /// Sets the flag with the given name, which is one of [`NAMES`](Self::NAMES).
+    fn set(&mut self, name: &str);
+
+    /// Returns the flag with the given name, which is one of
+    /// [`NAMES`](Self::NAMES).
+    fn get(&self, name: &str) -> bool;
+}
+
+/// A set of feature toggles parsed from a comma-separated list of names.
+///
+/// Names present in the list become `true`, everything else stays `false`,
+/// and unknown names are rejected. Serializes back to the comma list of
+/// enabled names.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{NamedFlags, ParseFromParameter, Toggles};
+///
+/// #[derive(Debug, Default, Eq, PartialEq)]
+/// struct Features {
+///     search: bool,
+///     export: bool,
+/// }
+///
+/// impl NamedFlags for Features {
+///     const NAMES: &'static [&'static str] = &["search", "export"];
+///
+///     fn set(&mut self, name: &str) {
+///         match name {
+///             "search" => self.search = true,
+///             "export" => self.export = true,
+///             _ => unreachable!(),
+///         }
+///     }
+///
+///     fn get(&self, name: &str) -> bool {
+///         match name {
+///             "search" => self.search,
+///             "export" => self.export,
+///             _ => unreachable!(),
+///         }
+///     }
+/// }
+///
+/// let toggles = Toggles::<Features>::parse_from_parameter("export").unwrap();
+/// assert_eq!(
+///     toggles.0,
+///     Features {
+///         search: false,
+///         export: true
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Toggles<T>(pub T);
+
+impl<T> Deref for Toggles<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Toggles<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: NamedFlags> Display for Toggles<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for name in T::NAMES {
+            if self.0.get(name) {
+                if !first {
+                    f.write_str(",")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_names<'a, T: NamedFlags, U: Type>(
+    names: impl Iterator<Item = &'a str>,
+) -> Result<T, ParseError<U>> {
+    let mut flags = T::default();
+    for name in names {
+        let name = name.trim();
+        if name.is_empty() {
+            continue;
+        }
+        if !T::NAMES.contains(&name) {
+            return Err(ParseError::custom(format!(
+                "unknown flag `{}`, expected one of: {}",
+                name,
+                T::NAMES.join(", ")
+            )));
+        }
+        flags.set(name);
+    }
+    Ok(flags)
+}
+
+impl<T: NamedFlags> Type for Toggles<T> {
+    const IS_REQUIRED: bool = true;
+
+    const ALLOW_EMPTY_VALUE: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_toggles".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            example: Some(Value::String(T::NAMES.join(","))),
+            ..MetaSchema::new_with_format("string", "toggles")
+        }))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<T: NamedFlags> ParseFromJSON for Toggles<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        match value {
+            Value::String(value) => parse_names(value.split(',')).map(Self),
+            Value::Array(values) => {
+                let mut names = Vec::with_capacity(values.len());
+                for value in &values {
+                    match value.as_str() {
+                        Some(name) => names.push(name),
+                        None => return Err(ParseError::custom("expected an array of strings")),
+                    }
+                }
+                parse_names(names.into_iter()).map(Self)
+            }
+            _ => Err(ParseError::expected_type(value)),
+        }
+    }
+}
+
+impl<T: NamedFlags> ParseFromParameter for Toggles<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_names(value.split(',')).map(Self)
+    }
+
+    fn parse_from_parameters<I: IntoIterator<Item = A>, A: AsRef<str>>(
+        iter: I,
+    ) -> ParseResult<Self> {
+        // an absent parameter means no toggles are enabled
+        let mut flags = Self::default();
+        for value in iter {
+            flags = parse_names(value.as_ref().split(',')).map(Self)?;
+        }
+        Ok(flags)
+    }
+}
+
+impl<T: NamedFlags> ToJSON for Toggles<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Default, Eq, PartialEq)]
+    struct Features {
+        a: bool,
+        b: bool,
+        c: bool,
+    }
+
+    impl NamedFlags for Features {
+        const NAMES: &'static [&'static str] = &["a", "b", "c"];
+
+        fn set(&mut self, name: &str) {
+            match name {
+                "a" => self.a = true,
+                "b" => self.b = true,
+                "c" => self.c = true,
+                _ => unreachable!(),
+            }
+        }
+
+        fn get(&self, name: &str) -> bool {
+            match name {
+                "a" => self.a,
+                "b" => self.b,
+                "c" => self.c,
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn partial_set() {
+        let toggles = Toggles::<Features>::parse_from_parameter("a,c").unwrap();
+        assert_eq!(
+            toggles.0,
+            Features {
+                a: true,
+                b: false,
+                c: true
+            }
+        );
+        assert_eq!(toggles.to_json(), Some(json!("a,c")));
+
+        // an empty list leaves everything off
+        let toggles = Toggles::<Features>::parse_from_parameter("").unwrap();
+        assert_eq!(toggles.0, Features::default());
+    }
+
+    #[test]
+    fn reject_unknown_name() {
+        let err = Toggles::<Features>::parse_from_parameter("a,x").unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("unknown flag `x`, expected one of: a, b, c")
+        );
+    }
+
+    #[test]
+    fn parse_from_json_forms() {
+        let toggles = Toggles::<Features>::parse_from_json(Some(json!("b"))).unwrap();
+        assert!(toggles.b);
+        let toggles = Toggles::<Features>::parse_from_json(Some(json!(["a", "b"]))).unwrap();
+        assert!(toggles.a && toggles.b && !toggles.c);
+        assert!(Toggles::<Features>::parse_from_json(Some(json!(1))).is_err());
+    }
+}
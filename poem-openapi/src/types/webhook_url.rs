@@ -0,0 +1,219 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use serde_json::Value;
+use url::Url;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// Restricts the hosts accepted by a [`WebhookUrl`].
+pub trait AllowedHostSuffix: Sync + Send {
+    /// The required host suffix, e.g. `example.com`.
+    ///
+    /// The exact host and any subdomain of it are accepted.
+    const SUFFIX: &'static str;
+}
+
+/// An HTTPS URL whose host must be the configured domain or one of its
+/// subdomains.
+///
+/// Intended for webhook registration endpoints that must not call out to
+/// arbitrary hosts. Non-HTTPS schemes and hosts outside the allowed suffix
+/// are rejected with a message naming the offending part.
+///
+/// # Examples
+///
+/// ```rust
+/// use poem_openapi::types::{AllowedHostSuffix, ParseFromJSON, WebhookUrl};
+///
+/// struct MyDomain;
+///
+/// impl AllowedHostSuffix for MyDomain {
+///     const SUFFIX: &'static str = "example.com";
+/// }
+///
+/// let url =
+///     WebhookUrl::<MyDomain>::parse_from_json(Some("https://hooks.example.com/cb".into()))
+///         .unwrap();
+/// assert_eq!(url.host_str(), Some("hooks.example.com"));
+/// ```
+pub struct WebhookUrl<S> {
+    url: Url,
+    _marker: PhantomData<S>,
+}
+
+// manual impls to avoid requiring the bounds on the marker type
+impl<S> fmt::Debug for WebhookUrl<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WebhookUrl").field(&self.url).finish()
+    }
+}
+
+impl<S> Clone for WebhookUrl<S> {
+    fn clone(&self) -> Self {
+        Self {
+            url: self.url.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S> PartialEq for WebhookUrl<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.url == other.url
+    }
+}
+
+impl<S> Eq for WebhookUrl<S> {}
+
+impl<S> WebhookUrl<S> {
+    /// Consumes the wrapper and returns the validated URL.
+    pub fn into_url(self) -> Url {
+        self.url
+    }
+}
+
+impl<S> Deref for WebhookUrl<S> {
+    type Target = Url;
+
+    fn deref(&self) -> &Self::Target {
+        &self.url
+    }
+}
+
+impl<S> Display for WebhookUrl<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.url.fmt(f)
+    }
+}
+
+fn parse_webhook_url<S: AllowedHostSuffix, T: Type>(value: &str) -> Result<Url, ParseError<T>> {
+    let url: Url = value.parse().map_err(ParseError::custom)?;
+    if url.scheme() != "https" {
+        return Err(ParseError::custom(format!(
+            "webhook URL must use the `https` scheme, not `{}`",
+            url.scheme()
+        )));
+    }
+    let host = url
+        .host_str()
+        .ok_or_else(|| ParseError::custom("webhook URL has no host"))?;
+    // accept the domain itself or any subdomain, but not hosts that merely
+    // end with the same characters (e.g. `badexample.com`)
+    if host != S::SUFFIX && !host.ends_with(&format!(".{}", S::SUFFIX)) {
+        return Err(ParseError::custom(format!(
+            "host `{host}` is not `{}` or one of its subdomains",
+            S::SUFFIX
+        )));
+    }
+    Ok(url)
+}
+
+impl<S: AllowedHostSuffix> Type for WebhookUrl<S> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        "string_webhook-url".into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("string", "url")))
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<S: AllowedHostSuffix> ParseFromJSON for WebhookUrl<S> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            Ok(Self {
+                url: parse_webhook_url::<S, Self>(&value)?,
+                _marker: PhantomData,
+            })
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<S: AllowedHostSuffix> ParseFromParameter for WebhookUrl<S> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        Ok(Self {
+            url: parse_webhook_url::<S, Self>(value)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<S: AllowedHostSuffix> ToJSON for WebhookUrl<S> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.url.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    struct ExampleDomain;
+
+    impl AllowedHostSuffix for ExampleDomain {
+        const SUFFIX: &'static str = "example.com";
+    }
+
+    #[test]
+    fn parse_allowed_host() {
+        let url = WebhookUrl::<ExampleDomain>::parse_from_json(Some(json!(
+            "https://hooks.example.com/cb"
+        )))
+        .unwrap();
+        assert_eq!(url.host_str(), Some("hooks.example.com"));
+        assert_eq!(url.to_json(), Some(json!("https://hooks.example.com/cb")));
+
+        // the bare domain is also accepted
+        assert!(
+            WebhookUrl::<ExampleDomain>::parse_from_parameter("https://example.com/cb").is_ok()
+        );
+    }
+
+    #[test]
+    fn reject_disallowed_urls() {
+        let err = WebhookUrl::<ExampleDomain>::parse_from_json(Some(json!("https://evil.com/cb")))
+            .unwrap_err();
+        assert!(
+            err.into_message()
+                .contains("host `evil.com` is not `example.com`")
+        );
+
+        // a host merely ending with the same characters is not a subdomain
+        assert!(
+            WebhookUrl::<ExampleDomain>::parse_from_parameter("https://badexample.com/cb").is_err()
+        );
+
+        let err = WebhookUrl::<ExampleDomain>::parse_from_parameter("http://hooks.example.com/cb")
+            .unwrap_err();
+        assert!(err.into_message().contains("`https` scheme"));
+    }
+}
@@ -0,0 +1,276 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    ops::Deref,
+};
+
+use serde_json::Value;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseError, ParseFromJSON, ParseFromParameter, ParseResult, ToJSON, Type},
+};
+
+/// An item with a quality weight, mirroring `Accept` header syntax.
+///
+/// Parses `item;q=0.8`; the weight defaults to `1.0` when the `;q=` part is
+/// omitted and must be within `0.0..=1.0`. Serializes back to the same form,
+/// omitting `;q=1`.
+///
+/// Use [`WeightedList`] to parse a whole comma-separated list sorted by
+/// weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weighted<T> {
+    /// The wrapped item.
+    pub item: T,
+    /// The quality weight within `0.0..=1.0`.
+    pub weight: f32,
+}
+
+impl<T> Weighted<T> {
+    /// Creates a `Weighted` with the default weight of `1.0`.
+    pub fn new(item: T) -> Self {
+        Self { item, weight: 1.0 }
+    }
+}
+
+impl<T> Deref for Weighted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.item
+    }
+}
+
+impl<T: Display> Display for Weighted<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.weight == 1.0 {
+            write!(f, "{}", self.item)
+        } else {
+            write!(f, "{};q={}", self.item, self.weight)
+        }
+    }
+}
+
+fn parse_weighted<T: ParseFromParameter, U: Type>(
+    value: &str,
+) -> Result<Weighted<T>, ParseError<U>> {
+    let (item, weight) = match value.split_once(';') {
+        Some((item, params)) => {
+            let weight = params
+                .trim()
+                .strip_prefix("q=")
+                .and_then(|weight| weight.trim().parse::<f32>().ok())
+                .filter(|weight| (0.0..=1.0).contains(weight))
+                .ok_or_else(|| {
+                    ParseError::custom(format!(
+                        "invalid weight in `{value}`, expected `;q=0.0..=1.0`"
+                    ))
+                })?;
+            (item, weight)
+        }
+        None => (value, 1.0),
+    };
+    let item = T::parse_from_parameter(item.trim()).map_err(ParseError::propagate)?;
+    Ok(Weighted { item, weight })
+}
+
+impl<T: Type> Type for Weighted<T> {
+    const IS_REQUIRED: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        format!("weighted_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            example: Some(Value::String("value;q=0.8".to_string())),
+            ..MetaSchema::new_with_format("string", "weighted")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+}
+
+impl<T: ParseFromParameter + Type> ParseFromJSON for Weighted<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_weighted(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<T: ParseFromParameter + Type> ParseFromParameter for Weighted<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_weighted(value)
+    }
+}
+
+impl<T: Display + Type> ToJSON for Weighted<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(self.to_string()))
+    }
+}
+
+/// A comma-separated list of [`Weighted`] items sorted by descending weight.
+///
+/// Parses `a;q=0.8,b,c;q=0.5` into `[b, a;q=0.8, c;q=0.5]`: items without a
+/// `;q=` part default to weight `1.0`, and items with equal weights keep
+/// their written order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedList<T>(pub Vec<Weighted<T>>);
+
+impl<T> Deref for WeightedList<T> {
+    type Target = [Weighted<T>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+fn parse_weighted_list<T: ParseFromParameter, U: Type>(
+    value: &str,
+) -> Result<WeightedList<T>, ParseError<U>> {
+    let mut items = value
+        .split(',')
+        .filter(|item| !item.trim().is_empty())
+        .map(parse_weighted)
+        .collect::<Result<Vec<Weighted<T>>, _>>()?;
+    // stable sort keeps the written order among equal weights
+    items.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+    Ok(WeightedList(items))
+}
+
+impl<T: Type> Type for WeightedList<T> {
+    const IS_REQUIRED: bool = true;
+
+    const ALLOW_EMPTY_VALUE: bool = true;
+
+    type RawValueType = Self;
+
+    type RawElementValueType = Self;
+
+    fn name() -> Cow<'static, str> {
+        format!("weighted_list_{}", T::name()).into()
+    }
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            example: Some(Value::String("a;q=0.8,b;q=0.5".to_string())),
+            ..MetaSchema::new_with_format("string", "weighted-list")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+        Some(self)
+    }
+
+    fn raw_element_iter<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+        Box::new(self.as_raw_value().into_iter())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: ParseFromParameter + Type> ParseFromJSON for WeightedList<T> {
+    fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+        let value = value.unwrap_or_default();
+        if let Value::String(value) = value {
+            parse_weighted_list(&value)
+        } else {
+            Err(ParseError::expected_type(value))
+        }
+    }
+}
+
+impl<T: ParseFromParameter + Type> ParseFromParameter for WeightedList<T> {
+    fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+        parse_weighted_list(value)
+    }
+}
+
+impl<T: Display + Type> ToJSON for WeightedList<T> {
+    fn to_json(&self) -> Option<Value> {
+        Some(Value::String(
+            self.0
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn explicit_weight() {
+        let item = Weighted::<String>::parse_from_parameter("gzip;q=0.8").unwrap();
+        assert_eq!(item.item, "gzip");
+        assert_eq!(item.weight, 0.8);
+        assert_eq!(item.to_json(), Some(json!("gzip;q=0.8")));
+    }
+
+    #[test]
+    fn default_weight() {
+        let item = Weighted::<String>::parse_from_parameter("gzip").unwrap();
+        assert_eq!(item.weight, 1.0);
+        assert_eq!(item.to_json(), Some(json!("gzip")));
+    }
+
+    #[test]
+    fn reject_invalid_weight() {
+        assert!(Weighted::<String>::parse_from_parameter("gzip;q=1.5").is_err());
+        assert!(Weighted::<String>::parse_from_parameter("gzip;weight=1").is_err());
+        let err = Weighted::<String>::parse_from_parameter("gzip;q=x").unwrap_err();
+        assert!(err.into_message().contains("invalid weight"));
+    }
+
+    #[test]
+    fn list_sorted_by_weight() {
+        let list = WeightedList::<String>::parse_from_parameter("a;q=0.8,b,c;q=0.5").unwrap();
+        let items = list
+            .iter()
+            .map(|item| (item.item.as_str(), item.weight))
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![("b", 1.0), ("a", 0.8), ("c", 0.5)]);
+
+        // equal weights keep the written order
+        let list = WeightedList::<String>::parse_from_parameter("x;q=0.5,y;q=0.5").unwrap();
+        assert_eq!(list[0].item, "x");
+        assert_eq!(list[1].item, "y");
+
+        assert_eq!(list.to_json(), Some(json!("x;q=0.5,y;q=0.5")));
+    }
+}
@@ -0,0 +1,34 @@
+use derive_more::Display;
+
+use crate::{
+    registry::MetaSchema,
+    types::Type,
+    validation::{Validator, ValidatorMeta},
+};
+
+#[derive(Display)]
+#[display("maxRows({len})")]
+pub struct MaxRows {
+    len: usize,
+}
+
+impl MaxRows {
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        Self { len }
+    }
+}
+
+impl<E: Type> Validator<ndarray::Array2<E>> for MaxRows {
+    #[inline]
+    fn check(&self, value: &ndarray::Array2<E>) -> bool {
+        value.nrows() <= self.len
+    }
+}
+
+impl ValidatorMeta for MaxRows {
+    fn update_meta(&self, meta: &mut MetaSchema) {
+        // the outer array of a matrix is the rows
+        meta.max_items = Some(self.len);
+    }
+}
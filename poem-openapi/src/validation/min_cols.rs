@@ -0,0 +1,39 @@
+use derive_more::Display;
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::Type,
+    validation::{Validator, ValidatorMeta},
+};
+
+#[derive(Display)]
+#[display("minCols({len})")]
+pub struct MinCols {
+    len: usize,
+}
+
+impl MinCols {
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        Self { len }
+    }
+}
+
+impl<E: Type> Validator<ndarray::Array2<E>> for MinCols {
+    #[inline]
+    fn check(&self, value: &ndarray::Array2<E>) -> bool {
+        value.ncols() >= self.len
+    }
+}
+
+impl ValidatorMeta for MinCols {
+    fn update_meta(&self, meta: &mut MetaSchema) {
+        // the column bound lives on the nested row schema
+        let items = meta
+            .items
+            .get_or_insert_with(|| Box::new(MetaSchemaRef::Inline(Box::new(MetaSchema::ANY))));
+        if let MetaSchemaRef::Inline(schema) = &mut **items {
+            schema.min_items = Some(self.len);
+        }
+    }
+}
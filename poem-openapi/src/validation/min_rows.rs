@@ -0,0 +1,34 @@
+use derive_more::Display;
+
+use crate::{
+    registry::MetaSchema,
+    types::Type,
+    validation::{Validator, ValidatorMeta},
+};
+
+#[derive(Display)]
+#[display("minRows({len})")]
+pub struct MinRows {
+    len: usize,
+}
+
+impl MinRows {
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        Self { len }
+    }
+}
+
+impl<E: Type> Validator<ndarray::Array2<E>> for MinRows {
+    #[inline]
+    fn check(&self, value: &ndarray::Array2<E>) -> bool {
+        value.nrows() >= self.len
+    }
+}
+
+impl ValidatorMeta for MinRows {
+    fn update_meta(&self, meta: &mut MetaSchema) {
+        // the outer array of a matrix is the rows
+        meta.min_items = Some(self.len);
+    }
+}
@@ -1,24 +1,40 @@
 use std::fmt::Display;
 
+#[cfg(feature = "ndarray")]
+mod max_cols;
 mod max_items;
 mod max_length;
 mod max_properties;
+#[cfg(feature = "ndarray")]
+mod max_rows;
 mod maximum;
+#[cfg(feature = "ndarray")]
+mod min_cols;
 mod min_items;
 mod min_length;
 mod min_properties;
+#[cfg(feature = "ndarray")]
+mod min_rows;
 mod minimum;
 mod multiple_of;
 mod pattern;
 mod unique_items;
 
+#[cfg(feature = "ndarray")]
+pub use max_cols::MaxCols;
 pub use max_items::MaxItems;
 pub use max_length::MaxLength;
 pub use max_properties::MaxProperties;
+#[cfg(feature = "ndarray")]
+pub use max_rows::MaxRows;
 pub use maximum::Maximum;
+#[cfg(feature = "ndarray")]
+pub use min_cols::MinCols;
 pub use min_items::MinItems;
 pub use min_length::MinLength;
 pub use min_properties::MinProperties;
+#[cfg(feature = "ndarray")]
+pub use min_rows::MinRows;
 pub use minimum::Minimum;
 pub use multiple_of::MultipleOf;
 pub use pattern::Pattern;
@@ -5,7 +5,7 @@ use poem::{
     web::Data,
 };
 use poem_openapi::{
-    ApiRequest, ApiResponse, Object, OpenApi, OpenApiService, ParameterStyle, Tags,
+    ApiRequest, ApiResponse, Object, OpenApi, OpenApiService, ParameterStyle, RouteTemplate, Tags,
     param::{Path, Query},
     payload::{Binary, Json, Payload, PlainText},
     registry::{MetaApi, MetaExternalDocument, MetaOperation, MetaParamIn, MetaSchema, Registry},
@@ -45,6 +45,98 @@ fn deprecated() {
     assert!(meta.paths[0].operations[0].deprecated);
 }
 
+#[test]
+fn deprecated_replaced_by() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(
+            path = "/abc",
+            method = "get",
+            deprecated(replaced_by = "newOperationId")
+        )]
+        async fn test(&self) {}
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert!(meta.paths[0].operations[0].deprecated);
+    assert_eq!(
+        meta.paths[0].operations[0].replaced_by,
+        Some("newOperationId")
+    );
+}
+
+#[test]
+fn deprecated_since_remove_in() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(
+            path = "/abc",
+            method = "get",
+            deprecated(since = "1.2.0", remove_in = "2.0.0")
+        )]
+        async fn test(&self) {}
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert!(meta.paths[0].operations[0].deprecated);
+    assert_eq!(meta.paths[0].operations[0].deprecated_since, Some("1.2.0"));
+    assert_eq!(
+        meta.paths[0].operations[0].deprecated_remove_in,
+        Some("2.0.0")
+    );
+
+    let spec = OpenApiService::new(Api, "test", "1.0").spec();
+    let spec = serde_json::from_str::<serde_json::Value>(&spec).unwrap();
+    let operation = &spec["paths"]["/abc"]["get"];
+    assert_eq!(operation["deprecated"], serde_json::json!(true));
+    assert_eq!(operation["x-deprecated-since"], serde_json::json!("1.2.0"));
+    assert_eq!(
+        operation["x-deprecated-remove-in"],
+        serde_json::json!("2.0.0")
+    );
+}
+
+#[test]
+fn operation_servers() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(
+            path = "/upload",
+            method = "post",
+            server = "https://upload.example.com"
+        )]
+        async fn upload(&self) {}
+
+        #[oai(path = "/abc", method = "get")]
+        async fn test(&self) {}
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    let operation = meta
+        .paths
+        .iter()
+        .find(|path| path.path == "/upload")
+        .map(|path| &path.operations[0])
+        .unwrap();
+    assert_eq!(operation.servers.len(), 1);
+    assert_eq!(operation.servers[0].url, "https://upload.example.com");
+
+    let spec = OpenApiService::new(Api, "test", "1.0").spec();
+    let spec = serde_json::from_str::<serde_json::Value>(&spec).unwrap();
+    assert_eq!(
+        spec["paths"]["/upload"]["post"]["servers"],
+        serde_json::json!([{ "url": "https://upload.example.com" }])
+    );
+    // operations without the attribute keep using the global servers
+    assert_eq!(spec["paths"]["/abc"]["get"].get("servers"), None);
+}
+
 #[test]
 fn tag() {
     #[derive(Tags)]
@@ -351,6 +443,47 @@ async fn payload_request() {
         .assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
 }
 
+#[tokio::test]
+async fn payload_request_content_type_override() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn test(
+            &self,
+            #[oai(content_type = "application/vnd.myapp.v2+json")] req: Json<i32>,
+        ) {
+            assert_eq!(req.0, 100);
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    let meta_request = meta.paths[0].operations[0].request.as_ref().unwrap();
+    assert_eq!(
+        meta_request.content[0].content_type,
+        "application/vnd.myapp.v2+json"
+    );
+    assert_eq!(meta_request.content[0].schema, i32::schema_ref());
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    cli.post("/")
+        .content_type("application/vnd.myapp.v2+json")
+        .body("100")
+        .send()
+        .await
+        .assert_status_is_ok();
+
+    cli.post("/")
+        .content_type("application/json")
+        .body("100")
+        .send()
+        .await
+        .assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
 #[tokio::test]
 async fn response() {
     const ALREADY_EXISTS_CODE: u16 = 409;
@@ -1084,3 +1217,267 @@ async fn parameter_style_none() {
     let spec = OpenApiService::new(Api {}, "test", "1.0").spec();
     assert!(!spec.contains("\"style\"") && !spec.contains("\"style\": null"));
 }
+
+#[test]
+fn derived_operation_id() {
+    #[allow(dead_code)]
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/users", method = "post")]
+        #[allow(dead_code)]
+        async fn create_user(&self) -> PlainText<String> {
+            PlainText("ok".to_string())
+        }
+
+        #[oai(path = "/users", method = "get", operation_id = "listUsers")]
+        #[allow(dead_code)]
+        async fn list_users(&self) -> PlainText<String> {
+            PlainText("ok".to_string())
+        }
+
+        #[oai(path = "/ping", method = "get", method = "head")]
+        #[allow(dead_code)]
+        async fn ping(&self) -> PlainText<String> {
+            PlainText("ok".to_string())
+        }
+    }
+
+    let meta = &Api::meta()[0];
+    assert_eq!(
+        meta.paths[0].operations[0].operation_id,
+        Some("create_user")
+    );
+    assert_eq!(meta.paths[0].operations[1].operation_id, Some("listUsers"));
+    // a function routed to several methods gets the method appended
+    assert_eq!(meta.paths[1].operations[0].operation_id, Some("ping_get"));
+    assert_eq!(meta.paths[1].operations[1].operation_id, Some("ping_head"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "duplicate operation id: hello")]
+async fn duplicate_operation_id() {
+    struct Api1;
+
+    #[OpenApi]
+    impl Api1 {
+        #[oai(path = "/a", method = "get", operation_id = "hello")]
+        async fn index(&self) -> PlainText<String> {
+            PlainText("a".to_string())
+        }
+    }
+
+    struct Api2;
+
+    #[OpenApi]
+    impl Api2 {
+        #[oai(path = "/b", method = "get", operation_id = "hello")]
+        async fn index(&self) -> PlainText<String> {
+            PlainText("b".to_string())
+        }
+    }
+
+    let _ = TestClient::new(OpenApiService::new((Api1, Api2), "test", "1.0"));
+}
+
+#[tokio::test]
+async fn route_template() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/users/:id", method = "get")]
+        async fn user(&self, Path(id): Path<i32>, template: RouteTemplate) -> PlainText<String> {
+            PlainText(format!("{} {}", template, id))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let resp = TestClient::new(ep).get("/users/7").send().await;
+    resp.assert_status_is_ok();
+    // the template keeps the `{id}` placeholder rather than the concrete path
+    resp.assert_text("/users/{id} 7").await;
+}
+
+#[test]
+fn json_schema_dialect() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn index(&self) {}
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0")
+        .json_schema_dialect("https://spec.openapis.org/oas/3.1/dialect/base");
+    let spec = serde_json::from_str::<serde_json::Value>(&service.spec()).unwrap();
+    assert_eq!(
+        spec["jsonSchemaDialect"],
+        serde_json::json!("https://spec.openapis.org/oas/3.1/dialect/base")
+    );
+
+    // absent unless declared
+    let spec =
+        serde_json::from_str::<serde_json::Value>(&OpenApiService::new(Api, "test", "1.0").spec())
+            .unwrap();
+    assert!(spec.get("jsonSchemaDialect").is_none());
+}
+
+#[test]
+fn info_terms_of_service_and_contact_email() {
+    use poem_openapi::ContactObject;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn index(&self) {}
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0")
+        .try_terms_of_service("https://example.com/terms")
+        .unwrap()
+        .contact(
+            ContactObject::new()
+                .name("support")
+                .try_email("support@example.com")
+                .unwrap(),
+        );
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    assert_eq!(
+        spec["info"]["termsOfService"],
+        serde_json::json!("https://example.com/terms")
+    );
+    assert_eq!(
+        spec["info"]["contact"]["email"],
+        serde_json::json!("support@example.com")
+    );
+
+    // invalid inputs are rejected
+    let err =
+        match OpenApiService::new(Api, "test", "1.0").try_terms_of_service("example.com/terms") {
+            Ok(_) => panic!("expected an invalid URL error"),
+            Err(err) => err,
+        };
+    assert!(err.contains("invalid terms of service URL"));
+    let err = ContactObject::new().try_email("not-an-email").unwrap_err();
+    assert!(err.contains("invalid contact email"));
+    assert!(ContactObject::new().try_email("a@b").is_err());
+}
+
+#[test]
+fn dedup_inline_schemas() {
+    #[derive(Object)]
+    struct Inner {
+        a: i32,
+    }
+
+    #[derive(Object)]
+    struct Outer {
+        #[oai(inline)]
+        x: Inner,
+        #[oai(inline)]
+        y: Inner,
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn index(&self, _req: Json<Outer>) {}
+    }
+
+    // without the flag the identical inline schemas stay inline
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new(Api, "test", "1.0").spec()).unwrap();
+    let outer = &spec["components"]["schemas"]["Outer"]["properties"];
+    assert!(outer["x"].get("$ref").is_none());
+    assert_eq!(outer["x"]["title"], serde_json::json!("Inner"));
+
+    // with the flag they collapse into one shared component with two
+    // references
+    let spec: serde_json::Value = serde_json::from_str(
+        &OpenApiService::new(Api, "test", "1.0")
+            .dedup_inline_schemas()
+            .spec(),
+    )
+    .unwrap();
+    let outer = &spec["components"]["schemas"]["Outer"]["properties"];
+    assert_eq!(
+        outer["x"]["$ref"],
+        serde_json::json!("#/components/schemas/Inner")
+    );
+    assert_eq!(outer["x"]["$ref"], outer["y"]["$ref"]);
+    assert_eq!(
+        spec["components"]["schemas"]["Inner"]["properties"]["a"]["type"],
+        serde_json::json!("integer")
+    );
+}
+
+#[tokio::test]
+async fn register_dyn_schemas() {
+    use std::collections::HashMap;
+
+    use poem::endpoint::BoxEndpoint;
+    use poem_openapi::registry::{MetaSchemaRef, Registry as SchemaRegistry};
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn index(&self) -> PlainText<String> {
+            PlainText("hello".to_string())
+        }
+    }
+
+    // a plugin contributes its schemas through a manual `OpenApi` impl
+    struct Plugin;
+
+    impl OpenApi for Plugin {
+        fn meta() -> Vec<MetaApi> {
+            vec![]
+        }
+
+        fn register(registry: &mut SchemaRegistry) {
+            registry.register_dyn(
+                "PluginEvent",
+                MetaSchema {
+                    properties: vec![(
+                        "payload",
+                        MetaSchemaRef::Reference("PluginPayload".to_string()),
+                    )],
+                    ..MetaSchema::new("object")
+                },
+            );
+            registry.register_dyn("PluginPayload", MetaSchema::new("string"));
+        }
+
+        fn add_routes(
+            self,
+            _route_table: &mut HashMap<String, HashMap<Method, BoxEndpoint<'static>>>,
+        ) {
+        }
+    }
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new((Api, Plugin), "test", "1.0").spec()).unwrap();
+    // no operation references the plugin schemas, but they survive the
+    // unused-schema sweep, including schemas they reference
+    assert_eq!(
+        spec["components"]["schemas"]["PluginEvent"]["type"],
+        serde_json::json!("object")
+    );
+    assert_eq!(
+        spec["components"]["schemas"]["PluginEvent"]["properties"]["payload"]["$ref"],
+        serde_json::json!("#/components/schemas/PluginPayload")
+    );
+    assert_eq!(
+        spec["components"]["schemas"]["PluginPayload"]["type"],
+        serde_json::json!("string")
+    );
+}
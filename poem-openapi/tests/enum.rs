@@ -1,7 +1,7 @@
 use poem_openapi::{
     Enum,
     registry::{MetaExternalDocument, MetaSchemaRef, Registry},
-    types::{ParseFromJSON, ToJSON, Type},
+    types::{Bitmask, ParseFromJSON, ParseFromParameter, ToJSON, Type},
 };
 use serde_json::{Value, json};
 
@@ -99,6 +99,34 @@ fn rename_item() {
     );
 }
 
+#[test]
+fn rename_item_in_parameter_and_schema() {
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    enum OrderStatus {
+        #[oai(rename = "pending_payment")]
+        PendingPayment,
+        #[oai(rename = "shipped")]
+        Shipped,
+    }
+
+    // the wire value round-trips through the parameter path
+    let status = OrderStatus::parse_from_parameter("pending_payment").unwrap();
+    assert_eq!(status, OrderStatus::PendingPayment);
+    assert_eq!(status.to_json(), Some(json!("pending_payment")));
+
+    // the variant name is not accepted
+    assert!(OrderStatus::parse_from_parameter("PendingPayment").is_err());
+
+    // the schema `enum` lists the renamed values
+    let mut registry = Registry::new();
+    OrderStatus::register(&mut registry);
+    let meta = registry.schemas.remove("OrderStatus").unwrap();
+    assert_eq!(
+        meta.enum_items,
+        vec![json!("pending_payment"), json!("shipped")]
+    );
+}
+
 #[test]
 #[should_panic]
 fn duplicate_name() {
@@ -221,3 +249,125 @@ async fn external_docs() {
         })
     );
 }
+
+#[test]
+fn bitmask() {
+    #[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+    #[oai(bitmask)]
+    enum Permission {
+        Read = 1,
+        Write = 2,
+        Delete = 4,
+    }
+
+    // integer representation
+    assert_eq!(
+        Bitmask::<Permission>::schema_ref().unwrap_inline().ty,
+        "integer"
+    );
+
+    let value = Bitmask::<Permission>::parse_from_json(Some(json!(5))).unwrap();
+    assert_eq!(value.0, vec![Permission::Read, Permission::Delete]);
+    assert_eq!(value.to_json(), Some(json!(5)));
+
+    assert!(Bitmask::<Permission>::parse_from_json(Some(json!(8))).is_err());
+    assert!(Bitmask::<Permission>::parse_from_json(Some(json!("abc"))).is_err());
+
+    // array representation
+    assert_eq!(Vec::<Permission>::schema_ref().unwrap_inline().ty, "array");
+
+    let value = Vec::<Permission>::parse_from_json(Some(json!(["Read", "Write"]))).unwrap();
+    assert_eq!(value, vec![Permission::Read, Permission::Write]);
+    assert_eq!(value.to_json(), Some(json!(["Read", "Write"])));
+}
+
+#[test]
+fn derive_max_length() {
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(derive_max_length)]
+    enum MyEnum {
+        CreateUser,
+        Delete,
+    }
+
+    let mut registry = Registry::new();
+    MyEnum::register(&mut registry);
+    let meta = registry.schemas.remove("MyEnum").unwrap();
+    assert_eq!(meta.max_length, Some("CreateUser".len()));
+
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "snake_case", derive_max_length)]
+    enum Renamed {
+        CreateUser,
+        Delete,
+    }
+
+    let mut registry = Registry::new();
+    Renamed::register(&mut registry);
+    let meta = registry.schemas.remove("Renamed").unwrap();
+    assert_eq!(meta.max_length, Some("create_user".len()));
+}
+
+#[test]
+fn other() {
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "lowercase")]
+    enum Status {
+        Active,
+        Inactive,
+        #[oai(other)]
+        Unknown(String),
+    }
+
+    assert_eq!(
+        Status::parse_from_json(Some(json!("active"))).unwrap(),
+        Status::Active
+    );
+    assert_eq!(
+        Status::parse_from_json(Some(json!("archived"))).unwrap(),
+        Status::Unknown("archived".to_string())
+    );
+    assert_eq!(
+        Status::parse_from_parameter("archived").unwrap(),
+        Status::Unknown("archived".to_string())
+    );
+
+    assert_eq!(Status::Active.to_json(), Some(json!("active")));
+    assert_eq!(
+        Status::Unknown("archived".to_string()).to_json(),
+        Some(json!("archived"))
+    );
+
+    // the catch-all variant is not part of the schema
+    let mut registry = Registry::new();
+    Status::register(&mut registry);
+    let meta = registry.schemas.remove("Status").unwrap();
+    assert_eq!(meta.enum_items, vec![json!("active"), json!("inactive")]);
+}
+
+#[test]
+fn enum_items_follow_declaration_order() {
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "lowercase")]
+    enum Priority {
+        Urgent,
+        High,
+        Normal,
+        Low,
+    }
+
+    // deliberately not alphabetical: the schema must list variants in source
+    // declaration order, not sorted or hash order
+    let mut registry = Registry::new();
+    Priority::register(&mut registry);
+    let meta = registry.schemas.remove("Priority").unwrap();
+    assert_eq!(
+        meta.enum_items,
+        vec![
+            json!("urgent"),
+            json!("high"),
+            json!("normal"),
+            json!("low")
+        ]
+    );
+}
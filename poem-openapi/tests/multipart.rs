@@ -549,3 +549,120 @@ async fn deny_unknown_fields() {
     .unwrap_err();
     assert_eq!(err.to_string(), "parse multipart error: unknown field `c`");
 }
+
+#[tokio::test]
+async fn streaming_fields_in_order() {
+    let data = create_multipart_payload(&[
+        ("first", None, b"1"),
+        ("second", None, b"2"),
+        ("third", Some("3.txt"), b"3"),
+    ]);
+    let mut multipart = poem_openapi::payload::Multipart::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap();
+
+    let mut fields = Vec::new();
+    while let Some(field) = multipart.next_field().await.unwrap() {
+        fields.push((
+            field.name().unwrap().to_string(),
+            field.text().await.unwrap(),
+        ));
+    }
+
+    assert_eq!(
+        fields,
+        vec![
+            ("first".to_string(), "1".to_string()),
+            ("second".to_string(), "2".to_string()),
+            ("third".to_string(), "3".to_string()),
+        ]
+    );
+
+    assert_eq!(
+        poem_openapi::payload::Multipart::schema_ref()
+            .unwrap_inline()
+            .ty,
+        "object"
+    );
+}
+
+#[tokio::test]
+async fn streaming_field_size_limit() {
+    let data = create_multipart_payload(&[
+        ("small", None, b"1234"),
+        ("large", Some("big.bin"), &[0u8; 8192]),
+    ]);
+    let multipart = poem_openapi::payload::Multipart::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap();
+    let mut multipart = multipart.field_size_limit("large", 1024);
+
+    // fields within their cap succeed
+    let field = multipart.next_field().await.unwrap().unwrap();
+    assert_eq!(field.name(), Some("small"));
+    assert_eq!(field.bytes().await.unwrap(), b"1234");
+
+    // the oversized field is rejected mid-stream with 413
+    let field = multipart.next_field().await.unwrap().unwrap();
+    assert_eq!(field.name(), Some("large"));
+    let err = field.bytes().await.unwrap_err();
+    assert_eq!(
+        err.into_response().status(),
+        poem::http::StatusCode::PAYLOAD_TOO_LARGE
+    );
+}
+
+#[cfg(feature = "ndarray")]
+#[tokio::test]
+async fn csv_grid_field() {
+    #[derive(Multipart, Debug, PartialEq)]
+    struct A {
+        matrix: ndarray::Array2<f64>,
+    }
+
+    let data = create_multipart_payload(&[("matrix", None, b"1,2,3\n4,5,6")]);
+    let a = A::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(a.matrix.shape(), &[2, 3]);
+    assert_eq!(a.matrix, ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+}
+
+#[tokio::test]
+async fn missing_field_error_names_field() {
+    #[derive(Multipart, Debug, Eq, PartialEq)]
+    struct A {
+        name: String,
+        created_at: String,
+    }
+
+    let data = create_multipart_payload(&[("name", None, b"abc")]);
+    let err = A::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "parse multipart error: field `created_at` is required"
+    );
+}
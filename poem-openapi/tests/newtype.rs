@@ -81,3 +81,79 @@ async fn rename_new_type_using_const() {
 
     assert_eq!(TypeA::name(), NEW_NAME);
 }
+
+#[tokio::test]
+async fn inherits_inner_schema_metadata() {
+    use std::borrow::Cow;
+
+    use poem_openapi::registry::{MetaSchema, MetaSchemaRef};
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    struct Inner(String);
+
+    impl Type for Inner {
+        const IS_REQUIRED: bool = true;
+
+        type RawValueType = Self;
+
+        type RawElementValueType = Self;
+
+        fn name() -> Cow<'static, str> {
+            "inner".into()
+        }
+
+        fn schema_ref() -> MetaSchemaRef {
+            MetaSchemaRef::Inline(Box::new(MetaSchema {
+                description: Some("A documented type"),
+                example: Some("abc".into()),
+                ..MetaSchema::new_with_format("string", "inner")
+            }))
+        }
+
+        fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+            Some(self)
+        }
+
+        fn raw_element_iter<'a>(
+            &'a self,
+        ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+            Box::new(self.as_raw_value().into_iter())
+        }
+    }
+
+    #[derive(NewType)]
+    #[oai(
+        from_json = false,
+        from_parameter = false,
+        from_multipart = false,
+        to_json = false,
+        to_header = false
+    )]
+    struct Wrapper(Inner);
+
+    // without overrides the inner metadata is retained
+    let schema = Wrapper::schema_ref();
+    let schema = schema.unwrap_inline();
+    assert_eq!(schema.description, Some("A documented type"));
+    assert_eq!(schema.example, Some("abc".into()));
+    assert_eq!(schema.format, Some("inner"));
+
+    /// Overridden
+    #[derive(NewType)]
+    #[oai(
+        from_json = false,
+        from_parameter = false,
+        from_multipart = false,
+        to_json = false,
+        to_header = false
+    )]
+    struct DocumentedWrapper(Inner);
+
+    // adding a summary still keeps the inner description, example and format
+    let schema = DocumentedWrapper::schema_ref();
+    let schema = schema.unwrap_inline();
+    assert_eq!(schema.title.as_deref(), Some("Overridden"));
+    assert_eq!(schema.description, Some("A documented type"));
+    assert_eq!(schema.example, Some("abc".into()));
+    assert_eq!(schema.format, Some("inner"));
+}
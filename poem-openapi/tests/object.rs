@@ -5,7 +5,7 @@ use poem_openapi::{
     registry::{MetaExternalDocument, MetaSchema, MetaSchemaRef, Registry},
     types::{Example, ParseFromJSON, ToJSON, Type},
 };
-use serde_json::json;
+use serde_json::{Value, json};
 
 fn get_meta<T: Type>() -> MetaSchema {
     let mut registry = Registry::new();
@@ -202,6 +202,21 @@ fn field_deprecated() {
     assert_eq!(meta.properties[0].1.unwrap_inline().deprecated, true);
 }
 
+#[test]
+fn field_deprecated_since_remove_in() {
+    #[derive(Object)]
+    struct Obj {
+        #[oai(deprecated(since = "1.2.0", remove_in = "2.0.0"))]
+        a: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    let field_schema = meta.properties[0].1.unwrap_inline();
+    assert_eq!(field_schema.deprecated, true);
+    assert_eq!(field_schema.deprecated_since, Some("1.2.0"));
+    assert_eq!(field_schema.deprecated_remove_in, Some("2.0.0"));
+}
+
 #[test]
 fn recursive_register() {
     #[derive(Object)]
@@ -397,6 +412,70 @@ fn read_only() {
     );
 }
 
+#[test]
+fn read_only_with_flatten() {
+    #[derive(Debug, Object, PartialEq)]
+    struct Base {
+        #[oai(read_only)]
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Debug, Object, PartialEq)]
+    struct Composed {
+        #[oai(flatten)]
+        base: Base,
+        extra: i32,
+    }
+
+    let meta = get_meta::<Composed>();
+    assert_eq!(meta.properties[0].0, "id");
+    assert!(meta.properties[0].1.unwrap_inline().read_only);
+
+    // the read-only required field of the base may be absent on input
+    assert_eq!(
+        Composed::parse_from_json(Some(serde_json::json!({
+            "name": "a",
+            "extra": 100,
+        })))
+        .unwrap(),
+        Composed {
+            base: Base {
+                id: 0,
+                name: "a".to_string()
+            },
+            extra: 100,
+        }
+    );
+
+    assert_eq!(
+        Composed {
+            base: Base {
+                id: 99,
+                name: "a".to_string()
+            },
+            extra: 100,
+        }
+        .to_json(),
+        Some(serde_json::json!({
+            "id": 99,
+            "name": "a",
+            "extra": 100,
+        }))
+    );
+
+    assert!(
+        Composed::parse_from_json(Some(serde_json::json!({
+            "id": 99,
+            "name": "a",
+            "extra": 100,
+        })))
+        .unwrap_err()
+        .into_message()
+        .contains("properties `id` is read only.")
+    );
+}
+
 #[cfg(feature = "time")]
 #[test]
 fn read_only_with_default() {
@@ -1132,3 +1211,396 @@ fn deserialize_with() {
         Obj { a: 7 }
     );
 }
+
+#[test]
+fn nullable_array_items() {
+    // `Vec<Option<T>>` must allow `null` elements in the items schema (the
+    // document is emitted as OpenAPI 3.0, where this is expressed with
+    // `nullable`)
+    let schema = <Vec<Option<i32>> as Type>::schema_ref();
+    let meta = schema.unwrap_inline();
+    assert_eq!(meta.ty, "array");
+    let items = meta.items.as_ref().unwrap().unwrap_inline();
+    assert!(items.nullable);
+    assert_eq!(items.ty, "integer");
+
+    // a non-optional element type stays non-nullable
+    let schema = <Vec<i32> as Type>::schema_ref();
+    let items = schema
+        .unwrap_inline()
+        .items
+        .as_ref()
+        .unwrap()
+        .unwrap_inline();
+    assert!(!items.nullable);
+}
+
+#[tokio::test]
+async fn object_default_for_empty_body() {
+    use poem::test::TestClient;
+    use poem_openapi::{OpenApiService, payload::Json};
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    #[oai(default)]
+    struct Obj {
+        a: i32,
+        b: String,
+    }
+
+    impl Default for Obj {
+        fn default() -> Self {
+            Self {
+                a: 100,
+                b: "abc".to_string(),
+            }
+        }
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.default, Some(json!({ "a": 100, "b": "abc" })));
+
+    assert_eq!(Obj::parse_from_json(None).unwrap(), Obj::default());
+    assert_eq!(
+        Obj::parse_from_json(Some(json!(null))).unwrap(),
+        Obj::default()
+    );
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn index(&self, value: Json<Obj>) -> Json<i32> {
+            assert_eq!(value.0, Obj::default());
+            Json(value.0.a)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .post("/")
+        .content_type("application/json")
+        .body(())
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_text("100").await;
+}
+
+#[test]
+fn inline_attribute() {
+    #[derive(Object)]
+    struct Inner {
+        value: i32,
+    }
+
+    #[derive(Object)]
+    struct Obj {
+        #[oai(inline)]
+        inner: Inner,
+    }
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+
+    // the helper type is inlined instead of registered as a component
+    assert!(!registry.schemas.contains_key("Inner"));
+
+    let meta = registry.schemas.remove("Obj").unwrap();
+    let (name, schema_ref) = &meta.properties[0];
+    assert_eq!(*name, "inner");
+    let schema = schema_ref.unwrap_inline();
+    assert_eq!(schema.ty, "object");
+    assert_eq!(schema.properties[0].0, "value");
+}
+
+#[test]
+fn title_defaults_to_type_name() {
+    #[derive(Object)]
+    struct Untitled {
+        a: i32,
+    }
+
+    #[derive(Object)]
+    #[oai(title = "Custom title")]
+    struct Titled {
+        a: i32,
+    }
+
+    let mut registry = Registry::new();
+    Untitled::register(&mut registry);
+    Titled::register(&mut registry);
+
+    let meta = registry.schemas.remove("Untitled").unwrap();
+    assert_eq!(meta.title, Some("Untitled".to_string()));
+
+    let meta = registry.schemas.remove("Titled").unwrap();
+    assert_eq!(meta.title, Some("Custom title".to_string()));
+}
+
+#[test]
+fn flatten_map_captures_unknown_fields() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        a: i32,
+        /// All properties not matched by a named field.
+        #[oai(flatten)]
+        extra: HashMap<String, Value>,
+    }
+
+    let obj = Obj::parse_from_json(Some(json!({
+        "a": 100,
+        "x": "abc",
+        "y": [1, 2, 3],
+    })))
+    .unwrap();
+    assert_eq!(obj.a, 100);
+    assert_eq!(obj.extra.get("x"), Some(&json!("abc")));
+    assert_eq!(obj.extra.get("y"), Some(&json!([1, 2, 3])));
+    // named fields are not duplicated into the map
+    assert!(!obj.extra.contains_key("a"));
+
+    // the captured fields serialize back at the top level
+    assert_eq!(
+        obj.to_json(),
+        Some(json!({
+            "a": 100,
+            "x": "abc",
+            "y": [1, 2, 3],
+        }))
+    );
+}
+
+#[test]
+fn example_from_default() {
+    #[derive(Object, Default)]
+    #[oai(example_from_default)]
+    struct Obj {
+        a: i32,
+        b: String,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.example, Some(json!({ "a": 0, "b": "" })));
+}
+
+#[test]
+fn numbers_as_strings() {
+    #[derive(Debug, Object)]
+    #[oai(numbers_as_strings)]
+    struct Obj {
+        a: i32,
+        b: f64,
+        c: String,
+        d: Vec<u64>,
+        e: Option<i32>,
+    }
+
+    // all numeric values serialize as strings, including nested ones
+    assert_eq!(
+        Obj {
+            a: 1,
+            b: 2.5,
+            c: "x".to_string(),
+            d: vec![3, 4],
+            e: Some(5),
+        }
+        .to_json(),
+        Some(json!({
+            "a": "1",
+            "b": "2.5",
+            "c": "x",
+            "d": ["3", "4"],
+            "e": "5",
+        }))
+    );
+
+    // parsing still accepts numbers
+    let obj = Obj::parse_from_json(Some(json!({
+        "a": 1,
+        "b": 2.5,
+        "c": "x",
+        "d": [3, 4],
+    })))
+    .unwrap();
+    assert_eq!(obj.a, 1);
+    assert_eq!(obj.d, vec![3, 4]);
+}
+
+#[cfg(all(feature = "chrono", feature = "jiff"))]
+#[test]
+fn chrono_and_jiff_coexist() {
+    // both date-time ecosystems can be mixed in one object during a migration
+    #[derive(Debug, Object)]
+    struct Obj {
+        chrono_ts: chrono::DateTime<chrono::Utc>,
+        jiff_ts: jiff::Timestamp,
+    }
+
+    let obj = Obj::parse_from_json(Some(json!({
+        "chrono_ts": "2015-09-18T23:56:04+00:00",
+        "jiff_ts": "2015-09-18T23:56:04Z",
+    })))
+    .unwrap();
+    assert_eq!(obj.chrono_ts.timestamp(), obj.jiff_ts.as_second());
+}
+
+#[test]
+fn schema_comment() {
+    /// Obj
+    #[derive(Object)]
+    #[oai(comment = "kept in sync with the billing service")]
+    struct Obj {
+        /// The invoice number.
+        #[oai(comment = "generated by the ERP, do not parse")]
+        number: String,
+        amount: i64,
+    }
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+    let meta = registry.schemas.remove("Obj").unwrap();
+    assert_eq!(meta.comment, Some("kept in sync with the billing service"));
+
+    assert_eq!(meta.properties[0].0, "number");
+    assert_eq!(
+        meta.properties[0].1.unwrap_inline().comment,
+        Some("generated by the ERP, do not parse")
+    );
+    assert_eq!(meta.properties[1].1.unwrap_inline().comment, None);
+
+    // `$comment` is the serialized keyword
+    let value = serde_json::to_value(&meta).unwrap();
+    assert_eq!(
+        value["$comment"],
+        json!("kept in sync with the billing service")
+    );
+    assert_eq!(
+        value["properties"]["number"]["$comment"],
+        json!("generated by the ERP, do not parse")
+    );
+}
+
+#[test]
+fn flatten_union_field() {
+    use poem_openapi::Union;
+
+    #[derive(Object, Debug, PartialEq)]
+    struct A {
+        a: i32,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct B {
+        b: String,
+    }
+
+    #[derive(Union, Debug, PartialEq)]
+    #[oai(one_of)]
+    enum Inner {
+        A(A),
+        B(B),
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Obj {
+        #[oai(flatten)]
+        inner: Inner,
+        c: i32,
+    }
+
+    // the union's `oneOf` is carried into the parent through `allOf`
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.properties.len(), 1);
+    assert_eq!(meta.properties[0].0, "c");
+    assert_eq!(meta.all_of.len(), 1);
+    let union_schema = meta.all_of[0].unwrap_inline();
+    assert_eq!(union_schema.one_of.len(), 2);
+
+    let obj = Obj {
+        inner: Inner::A(A { a: 100 }),
+        c: 300,
+    };
+    assert_eq!(obj.to_json(), Some(json!({"a": 100, "c": 300})));
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({"a": 100, "c": 300}))).unwrap(),
+        obj
+    );
+
+    let obj = Obj {
+        inner: Inner::B(B {
+            b: "hello".to_string(),
+        }),
+        c: 1,
+    };
+    assert_eq!(Obj::parse_from_json(obj.to_json()).unwrap(), obj);
+}
+
+#[test]
+fn output_transforms() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Obj {
+        #[oai(to_uppercase)]
+        code: String,
+        #[oai(to_lowercase, trim)]
+        email: String,
+        plain: String,
+    }
+
+    // input is preserved as-is
+    let obj = Obj::parse_from_json(Some(json!({
+        "code": "abc",
+        "email": "  User@Example.COM ",
+        "plain": "MiXeD",
+    })))
+    .unwrap();
+    assert_eq!(obj.code, "abc");
+    assert_eq!(obj.email, "  User@Example.COM ");
+
+    // output is canonicalized
+    assert_eq!(
+        obj.to_json(),
+        Some(json!({
+            "code": "ABC",
+            "email": "user@example.com",
+            "plain": "MiXeD",
+        }))
+    );
+}
+
+#[test]
+fn required_if_feature() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Obj {
+        a: i32,
+        #[oai(required_if(feature = "strict"))]
+        b: Option<String>,
+    }
+
+    let meta = get_meta::<Obj>();
+    let value = json!({ "a": 1 });
+
+    if cfg!(feature = "strict") {
+        // strict builds promote `b` to required
+        assert_eq!(meta.required, vec!["a", "b"]);
+        let err = Obj::parse_from_json(Some(value)).unwrap_err();
+        assert!(err.into_message().contains("field `b` is required"));
+        assert!(Obj::parse_from_json(Some(json!({ "a": 1, "b": null }))).is_err());
+    } else {
+        assert_eq!(meta.required, vec!["a"]);
+        let obj = Obj::parse_from_json(Some(value)).unwrap();
+        assert_eq!(obj, Obj { a: 1, b: None });
+    }
+
+    // present values parse the same way in both builds
+    let obj = Obj::parse_from_json(Some(json!({ "a": 1, "b": "x" }))).unwrap();
+    assert_eq!(
+        obj,
+        Obj {
+            a: 1,
+            b: Some("x".to_string())
+        }
+    );
+}
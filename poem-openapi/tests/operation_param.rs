@@ -9,7 +9,7 @@ use poem_openapi::param::{Cookie as ParamCookie, CookiePrivate, CookieSigned};
 use poem_openapi::{
     OpenApi, OpenApiService,
     param::{Header, Path, Query},
-    payload::Json,
+    payload::{Json, PlainText},
     registry::{MetaApi, MetaParamIn, MetaSchema, MetaSchemaRef},
     types::Type,
 };
@@ -297,6 +297,61 @@ async fn header_multiple_values() {
         .assert_status_is_ok();
 }
 
+#[tokio::test]
+async fn header_explode_false() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, #[oai(name = "X-Tags", explode = false)] tags: Header<Vec<String>>) {
+            assert_eq!(tags.0, vec!["a", "b", "c"]);
+        }
+    }
+
+    let api = OpenApiService::new(Api, "test", "1.0");
+    TestClient::new(api)
+        .get("/")
+        .header("X-Tags", "a, b,c")
+        .send()
+        .await
+        .assert_status_is_ok();
+}
+
+#[tokio::test]
+async fn header_explode_false_absent() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, #[oai(name = "X-Tags", explode = false)] tags: Header<Vec<String>>) {
+            assert!(tags.0.is_empty());
+        }
+
+        #[oai(path = "/required", method = "get")]
+        async fn test_required(
+            &self,
+            #[oai(name = "X-Value", explode = false)] _value: Header<String>,
+        ) {
+        }
+    }
+
+    use poem::http::StatusCode;
+
+    let api = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(api);
+
+    // an absent header parses as an empty list
+    cli.get("/").send().await.assert_status_is_ok();
+
+    // a required scalar still reports a parse error instead of panicking
+    cli.get("/required")
+        .send()
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn header_default() {
     struct Api;
@@ -366,6 +421,37 @@ async fn path() {
         .assert_status_is_ok();
 }
 
+#[tokio::test]
+async fn path_default() {
+    fn default_path() -> String {
+        "latest".to_string()
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/k/*v", method = "get")]
+        async fn test(
+            &self,
+            #[oai(default = "default_path")] v: Path<String>,
+        ) -> PlainText<String> {
+            PlainText(v.0)
+        }
+    }
+
+    let api = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(api);
+
+    let resp = cli.get("/k/10").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("10").await;
+
+    let resp = cli.get("/k/").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("latest").await;
+}
+
 #[cfg(feature = "cookie")]
 #[tokio::test]
 async fn cookie() {
@@ -776,3 +862,209 @@ async fn cookie_rename() {
         .await
         .assert_status_is_ok();
 }
+
+#[tokio::test]
+async fn idempotency_key() {
+    use poem::http::StatusCode;
+    use poem_openapi::types::IdempotencyKey;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "post")]
+        async fn create(&self, #[oai(name = "Idempotency-Key")] key: Header<IdempotencyKey>) {
+            assert_eq!(&*key.0, "8e03978e-40d5-43e8-bc93-6894a57f9324");
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(
+        meta.paths[0].operations[0].params[0].name,
+        "Idempotency-Key"
+    );
+    assert_eq!(
+        meta.paths[0].operations[0].params[0].in_type,
+        MetaParamIn::Header
+    );
+    assert!(meta.paths[0].operations[0].params[0].required);
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    cli.post("/abc")
+        .header("Idempotency-Key", "8e03978e-40d5-43e8-bc93-6894a57f9324")
+        .send()
+        .await
+        .assert_status_is_ok();
+
+    cli.post("/abc")
+        .send()
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+
+    cli.post("/abc")
+        .header("Idempotency-Key", "bad key")
+        .send()
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn param_component() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/a", method = "get")]
+        async fn a(&self, #[oai(component = "Page")] page: Query<i32>) {
+            assert_eq!(page.0, 3);
+        }
+
+        #[oai(path = "/b", method = "get")]
+        async fn b(&self, #[oai(component = "Page")] page: Query<i32>) {
+            assert_eq!(page.0, 7);
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(
+        meta.paths[0].operations[0].params[0].component.as_deref(),
+        Some("Page")
+    );
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let spec = serde_json::from_str::<serde_json::Value>(&ep.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/a"]["get"]["parameters"][0],
+        serde_json::json!({ "$ref": "#/components/parameters/Page" })
+    );
+    assert_eq!(
+        spec["paths"]["/b"]["get"]["parameters"][0],
+        serde_json::json!({ "$ref": "#/components/parameters/Page" })
+    );
+    assert_eq!(spec["components"]["parameters"]["Page"]["name"], "page");
+    assert_eq!(spec["components"]["parameters"]["Page"]["in"], "query");
+
+    let cli = TestClient::new(ep);
+    cli.get("/a")
+        .query("page", &3)
+        .send()
+        .await
+        .assert_status_is_ok();
+    cli.get("/b")
+        .query("page", &7)
+        .send()
+        .await
+        .assert_status_is_ok();
+}
+
+#[cfg(feature = "cookie")]
+#[tokio::test]
+async fn cookie_explode_false() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, #[oai(explode = false)] ids: ParamCookie<Vec<i32>>) {
+            assert_eq!(ids.0, vec![1, 2, 3]);
+        }
+    }
+
+    let api = OpenApiService::new(Api, "test", "1.0");
+
+    TestClient::new(api)
+        .get("/")
+        .header(header::COOKIE, "ids=1,2,3")
+        .send()
+        .await
+        .assert_status_is_ok();
+}
+
+#[tokio::test]
+async fn page() {
+    use poem::http::StatusCode;
+    use poem_openapi::{param::Page, types::Cursor};
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, page: Page<20, 100>) -> PlainText<String> {
+            PlainText(format!(
+                "{}|{}",
+                page.cursor
+                    .map(|cursor| String::from_utf8(cursor.bytes().to_vec()).unwrap())
+                    .unwrap_or_default(),
+                page.limit
+            ))
+        }
+    }
+
+    // both parameters appear in the spec, with default and maximum on `limit`
+    let meta: MetaApi = Api::meta().remove(0);
+    let params = &meta.paths[0].operations[0].params;
+    assert_eq!(params[0].name, "cursor");
+    assert!(!params[0].required);
+    assert_eq!(params[1].name, "limit");
+    let limit_schema = params[1].schema.unwrap_inline();
+    assert_eq!(limit_schema.default, Some(serde_json::json!(20)));
+    assert_eq!(limit_schema.minimum, Some(1.0));
+    assert_eq!(limit_schema.maximum, Some(100.0));
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // absent limit falls back to the default
+    let resp = cli.get("/").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("|20").await;
+
+    // an oversized limit is clamped, not rejected
+    let resp = cli.get("/").query("limit", &500).send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("|100").await;
+
+    // a zero limit is clamped up to the documented minimum
+    let resp = cli.get("/").query("limit", &0).send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("|1").await;
+
+    let cursor = Cursor::new(*b"item:42");
+    let resp = cli
+        .get("/")
+        .query("cursor", &cursor.as_str())
+        .query("limit", &10)
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_text("item:42|10").await;
+
+    let resp = cli.get("/").query("cursor", &"not%valid!").send().await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn extract_param_options_builder() {
+    use poem_openapi::ExtractParamOptions;
+
+    // only the name is set, everything else keeps its default
+    let opts = ExtractParamOptions::<Vec<i32>>::new("ids");
+    assert_eq!(opts.name, "ids");
+    assert!(!opts.ignore_case);
+    assert!(opts.default_value.is_none());
+    assert!(opts.example_value.is_none());
+    assert!(opts.explode);
+    assert!(opts.style.is_none());
+    assert!(opts.max_items.is_none());
+    assert!(opts.trim_values);
+    assert!(!opts.flatten_commas);
+
+    let opts = ExtractParamOptions::<Vec<i32>>::new("ids")
+        .with_explode(false)
+        .with_max_items(Some(3));
+    assert!(!opts.explode);
+    assert_eq!(opts.max_items, Some(3));
+}
@@ -2,7 +2,7 @@ use poem::{Error, http::StatusCode, test::TestClient};
 use poem_openapi::{
     ApiResponse, OpenApi, OpenApiService,
     param::Query,
-    payload::{Json, Response},
+    payload::{Cached, Json, Response},
 };
 
 #[tokio::test]
@@ -51,3 +51,201 @@ async fn response_wrapper() {
     resp.assert_status(StatusCode::BAD_REQUEST);
     resp.assert_header("MY-HEADER1", "def");
 }
+
+#[tokio::test]
+async fn json_with_utf8_bom() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn index(&self, value: Json<i32>) -> Json<i32> {
+            Json(value.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .post("/")
+        .content_type("application/json")
+        .body(b"\xef\xbb\xbf100".to_vec())
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_text("100").await;
+}
+
+#[tokio::test]
+async fn multipart_mixed_response() {
+    use poem_openapi::payload::{MultipartMixed, Part};
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/batch", method = "get")]
+        async fn batch(&self) -> MultipartMixed {
+            MultipartMixed::new()
+                .part(Part::new("text/plain; charset=utf-8", "hello"))
+                .part(Part::json(&serde_json::json!({ "id": 1 })))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/batch").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_content_type("multipart/mixed; boundary=poem-openapi-boundary");
+    resp.assert_text(
+        "--poem-openapi-boundary\r\n\
+        Content-Type: text/plain; charset=utf-8\r\n\
+        \r\n\
+        hello\r\n\
+        --poem-openapi-boundary\r\n\
+        Content-Type: application/json; charset=utf-8\r\n\
+        \r\n\
+        {\"id\":1}\r\n\
+        --poem-openapi-boundary--\r\n",
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn cached_response() {
+    use std::time::Duration;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/public", method = "get")]
+        async fn public(&self) -> Cached<Json<i32>> {
+            Cached::new(Json(100))
+                .public()
+                .max_age(Duration::from_secs(3600))
+        }
+
+        #[oai(path = "/private", method = "get")]
+        async fn private(&self) -> Cached<Json<i32>> {
+            Cached::new(Json(100))
+                .private()
+                .max_age(Duration::from_secs(60))
+                .stale_while_revalidate(Duration::from_secs(30))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/public").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header("cache-control", "public, max-age=3600");
+    resp.assert_text("100").await;
+
+    let resp = cli.get("/private").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header(
+        "cache-control",
+        "private, max-age=60, stale-while-revalidate=30",
+    );
+}
+
+#[cfg(feature = "msgpack")]
+#[tokio::test]
+async fn msgpack_negotiation() {
+    use poem::web::Accept;
+    use poem_openapi::{
+        payload::JsonOrMsgPack,
+        registry::{MetaApi, MetaResponses},
+    };
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn stats(&self, accept: Accept) -> JsonOrMsgPack<Vec<i32>> {
+            JsonOrMsgPack::new(&accept, vec![1, 2, 3])
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    let meta_responses: &MetaResponses = &meta.paths[0].operations[0].responses;
+    let content = &meta_responses.responses[0].content;
+    assert_eq!(content[0].content_type, "application/json; charset=utf-8");
+    assert_eq!(content[1].content_type, "application/msgpack");
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .get("/")
+        .header("accept", "application/msgpack")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_content_type("application/msgpack");
+    let body = resp.0.into_body().into_vec().await.unwrap();
+    let value: Vec<i32> = rmp_serde::from_slice(&body).unwrap();
+    assert_eq!(value, vec![1, 2, 3]);
+
+    let resp = cli
+        .get("/")
+        .header("accept", "application/json")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_content_type("application/json; charset=utf-8");
+    resp.assert_json(&[1, 2, 3]).await;
+
+    // no preference falls back to JSON
+    let resp = cli.get("/").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_content_type("application/json; charset=utf-8");
+}
+
+#[tokio::test]
+async fn measured_response() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    };
+
+    use poem::EndpointExt;
+    use poem_openapi::payload::{Measured, ResponseSize};
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn index(&self) -> Measured<Json<Vec<i32>>> {
+            Measured(Json(vec![1, 2, 3]))
+        }
+    }
+
+    let recorded = Arc::new(AtomicU64::new(0));
+    let ep = {
+        let recorded = recorded.clone();
+        OpenApiService::new(Api, "test", "1.0").around(move |ep, req| {
+            let recorded = recorded.clone();
+            async move {
+                let resp = ep.call(req).await?;
+                if let Some(ResponseSize(size)) = resp.extensions().get::<ResponseSize>() {
+                    recorded.store(*size, Ordering::Relaxed);
+                }
+                Ok(resp)
+            }
+        })
+    };
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/").send().await;
+    resp.assert_status_is_ok();
+    let body = resp.0.into_body().into_vec().await.unwrap();
+    assert_eq!(recorded.load(Ordering::Relaxed), body.len() as u64);
+    assert_eq!(body, b"[1,2,3]");
+}
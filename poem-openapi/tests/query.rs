@@ -5,9 +5,9 @@ use poem::{
 };
 use poem_openapi::{
     ApiResponse, OpenApi, OpenApiService,
-    param::Query,
+    param::{Query, QueryStruct},
     payload::{Json, PlainText},
-    registry::MetaApi,
+    registry::{MetaApi, MetaParamIn},
     types::{MaybeUndefined, ToJSON},
 };
 use serde_json::Value;
@@ -59,10 +59,702 @@ async fn query_explode_false() {
     resp.assert_status_is_ok();
     resp.assert_json(&[1, 2, 3]).await;
 
+    // present but empty parses as an empty parameter list, which for
+    // `MaybeUndefined` is `Undefined`
     let resp = cli.post("/abc").query("fields", &"").send().await;
-    resp.assert_status(StatusCode::BAD_REQUEST);
+    resp.assert_status_is_ok();
+    resp.assert_json(Value::Null).await;
 
     let resp = cli.post("/abc").send().await;
     resp.assert_status_is_ok();
     resp.assert_json(Value::Null).await;
 }
+
+#[tokio::test]
+async fn query_max_items() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "get")]
+        async fn test(&self, #[oai(max_items = 3)] values: Query<Vec<u32>>) -> Json<Vec<u32>> {
+            Json(values.0)
+        }
+
+        #[oai(path = "/def", method = "get")]
+        async fn test_explode_false(
+            &self,
+            #[oai(explode = false, max_items = 3)] values: Query<Vec<u32>>,
+        ) -> Json<Vec<u32>> {
+            Json(values.0)
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(
+        meta.paths[0].operations[0].params[0]
+            .schema
+            .unwrap_inline()
+            .max_items,
+        Some(3)
+    );
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .get("/abc")
+        .query("values", &"1")
+        .query("values", &"2")
+        .query("values", &"3")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&[1, 2, 3]).await;
+
+    let resp = cli
+        .get("/abc")
+        .query("values", &"1")
+        .query("values", &"2")
+        .query("values", &"3")
+        .query("values", &"4")
+        .send()
+        .await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+
+    let resp = cli.get("/def").query("values", &"1,2,3").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&[1, 2, 3]).await;
+
+    let resp = cli.get("/def").query("values", &"1,2,3,4").send().await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn query_max_raw_length() {
+    #[derive(ApiResponse)]
+    #[oai(bad_request_handler = "bad_request_handler")]
+    enum MyResponse {
+        /// Ok
+        #[oai(status = 200)]
+        Ok(Json<Vec<u32>>),
+        /// Bad Request
+        #[oai(status = 400)]
+        BadRequest(PlainText<String>),
+    }
+
+    fn bad_request_handler(err: Error) -> MyResponse {
+        MyResponse::BadRequest(PlainText(err.to_string()))
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "get")]
+        async fn test(
+            &self,
+            #[oai(explode = false, max_raw_length = 16)] values: Query<Vec<u32>>,
+        ) -> MyResponse {
+            MyResponse::Ok(Json(values.0))
+        }
+
+        #[oai(path = "/def", method = "get")]
+        async fn test_explode(
+            &self,
+            #[oai(max_raw_length = 4)] values: Query<Vec<u32>>,
+        ) -> MyResponse {
+            MyResponse::Ok(Json(values.0))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/abc").query("values", &"1,2,3").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&[1, 2, 3]).await;
+
+    // the guard runs before the value is split, so the unparseable elements
+    // at the end are never reached and the error is about the raw length
+    let resp = cli
+        .get("/abc")
+        .query("values", &"1,2,3,4,5,6,7,x,y,z")
+        .send()
+        .await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+    let body = resp.0.into_body().into_string().await.unwrap();
+    assert!(body.contains("longer than 16 bytes"), "{body}");
+    assert!(!body.contains("invalid digit"), "{body}");
+
+    // with `explode` the limit applies to each repeated value
+    let resp = cli
+        .get("/def")
+        .query("values", &"1")
+        .query("values", &"23456")
+        .send()
+        .await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+    let body = resp.0.into_body().into_string().await.unwrap();
+    assert!(body.contains("longer than 4 bytes"), "{body}");
+}
+
+#[tokio::test]
+async fn query_enum_set() {
+    use poem_openapi::{Enum, types::EnumSet};
+
+    #[derive(Debug, Enum, Copy, Clone, Eq, PartialEq)]
+    #[oai(rename_all = "lowercase")]
+    enum Feature {
+        Alpha,
+        Beta,
+        Gamma,
+    }
+
+    #[derive(ApiResponse)]
+    #[oai(bad_request_handler = "bad_request_handler")]
+    enum MyResponse {
+        /// Ok
+        #[oai(status = 200)]
+        Ok(Json<Value>),
+        /// Bad Request
+        #[oai(status = 400)]
+        BadRequest(PlainText<String>),
+    }
+
+    fn bad_request_handler(err: Error) -> MyResponse {
+        MyResponse::BadRequest(PlainText(err.to_string()))
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "get")]
+        async fn test(
+            &self,
+            #[oai(explode = false)] features: Query<EnumSet<Feature>>,
+        ) -> MyResponse {
+            MyResponse::Ok(Json(features.0.to_json().unwrap()))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .get("/abc")
+        .query("features", &"alpha,gamma,alpha")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&["alpha", "gamma"]).await;
+
+    let resp = cli.get("/abc").query("features", &"delta").send().await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+    let text = resp.0.into_body().into_string().await.unwrap();
+    assert!(text.contains(r#"invalid value "delta", expected one of: alpha, beta, gamma"#));
+}
+
+#[tokio::test]
+async fn query_flag() {
+    use poem_openapi::types::Flag;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "get")]
+        async fn test(&self, verbose: Query<Flag>) -> Json<bool> {
+            Json(verbose.0.0)
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(meta.paths[0].path, "/abc");
+    let param = &meta.paths[0].operations[0].params[0];
+    assert_eq!(param.name, "verbose");
+    assert!(!param.required);
+    assert!(param.allow_empty_value);
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/abc?verbose").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&true).await;
+
+    let resp = cli.get("/abc").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&false).await;
+
+    let resp = cli.get("/abc").query("verbose", &"false").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&false).await;
+}
+
+#[tokio::test]
+async fn query_tri_state_bool() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/abc", method = "get")]
+        async fn test(&self, active: Query<Option<bool>>) -> Json<Value> {
+            Json(match active.0 {
+                Some(value) => Value::Bool(value),
+                None => Value::Null,
+            })
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/abc").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&Value::Null).await;
+
+    let resp = cli.get("/abc").query("active", &"").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&Value::Null).await;
+
+    let resp = cli.get("/abc").query("active", &true).send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&true).await;
+
+    let resp = cli.get("/abc").query("active", &false).send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&false).await;
+}
+
+#[tokio::test]
+async fn query_preserves_empty_elements() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/strings", method = "get")]
+        async fn strings(
+            &self,
+            #[oai(explode = false)] a: Query<Vec<String>>,
+        ) -> Json<Vec<String>> {
+            Json(a.0)
+        }
+
+        #[oai(path = "/options", method = "get")]
+        async fn options(&self, #[oai(explode = false)] a: Query<Vec<Option<i32>>>) -> Json<Value> {
+            Json(a.0.to_json().unwrap_or_default())
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // empty elements between delimiters are preserved as empty strings
+    let resp = cli.get("/strings").query("a", &"x,,y").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!(["x", "", "y"])).await;
+
+    // ... and as `None` for optional element types
+    let resp = cli.get("/options").query("a", &"1,,3").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!([1, null, 3])).await;
+}
+
+#[tokio::test]
+async fn query_enum_list() {
+    use poem_openapi::Enum;
+
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "lowercase")]
+    enum Include {
+        Comments,
+        Author,
+        Tags,
+    }
+
+    #[derive(ApiResponse)]
+    #[oai(bad_request_handler = "bad_request_handler")]
+    enum MyResponse {
+        /// Ok
+        #[oai(status = 200)]
+        Ok(Json<Value>),
+        /// Bad Request
+        #[oai(status = 400)]
+        BadRequest(PlainText<String>),
+    }
+
+    fn bad_request_handler(err: Error) -> MyResponse {
+        MyResponse::BadRequest(PlainText(err.to_string()))
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/include", method = "get")]
+        async fn test(&self, #[oai(explode = false)] include: Query<Vec<Include>>) -> MyResponse {
+            MyResponse::Ok(Json(include.0.to_json().unwrap_or_default()))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // the order of the tokens is preserved
+    let resp = cli
+        .get("/include")
+        .query("include", &"tags,comments,author")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!(["tags", "comments", "author"]))
+        .await;
+
+    // unknown tokens are rejected with the list of valid values
+    let resp = cli
+        .get("/include")
+        .query("include", &"comments,likes")
+        .send()
+        .await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+    let text = resp.0.into_body().into_string().await.unwrap();
+    assert!(text.contains(r#"unknown value "likes""#), "{text}");
+    assert!(
+        text.contains("expected one of: comments, author, tags"),
+        "{text}"
+    );
+}
+
+#[tokio::test]
+async fn query_deep_object() {
+    use std::collections::HashMap;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(
+            &self,
+            #[oai(style = "deep_object")] filter: Query<HashMap<String, String>>,
+        ) -> Json<Value> {
+            Json(filter.0.to_json().unwrap())
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .get("/")
+        .query("filter[status]", &"open")
+        .query("filter[tier]", &"gold")
+        .query("other", &"ignored")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!({ "status": "open", "tier": "gold" }))
+        .await;
+}
+
+#[tokio::test]
+async fn query_delimited_styles() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/comma", method = "get")]
+        async fn comma(&self, #[oai(explode = false)] ids: Query<Vec<i32>>) -> Json<Vec<i32>> {
+            Json(ids.0)
+        }
+
+        #[oai(path = "/space", method = "get")]
+        async fn space(
+            &self,
+            #[oai(explode = false, style = "space_delimited")] ids: Query<Vec<i32>>,
+        ) -> Json<Vec<i32>> {
+            Json(ids.0)
+        }
+
+        #[oai(path = "/pipe", method = "get")]
+        async fn pipe(
+            &self,
+            #[oai(explode = false, style = "pipe_delimited")] ids: Query<Vec<i32>>,
+        ) -> Json<Vec<i32>> {
+            Json(ids.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    for (path, value) in [("/comma", "1,2,3"), ("/space", "1 2 3"), ("/pipe", "1|2|3")] {
+        let resp = cli.get(path).query("ids", &value).send().await;
+        resp.assert_status_is_ok();
+        resp.assert_json(&[1, 2, 3]).await;
+    }
+}
+
+#[tokio::test]
+async fn query_empty_vs_missing() {
+    const fn empty_tags() -> Vec<String> {
+        Vec::new()
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(
+            &self,
+            #[oai(explode = false, default = "empty_tags")] tags: Query<Vec<String>>,
+        ) -> Json<Vec<String>> {
+            Json(tags.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // present with values
+    let resp = cli.get("/").query("tags", &"a,b").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!(["a", "b"])).await;
+
+    // present but empty parses as an empty list, not `[""]`
+    let resp = cli.get("/").query("tags", &"").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!([])).await;
+
+    // absent falls back to the default
+    let resp = cli.get("/").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!([])).await;
+}
+
+#[tokio::test]
+async fn query_trim_values() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/trimmed", method = "get")]
+        async fn trimmed(
+            &self,
+            #[oai(explode = false)] x: Query<Vec<String>>,
+        ) -> Json<Vec<String>> {
+            Json(x.0)
+        }
+
+        #[oai(path = "/raw", method = "get")]
+        async fn raw(
+            &self,
+            #[oai(explode = false, trim_values = false)] x: Query<Vec<String>>,
+        ) -> Json<Vec<String>> {
+            Json(x.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/trimmed").query("x", &" a ,b").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!(["a", "b"])).await;
+
+    let resp = cli.get("/raw").query("x", &" a ,b").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!([" a ", "b"])).await;
+}
+
+#[tokio::test]
+async fn query_fixed_size_array() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, #[oai(explode = false)] p: Query<[f32; 3]>) -> Json<Vec<f32>> {
+            Json(p.0.to_vec())
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/").query("p", &"1.0,2.0,3.0").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!([1.0, 2.0, 3.0])).await;
+
+    cli.get("/")
+        .query("p", &"1.0,2.0")
+        .send()
+        .await
+        .assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn query_ignore_case_collects_all_variants() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, #[oai(ignore_case)] tag: Query<Vec<String>>) -> Json<Vec<String>> {
+            Json(tag.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli
+        .get("/")
+        .query("Tag", &"a")
+        .query("TAG", &"b")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(serde_json::json!(["a", "b"])).await;
+}
+
+#[tokio::test]
+async fn query_struct() {
+    #[derive(Debug, poem_openapi::Object, serde::Deserialize)]
+    struct Params {
+        keyword: String,
+        page: Option<u32>,
+        tag: Vec<String>,
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, params: QueryStruct<Params>) -> PlainText<String> {
+            PlainText(format!(
+                "{}|{:?}|{}",
+                params.keyword,
+                params.page,
+                params.tag.join(",")
+            ))
+        }
+    }
+
+    // every struct field is documented as its own query parameter
+    let meta: MetaApi = Api::meta().remove(0);
+    let params = &meta.paths[0].operations[0].params;
+    assert_eq!(
+        params
+            .iter()
+            .map(|param| (param.name.as_str(), param.in_type, param.required))
+            .collect::<Vec<_>>(),
+        vec![
+            ("keyword", MetaParamIn::Query, true),
+            ("page", MetaParamIn::Query, false),
+            ("tag", MetaParamIn::Query, true),
+        ]
+    );
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // repeated keys are collected into the `Vec` field
+    let resp = cli.get("/?keyword=rust&tag=a&tag=b").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("rust|None|a,b").await;
+
+    let resp = cli.get("/?keyword=rust&page=3&tag=a").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_text("rust|Some(3)|a").await;
+
+    // missing required field
+    let resp = cli.get("/?tag=a").send().await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn query_error_includes_value() {
+    #[derive(ApiResponse)]
+    #[oai(bad_request_handler = "bad_request_handler")]
+    enum MyResponse {
+        /// Ok
+        #[oai(status = 200)]
+        Ok(PlainText<String>),
+        /// Bad Request
+        #[oai(status = 400)]
+        BadRequest(PlainText<String>),
+    }
+
+    fn bad_request_handler(err: Error) -> MyResponse {
+        MyResponse::BadRequest(PlainText(err.to_string()))
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, ts: Query<i64>) -> MyResponse {
+            MyResponse::Ok(PlainText(ts.0.to_string()))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // the message names the parameter and preserves the offending value
+    let resp = cli.get("/").query("ts", &"invalid-timestamp").send().await;
+    resp.assert_status(StatusCode::BAD_REQUEST);
+    let msg = resp.0.into_body().into_string().await.unwrap();
+    assert!(msg.contains("`ts`"), "{msg}");
+    assert!(msg.contains("invalid-timestamp"), "{msg}");
+}
+
+#[tokio::test]
+async fn query_flatten_commas() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/strict", method = "get")]
+        async fn strict(&self, ids: Query<Vec<String>>) -> Json<Vec<String>> {
+            Json(ids.0)
+        }
+
+        #[oai(path = "/tolerant", method = "get")]
+        async fn tolerant(
+            &self,
+            #[oai(flatten_commas = true)] ids: Query<Vec<i32>>,
+        ) -> Json<Vec<i32>> {
+            Json(ids.0)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // strict explode keeps repeated values verbatim
+    let resp = cli
+        .get("/strict")
+        .query("ids", &"1")
+        .query("ids", &"2,3")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&["1", "2,3"]).await;
+
+    // the tolerant mode flattens mixed conventions
+    let resp = cli
+        .get("/tolerant")
+        .query("ids", &"1")
+        .query("ids", &"2, 3")
+        .send()
+        .await;
+    resp.assert_status_is_ok();
+    resp.assert_json(&[1, 2, 3]).await;
+}
@@ -613,3 +613,189 @@ async fn status_range() {
     let resp = cli.get("/?error=server").send().await;
     resp.assert_status(StatusCode::INSUFFICIENT_STORAGE);
 }
+
+#[tokio::test]
+async fn default_response() {
+    #[derive(Debug, ApiResponse)]
+    enum MyResponse {
+        #[oai(status = 200)]
+        Ok(Json<String>),
+        #[oai(default)]
+        Error(Json<i32>),
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self, Query(error): Query<Option<bool>>) -> MyResponse {
+            if error.unwrap_or_default() {
+                MyResponse::Error(Json(100))
+            } else {
+                MyResponse::Ok(Json("hello world".into()))
+            }
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec = serde_json::from_str::<serde_json::Value>(&service.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/"]["get"]["responses"],
+        json!({
+          "200": {
+            "description": "",
+            "content": {
+              "application/json; charset=utf-8": { "schema": { "type": "string" } }
+            }
+          },
+          "default": {
+            "description": "",
+            "content": {
+              "application/json; charset=utf-8": {
+                "schema": { "type": "integer", "format": "int32" }
+              }
+            }
+          }
+        })
+    );
+
+    let cli = TestClient::new(service);
+
+    let resp = cli.get("/").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_json(json!("hello world")).await;
+
+    let resp = cli.get("/?error=true").send().await;
+    resp.assert_json(json!(100)).await;
+}
+
+#[tokio::test]
+async fn accepted() {
+    use poem::http::Uri;
+    use poem_openapi::response::Accepted;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/jobs", method = "post")]
+        async fn create_job(&self) -> Accepted {
+            Accepted {
+                location: Uri::from_static("/jobs/123"),
+            }
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec = serde_json::from_str::<serde_json::Value>(&service.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/jobs"]["post"]["responses"]["202"]["headers"]["location"]["required"],
+        json!(true)
+    );
+
+    let cli = TestClient::new(service);
+    let resp = cli.post("/jobs").send().await;
+    resp.assert_status(StatusCode::ACCEPTED);
+    resp.assert_header("location", "/jobs/123");
+}
+
+#[tokio::test]
+async fn retry_after() {
+    use std::time::{Duration, SystemTime};
+
+    use poem_openapi::response::RetryAfter;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/seconds", method = "get")]
+        async fn seconds(&self) -> RetryAfter<PlainText<String>> {
+            RetryAfter::new(PlainText("slow down".to_string()), Duration::from_secs(120))
+        }
+
+        #[oai(path = "/date", method = "get")]
+        async fn date(&self) -> RetryAfter<PlainText<String>> {
+            RetryAfter::new(
+                PlainText("maintenance".to_string()),
+                SystemTime::UNIX_EPOCH + Duration::from_secs(1445412480),
+            )
+        }
+
+        #[oai(path = "/date2", method = "get")]
+        async fn date2(&self) -> RetryAfter<PlainText<String>> {
+            // RFC 9110's IMF-fixdate example
+            RetryAfter::new(
+                PlainText("maintenance".to_string()),
+                SystemTime::UNIX_EPOCH + Duration::from_secs(784111777),
+            )
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec = serde_json::from_str::<serde_json::Value>(&service.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/seconds"]["get"]["responses"]["200"]["headers"]["Retry-After"]["required"],
+        json!(true)
+    );
+
+    let cli = TestClient::new(service);
+    let resp = cli.get("/seconds").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header("retry-after", "120");
+
+    let resp = cli.get("/date").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT");
+
+    let resp = cli.get("/date2").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header("retry-after", "Sun, 06 Nov 1994 08:49:37 GMT");
+}
+
+#[cfg(feature = "compression")]
+#[tokio::test]
+async fn compressed_min_size() {
+    use poem::web::CompressionAlgo;
+    use poem_openapi::response::Compressed;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/small", method = "get")]
+        async fn small(&self) -> Compressed<PlainText<String>> {
+            Compressed::new(PlainText("tiny".to_string()), CompressionAlgo::GZIP)
+                .with_min_size(1024)
+        }
+
+        #[oai(path = "/large", method = "get")]
+        async fn large(&self) -> Compressed<PlainText<String>> {
+            Compressed::new(PlainText("a".repeat(4096)), CompressionAlgo::GZIP).with_min_size(1024)
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    // below the threshold: uncompressed, no `Content-Encoding`
+    let resp = cli.get("/small").send().await;
+    resp.assert_status_is_ok();
+    assert!(
+        !resp
+            .0
+            .headers()
+            .contains_key(poem::http::header::CONTENT_ENCODING)
+    );
+    resp.assert_text("tiny").await;
+
+    // above the threshold: compressed, `Content-Encoding: gzip`
+    let resp = cli.get("/large").send().await;
+    resp.assert_status_is_ok();
+    resp.assert_header("content-encoding", "gzip");
+    let body = resp.0.into_body().into_vec().await.unwrap();
+    // gzip magic number
+    assert_eq!(&body[..2], &[0x1f, 0x8b]);
+    assert!(body.len() < 4096);
+}
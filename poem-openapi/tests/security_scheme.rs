@@ -449,6 +449,62 @@ async fn oauth2_auth() {
     assert!(!MySecurityScheme::has_security_fallback())
 }
 
+#[tokio::test]
+async fn oauth2_operation_scopes() {
+    #[derive(OAuthScopes)]
+    #[allow(dead_code)]
+    enum UserScopes {
+        #[oai(rename = "read:users")]
+        ReadUsers,
+        #[oai(rename = "write:users")]
+        WriteUsers,
+    }
+
+    #[derive(SecurityScheme)]
+    #[oai(
+        ty = "oauth2",
+        flows(implicit(
+            authorization_url = "https://test.com/authorize",
+            scopes = "UserScopes"
+        ))
+    )]
+    #[allow(dead_code)]
+    struct MySecurityScheme(Bearer);
+
+    struct MyApi;
+
+    #[OpenApi]
+    impl MyApi {
+        #[oai(path = "/users", method = "get")]
+        async fn list(
+            &self,
+            #[oai(scope = "UserScopes::ReadUsers")] _auth: MySecurityScheme,
+        ) -> PlainText<String> {
+            PlainText("ok".to_string())
+        }
+
+        #[oai(path = "/users", method = "post")]
+        async fn create(
+            &self,
+            #[oai(scope = "UserScopes::ReadUsers", scope = "UserScopes::WriteUsers")]
+            _auth: MySecurityScheme,
+        ) -> PlainText<String> {
+            PlainText("ok".to_string())
+        }
+    }
+
+    let service = OpenApiService::new(MyApi, "test", "1.0");
+    let spec = serde_json::from_str::<Value>(&service.spec()).unwrap();
+    assert_eq!(
+        &spec["paths"]["/users"]["get"]["security"],
+        &json!([{ "MySecurityScheme": ["read:users"] }])
+    );
+    assert_eq!(
+        &spec["paths"]["/users"]["post"]["security"],
+        &json!([{ "MySecurityScheme": ["read:users", "write:users"] }])
+    );
+}
+
 #[tokio::test]
 async fn checker_result() {
     #[derive(SecurityScheme)]
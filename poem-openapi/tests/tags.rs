@@ -60,13 +60,13 @@ async fn meta() {
         registry.tags,
         vec![
             MetaTag {
-                name: "UserOperations",
-                description: Some("User operations"),
+                name: "UserOperations".to_string(),
+                description: Some("User operations".to_string()),
                 external_docs: None
             },
             MetaTag {
-                name: "PetOperations",
-                description: Some("Pet operations"),
+                name: "PetOperations".to_string(),
+                description: Some("Pet operations".to_string()),
                 external_docs: None
             }
         ]
@@ -91,7 +91,7 @@ async fn external_docs() {
     assert_eq!(
         registry.tags.into_iter().next().unwrap(),
         MetaTag {
-            name: "UserOperations",
+            name: "UserOperations".to_string(),
             description: None,
             external_docs: Some(MetaExternalDocument {
                 url: "https://github.com/OAI/OpenAPI-Specification/blob/main/versions/3.1.0.md"
@@ -101,3 +101,32 @@ async fn external_docs() {
         }
     );
 }
+
+#[tokio::test]
+async fn tag_definitions_on_service() {
+    use poem_openapi::{OpenApi, OpenApiService, TagObject};
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "get")]
+        async fn test(&self) {}
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0").tag(
+        TagObject::new("user")
+            .description("User operations")
+            .external_document("https://example.com/docs/user"),
+    );
+
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    assert_eq!(
+        spec["tags"],
+        serde_json::json!([{
+            "name": "user",
+            "description": "User operations",
+            "externalDocs": { "url": "https://example.com/docs/user" },
+        }])
+    );
+}
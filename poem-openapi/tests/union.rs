@@ -614,3 +614,75 @@ fn rename_all() {
         }))
     );
 }
+
+#[tokio::test]
+async fn tagged_union_event_stream() {
+    use poem::test::TestClient;
+    use poem_openapi::{OpenApi, OpenApiService, payload::EventStream, types::TaggedUnion};
+
+    #[derive(Debug, Object, PartialEq)]
+    struct Delta {
+        content: String,
+    }
+
+    #[derive(Debug, Object, PartialEq)]
+    struct Done {}
+
+    #[derive(Debug, Object, PartialEq)]
+    struct ChunkError {
+        message: String,
+    }
+
+    #[derive(Debug, Union, PartialEq)]
+    #[oai(discriminator_name = "type", rename_all = "lowercase")]
+    enum Chunk {
+        Delta(Delta),
+        Done(Done),
+        Error(ChunkError),
+    }
+
+    assert_eq!(Chunk::DISCRIMINATOR_NAME, "type");
+    assert_eq!(
+        Chunk::Delta(Delta {
+            content: "hello".to_string()
+        })
+        .discriminator(),
+        "delta"
+    );
+    assert_eq!(Chunk::Done(Done {}).discriminator(), "done");
+    assert_eq!(
+        Chunk::Error(ChunkError {
+            message: "oops".to_string()
+        })
+        .discriminator(),
+        "error"
+    );
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/stream", method = "get")]
+        async fn stream(
+            &self,
+        ) -> EventStream<futures_util::stream::Iter<std::vec::IntoIter<Chunk>>> {
+            EventStream::new(futures_util::stream::iter(vec![
+                Chunk::Delta(Delta {
+                    content: "hello".to_string(),
+                }),
+                Chunk::Done(Done {}),
+            ]))
+            .typed_events()
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0");
+    let cli = TestClient::new(ep);
+
+    let resp = cli.get("/stream").send().await;
+    resp.assert_status_is_ok();
+    let text = resp.0.into_body().into_string().await.unwrap();
+    assert!(text.contains("event: delta"));
+    assert!(text.contains(r#"data: {"content":"hello","type":"delta"}"#));
+    assert!(text.contains("event: done"));
+}
@@ -742,3 +742,73 @@ fn test_custom_validator() {
         "failed to parse \"A\": field `value` verification failed. MyIntValidator"
     );
 }
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_matrix_items() {
+    #[derive(Object, Debug, PartialEq)]
+    struct A {
+        #[oai(validator(max_rows = 2, min_rows = 1))]
+        matrix: ndarray::Array2<i32>,
+    }
+
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [[1, 2], [3, 4]] }))).unwrap(),
+        A {
+            matrix: ndarray::arr2(&[[1, 2], [3, 4]])
+        }
+    );
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [[1], [2], [3]] })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `matrix` verification failed. maxRows(2)"
+    );
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [] })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `matrix` verification failed. minRows(1)"
+    );
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_matrix_rows_and_cols() {
+    #[derive(Object, Debug, PartialEq)]
+    struct A {
+        #[oai(validator(min_rows = 1, max_rows = 100, min_cols = 1, max_cols = 2))]
+        matrix: ndarray::Array2<i32>,
+    }
+
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [[1, 2], [3, 4]] }))).unwrap(),
+        A {
+            matrix: ndarray::arr2(&[[1, 2], [3, 4]])
+        }
+    );
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [[1, 2, 3]] })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `matrix` verification failed. maxCols(2)"
+    );
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "matrix": [] })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `matrix` verification failed. minRows(1)"
+    );
+
+    // the row bounds land on the outer schema and the column bounds on the
+    // nested row schema
+    let mut registry = Registry::default();
+    A::register(&mut registry);
+    let schema = registry.schemas.get("A").unwrap();
+    let field_schema = schema.properties[0].1.unwrap_inline();
+    assert_eq!(field_schema.max_items, Some(100));
+    assert_eq!(field_schema.min_items, Some(1));
+    let row_schema = field_schema.items.as_ref().unwrap().unwrap_inline();
+    assert_eq!(row_schema.max_items, Some(2));
+    assert_eq!(row_schema.min_items, Some(1));
+}
@@ -126,7 +126,9 @@ async fn parameters() {
                 required: true,
                 deprecated: false,
                 explode: true,
+                allow_empty_value: false,
                 style: None,
+                component: None,
             },
             MetaOperationParam {
                 name: "b".to_string(),
@@ -136,7 +138,9 @@ async fn parameters() {
                 required: true,
                 deprecated: false,
                 explode: true,
+                allow_empty_value: false,
                 style: None,
+                component: None,
             }
         ]
     );
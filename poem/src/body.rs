@@ -169,6 +169,13 @@ impl Body {
         size_hint.lower() == 0 && size_hint.upper() == Some(0)
     }
 
+    /// Returns the exact length of this body, if it is known in advance.
+    ///
+    /// Streaming bodies return `None`.
+    pub fn exact_size(&self) -> Option<u64> {
+        hyper::body::Body::size_hint(&self.0).exact()
+    }
+
     /// Consumes this body object to return a [`Bytes`] that contains all data.
     pub async fn into_bytes(self) -> Result<Bytes, ReadBodyError> {
         Ok(self
@@ -12,6 +12,7 @@ mod form;
 mod json;
 #[cfg(feature = "multipart")]
 mod multipart;
+mod partial_content;
 mod path;
 mod query;
 mod real_ip;
@@ -32,6 +33,7 @@ pub use headers;
 #[cfg(feature = "csrf")]
 mod csrf;
 mod typed_header;
+mod user_agent;
 #[cfg(feature = "websocket")]
 #[cfg_attr(docsrs, doc(cfg(feature = "websocket")))]
 pub mod websocket;
@@ -65,11 +67,13 @@ pub use self::{
     data::Data,
     form::Form,
     json::Json,
+    partial_content::PartialContent,
     path::Path,
     query::Query,
-    real_ip::RealIp,
+    real_ip::{ClientIp, RealIp},
     redirect::Redirect,
     typed_header::TypedHeader,
+    user_agent::{UserAgent, UserAgentProduct},
 };
 use crate::{
     body::Body,
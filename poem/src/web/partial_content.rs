@@ -0,0 +1,142 @@
+use std::ops::Bound;
+
+use bytes::Bytes;
+use headers::{ContentRange, Range};
+use http::{StatusCode, header};
+
+use crate::{Body, IntoResponse, Response};
+
+/// A response helper for `Range` requests, defined in
+/// [RFC7233](https://tools.ietf.org/html/rfc7233)
+///
+/// If a satisfiable range is given, it responds with `206 Partial Content`,
+/// sets the `Content-Range` header and slices the body accordingly. An
+/// unsatisfiable range produces `416 Range Not Satisfiable`. Without a range
+/// the full body is returned.
+///
+/// # Example
+///
+/// ```
+/// use headers::HeaderMapExt;
+/// use poem::{
+///     handler,
+///     http::StatusCode,
+///     test::TestClient,
+///     web::PartialContent,
+///     Request,
+/// };
+///
+/// #[handler]
+/// fn index(req: &Request) -> PartialContent<&'static [u8]> {
+///     PartialContent::new(b"hello world".as_slice()).with_range(req.headers().typed_get())
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let cli = TestClient::new(index);
+/// let resp = cli.get("/").header("range", "bytes=0-4").send().await;
+/// resp.assert_status(StatusCode::PARTIAL_CONTENT);
+/// resp.assert_header("content-range", "bytes 0-4/11");
+/// resp.assert_text("hello").await;
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartialContent<T> {
+    data: T,
+    range: Option<Range>,
+}
+
+impl<T: Into<Bytes>> PartialContent<T> {
+    /// Create a `PartialContent` response with the full content.
+    pub fn new(data: T) -> Self {
+        Self { data, range: None }
+    }
+
+    /// Specify the requested range.
+    #[must_use]
+    pub fn with_range(self, range: Option<Range>) -> Self {
+        Self { range, ..self }
+    }
+}
+
+impl<T: Into<Bytes> + Send> IntoResponse for PartialContent<T> {
+    fn into_response(self) -> Response {
+        let data: Bytes = self.data.into();
+        let full_length = data.len() as u64;
+
+        let had_range = self.range.is_some();
+        match self
+            .range
+            .and_then(|range| range.satisfiable_ranges(full_length).next())
+        {
+            Some((start, end)) => {
+                let start = match start {
+                    Bound::Included(n) => n,
+                    Bound::Excluded(n) => n + 1,
+                    Bound::Unbounded => 0,
+                };
+                let end = match end {
+                    Bound::Included(n) => n + 1,
+                    Bound::Excluded(n) => n,
+                    Bound::Unbounded => full_length,
+                };
+                if end < start || end > full_length {
+                    return Response::builder()
+                        .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                        .typed_header(ContentRange::unsatisfied_bytes(full_length))
+                        .finish();
+                }
+
+                Response::builder()
+                    .status(StatusCode::PARTIAL_CONTENT)
+                    .header(header::ACCEPT_RANGES, "bytes")
+                    .header(header::CONTENT_LENGTH, end - start)
+                    .typed_header(ContentRange::bytes(start..end, full_length).unwrap())
+                    .body(Body::from_bytes(data.slice(start as usize..end as usize)))
+            }
+            None if had_range => Response::builder()
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .typed_header(ContentRange::unsatisfied_bytes(full_length))
+                .finish(),
+            None => Response::builder()
+                .header(header::ACCEPT_RANGES, "bytes")
+                .header(header::CONTENT_LENGTH, full_length)
+                .body(Body::from_bytes(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use headers::HeaderMapExt;
+
+    use super::*;
+    use crate::{Request, handler, test::TestClient};
+
+    #[handler(internal)]
+    fn index(req: &Request) -> PartialContent<&'static [u8]> {
+        PartialContent::new(b"hello world".as_slice()).with_range(req.headers().typed_get())
+    }
+
+    #[tokio::test]
+    async fn test_partial_content() {
+        let cli = TestClient::new(index);
+
+        let resp = cli.get("/").send().await;
+        resp.assert_status_is_ok();
+        resp.assert_text("hello world").await;
+
+        let resp = cli.get("/").header("range", "bytes=6-10").send().await;
+        resp.assert_status(StatusCode::PARTIAL_CONTENT);
+        resp.assert_header("content-range", "bytes 6-10/11");
+        resp.assert_text("world").await;
+    }
+
+    #[tokio::test]
+    async fn test_unsatisfiable_range() {
+        let cli = TestClient::new(index);
+
+        let resp = cli.get("/").header("range", "bytes=100-200").send().await;
+        resp.assert_status(StatusCode::RANGE_NOT_SATISFIABLE);
+        resp.assert_header("content-range", "bytes */11");
+    }
+}
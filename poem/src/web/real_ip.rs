@@ -60,6 +60,76 @@ impl<'a> FromRequest<'a> for RealIp {
     }
 }
 
+/// An extractor that resolves the real client ip behind a known number of
+/// trusted proxies.
+///
+/// The proxy chain is taken from the `Forwarded` header if present, otherwise
+/// from `X-Forwarded-For`, otherwise from `X-Real-IP`. With `TRUSTED_HOPS = N`,
+/// the `N`th address from the end of the chain is used, so addresses appended
+/// by untrusted clients cannot spoof the result. If no header yields an
+/// address, the connection remote address is used.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ClientIp<const TRUSTED_HOPS: usize = 1>(pub Option<IpAddr>);
+
+fn forwarded_chain(req: &Request) -> Vec<IpAddr> {
+    if let Some(forwarded) = req
+        .headers()
+        .get("forwarded")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| rfc7239::parse(value).collect::<Result<Vec<_>, _>>().ok())
+    {
+        let chain = forwarded
+            .into_iter()
+            .filter_map(|item| match item.forwarded_for {
+                Some(NodeIdentifier {
+                    name: NodeName::Ip(ip_addr),
+                    ..
+                }) => Some(ip_addr),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if !chain.is_empty() {
+            return chain;
+        }
+    }
+
+    if let Some(value) = req
+        .headers()
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+    {
+        let chain = value
+            .split(',')
+            .filter_map(|value| value.trim().parse::<IpAddr>().ok())
+            .collect::<Vec<_>>();
+        if !chain.is_empty() {
+            return chain;
+        }
+    }
+
+    req.headers()
+        .get("x-real-ip")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<IpAddr>().ok())
+        .into_iter()
+        .collect()
+}
+
+impl<'a, const TRUSTED_HOPS: usize> FromRequest<'a> for ClientIp<TRUSTED_HOPS> {
+    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+        let chain = forwarded_chain(req);
+        if !chain.is_empty() {
+            let idx = chain.len().saturating_sub(TRUSTED_HOPS.max(1));
+            return Ok(ClientIp(Some(chain[idx])));
+        }
+
+        match req.remote_addr().0 {
+            Addr::SocketAddr(addr) => Ok(ClientIp(Some(addr.ip()))),
+            _ => Ok(ClientIp(None)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +167,65 @@ mod tests {
             RealIp(Some("192.0.2.43".parse().unwrap()))
         );
     }
+
+    #[tokio::test]
+    async fn test_clientip_extractor() {
+        assert_eq!(
+            ClientIp::<1>::from_request_without_body(&create_request("x-real-ip", "203.0.113.195"))
+                .await
+                .unwrap(),
+            ClientIp(Some("203.0.113.195".parse().unwrap()))
+        );
+
+        // one trusted hop: the last entry was appended by the trusted proxy
+        assert_eq!(
+            ClientIp::<1>::from_request_without_body(&create_request(
+                "x-forwarded-for",
+                "203.0.113.195, 70.41.3.18, 150.172.238.178"
+            ))
+            .await
+            .unwrap(),
+            ClientIp(Some("150.172.238.178".parse().unwrap()))
+        );
+
+        // two trusted hops
+        assert_eq!(
+            ClientIp::<2>::from_request_without_body(&create_request(
+                "x-forwarded-for",
+                "203.0.113.195, 70.41.3.18, 150.172.238.178"
+            ))
+            .await
+            .unwrap(),
+            ClientIp(Some("70.41.3.18".parse().unwrap()))
+        );
+
+        // more trusted hops than entries: use the leftmost entry
+        assert_eq!(
+            ClientIp::<5>::from_request_without_body(&create_request(
+                "x-forwarded-for",
+                "203.0.113.195, 70.41.3.18"
+            ))
+            .await
+            .unwrap(),
+            ClientIp(Some("203.0.113.195".parse().unwrap()))
+        );
+
+        assert_eq!(
+            ClientIp::<1>::from_request_without_body(&create_request(
+                "forwarded",
+                "for=192.0.2.43, for=198.51.100.17"
+            ))
+            .await
+            .unwrap(),
+            ClientIp(Some("198.51.100.17".parse().unwrap()))
+        );
+
+        // no headers and no socket address: fall back to the remote address
+        assert_eq!(
+            ClientIp::<1>::from_request_without_body(&Request::builder().finish())
+                .await
+                .unwrap(),
+            ClientIp(None)
+        );
+    }
 }
@@ -0,0 +1,140 @@
+use http::header;
+
+use crate::{FromRequest, Request, RequestBody, Result};
+
+/// A product token in a `User-Agent` header.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UserAgentProduct {
+    /// The product name.
+    pub product: String,
+    /// The product version.
+    pub version: Option<String>,
+    /// The comment following the product, without the enclosing parentheses.
+    pub comment: Option<String>,
+}
+
+/// `User-Agent` header, defined in [RFC7231](http://tools.ietf.org/html/rfc7231#section-5.5.3)
+///
+/// The header is parsed into a list of products, each with an optional version
+/// and an optional comment. If the request does not contain a `User-Agent`
+/// header, the list is empty.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UserAgent(pub Vec<UserAgentProduct>);
+
+fn parse_user_agent(value: &str) -> Vec<UserAgentProduct> {
+    let mut products = Vec::<UserAgentProduct>::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+        } else if ch == '(' {
+            chars.next();
+            let mut comment = String::new();
+            let mut depth = 1;
+            for ch in chars.by_ref() {
+                match ch {
+                    '(' => depth += 1,
+                    ')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+                comment.push(ch);
+            }
+            match products.last_mut() {
+                Some(product) if product.comment.is_none() => {
+                    product.comment = Some(comment);
+                }
+                _ => products.push(UserAgentProduct {
+                    product: String::new(),
+                    version: None,
+                    comment: Some(comment),
+                }),
+            }
+        } else {
+            let mut token = String::new();
+            while let Some(&ch) = chars.peek() {
+                if ch.is_whitespace() || ch == '(' {
+                    break;
+                }
+                token.push(ch);
+                chars.next();
+            }
+            let (product, version) = match token.split_once('/') {
+                Some((product, version)) => (product.to_string(), Some(version.to_string())),
+                None => (token, None),
+            };
+            products.push(UserAgentProduct {
+                product,
+                version,
+                comment: None,
+            });
+        }
+    }
+
+    products
+}
+
+impl<'a> FromRequest<'a> for UserAgent {
+    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+        Ok(Self(
+            req.headers()
+                .get(header::USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(parse_user_agent)
+                .unwrap_or_default(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_user_agent_extractor() {
+        let req = Request::builder()
+            .header(
+                header::USER_AGENT,
+                "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko)",
+            )
+            .finish();
+        let user_agent = UserAgent::from_request_without_body(&req).await.unwrap();
+        assert_eq!(
+            user_agent.0,
+            &[
+                UserAgentProduct {
+                    product: "Mozilla".to_string(),
+                    version: Some("5.0".to_string()),
+                    comment: Some("X11; Linux x86_64".to_string()),
+                },
+                UserAgentProduct {
+                    product: "AppleWebKit".to_string(),
+                    version: Some("537.36".to_string()),
+                    comment: Some("KHTML, like Gecko".to_string()),
+                }
+            ]
+        );
+
+        let req = Request::builder()
+            .header(header::USER_AGENT, "curl/8.5.0")
+            .finish();
+        let user_agent = UserAgent::from_request_without_body(&req).await.unwrap();
+        assert_eq!(
+            user_agent.0,
+            &[UserAgentProduct {
+                product: "curl".to_string(),
+                version: Some("8.5.0".to_string()),
+                comment: None,
+            }]
+        );
+
+        let req = Request::builder().finish();
+        let user_agent = UserAgent::from_request_without_body(&req).await.unwrap();
+        assert_eq!(user_agent.0, &[]);
+    }
+}